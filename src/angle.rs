@@ -19,6 +19,7 @@
 // D E F I N I T I O N S
 //#######################
 
+    #[repr(transparent)]
     #[derive(Clone, Copy, Hash, Default, Debug)]
     pub struct Angle(pub u8);
 
@@ -353,6 +354,8 @@
 
 
     const RAD_TO_BYTE_RATIO: f32 = 255f32 / std::f32::consts::FRAC_2_PI;
+    const BYTE_TO_DEG_RATIO: f32 = 360f32 / 256f32;
+    const DEG_TO_BYTE_RATIO: f32 = 256f32 / 360f32;
 
 
 //###############################
@@ -360,6 +363,12 @@
 //###############################
 
     impl Angle {
+        /// Converts a single byte angle to floating point degrees
+        pub fn to_degrees(self) -> f32 { self.0 as f32 * BYTE_TO_DEG_RATIO }
+        /// Converts floating point degrees to the nearest single byte angle
+        pub fn from_degrees(degrees: f32) -> Self { Angle(u8::wrapping_from((degrees * DEG_TO_BYTE_RATIO).round() as i32)) }
+
+
         pub(crate) fn sinf(self) -> f32 {
             match self.0 & 0xC0 {
                 0x00 =>  TRIGO_LOOKUP[self.0 as usize],
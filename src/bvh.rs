@@ -0,0 +1,236 @@
+//#########################
+// D E P E N D E N C I E S
+//#########################
+
+    use crate::traits::{Signed, AsF64, Vector};
+    use crate::geometry::{Ray, Ball, Aabb};
+
+
+//#######################
+// D E F I N I T I O N S
+//#######################
+
+    /// How nodes get divided while building, both cut along the axis where the
+    /// centers of the boxes spread the most
+    #[derive(Clone, Copy, PartialEq, Eq, Hash, Default, Debug)]
+    pub enum Split {
+        /// Halves the boxes, fast to build
+        Median,
+        /// Minimizes the surface area heuristic, faster to query
+        #[default]
+        SurfaceArea,
+    } // enum ..
+
+
+    /// Bounding volume hierarchy over the boxes of a slice, queries report indices into that slice
+    #[derive(Clone, PartialEq, Eq, Hash, Default, Debug)]
+    pub struct Bvh<V: Vector> {
+        /// Parents come before their children, the root is the first node
+        nodes: Vec<Node<V>>,
+        /// Indices of the boxes, each leaf owning a contiguous range
+        items: Vec<usize>,
+        /// Copy of the boxes in the order of `items`
+        boxes: Vec<Aabb<V>>,
+    } // struct ..
+
+
+    #[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+    struct Node<V: Vector> {
+        bounds: Aabb<V>,
+        /// Leaves start in `items`, inner nodes have their children at `start` and `start + 1`
+        start:  usize,
+        /// Number of items, zero for inner nodes
+        count:  usize,
+    } // struct ..
+
+
+    const LEAF_SIZE: usize = 4usize;
+
+
+//###############################
+// I M P L E M E N T A T I O N S
+//###############################
+
+    impl<V: Vector> Bvh<V> where V::Scalar: Signed + AsF64 {
+        pub fn build(boxes: &[Aabb<V>], split: Split) -> Self {
+            let mut bvh = Bvh { nodes: Vec::with_capacity(2usize * boxes.len()), items: (0usize..boxes.len()).collect(), boxes: Vec::new() };
+            if boxes.is_empty() { return bvh }
+
+            bvh.nodes.push(Node { bounds: boxes[0usize], start: 0usize, count: boxes.len() });
+            let mut pending = vec![0usize];
+            while let Some(index) = pending.pop() {
+                let Node { start, count, .. } = bvh.nodes[index];
+                bvh.nodes[index].bounds = union(bvh.items[start..start + count].iter().map(|&item| boxes[item]));
+                if count <= LEAF_SIZE { continue }
+
+                let items = &mut bvh.items[start..start + count];
+                let Some(left) = divide(boxes, items, split) else { continue };
+
+                let children = bvh.nodes.len();
+                bvh.nodes.push(Node { bounds: bvh.nodes[index].bounds, start, count: left });
+                bvh.nodes.push(Node { bounds: bvh.nodes[index].bounds, start: start + left, count: count - left });
+                bvh.nodes[index] = Node { start: children, count: 0usize, ..bvh.nodes[index] };
+                pending.extend([children, children + 1usize]);
+            } // while ..
+
+            bvh.boxes = bvh.items.iter().map(|&item| boxes[item]).collect();
+            bvh
+        } // fn ..
+
+
+        /// Bounds of every box, `None` when empty
+        pub fn bounds(&self) -> Option<Aabb<V>> { self.nodes.first().map(|root| root.bounds) }
+
+
+        /// Updates the bounds after the boxes moved, keeping the tree. Queries slow down as the
+        /// boxes drift away from where they were at build time, rebuild then
+        pub fn refit(&mut self, boxes: &[Aabb<V>]) {
+            for (copy, &item) in self.boxes.iter_mut().zip(&self.items) { *copy = boxes[item] }
+            for index in (0usize..self.nodes.len()).rev() {
+                let Node { start, count, .. } = self.nodes[index];
+                self.nodes[index].bounds = match count {
+                    0usize => union([self.nodes[start].bounds, self.nodes[start + 1usize].bounds]),
+                    _      => union(self.boxes[start..start + count].iter().copied()),
+                }; // match ..
+            } // for ..
+        } // fn ..
+
+
+        /// Nearest hit along the ray as an index and a ray parameter. `hit` tests the ray against
+        /// the item of an index, and is only called for items whose box the ray goes through
+        pub fn ray_cast(&self, ray: &Ray<V>, mut hit: impl FnMut(usize) -> Option<V::Scalar>) -> Option<(usize, V::Scalar)> {
+            let mut nearest: Option<(usize, V::Scalar)> = None;
+            let mut pending = match self.nodes.first() {
+                Some(root) => entry(ray, &root.bounds).map(|time| (0usize, time)).into_iter().collect(),
+                None       => Vec::new(),
+            }; // let ..
+
+            while let Some((index, time)) = pending.pop() {
+                if nearest.is_some_and(|(_, nearest)| nearest.as_f64() < time) { continue }
+
+                let Node { start, count, .. } = self.nodes[index];
+                if count > 0usize {
+                    for (&item, bounds) in self.items[start..start + count].iter().zip(&self.boxes[start..start + count]) {
+                        if entry(ray, bounds).is_none_or(|time| nearest.is_some_and(|(_, nearest)| nearest.as_f64() < time)) { continue }
+                        if let Some(time) = hit(item).filter(|&time| nearest.is_none_or(|(_, nearest)| time < nearest)) { nearest = Some((item, time)) }
+                    } // for ..
+                    continue
+                } // if ..
+
+                // The nearest child goes on top of the stack to shrink the search early
+                let mut children: Vec<(usize, f64)> = [start, start + 1usize].into_iter()
+                    .filter_map(|child| entry(ray, &self.nodes[child].bounds).map(|time| (child, time)))
+                    .collect();
+                children.sort_by(|a, b| b.1.total_cmp(&a.1));
+                pending.extend(children);
+            } // while ..
+
+            nearest
+        } // fn ..
+
+
+        /// Calls `found` with the index of every box overlapping the given one, touching included
+        pub fn query_aabb(&self, aabb: &Aabb<V>, found: impl FnMut(usize)) {
+            self.query(|bounds| overlaps(bounds, aabb), found)
+        } // fn ..
+
+
+        /// Calls `found` with the index of every box overlapping the sphere, touching included
+        pub fn query_sphere(&self, sphere: &Ball<V>, found: impl FnMut(usize)) {
+            self.query(|bounds| bounds.squared_distance(sphere.center) <= sphere.radius * sphere.radius, found)
+        } // fn ..
+
+
+        fn query(&self, overlap: impl Fn(&Aabb<V>) -> bool, mut found: impl FnMut(usize)) {
+            let mut pending = if self.nodes.is_empty() { Vec::new() } else { vec![0usize] };
+            while let Some(index) = pending.pop() {
+                let Node { bounds, start, count } = self.nodes[index];
+                if !overlap(&bounds) { continue }
+                if count == 0usize { pending.extend([start, start + 1usize]); continue }
+
+                for (&item, bounds) in self.items[start..start + count].iter().zip(&self.boxes[start..start + count]) {
+                    if overlap(bounds) { found(item) }
+                } // for ..
+            } // while ..
+        } // fn ..
+    } // impl ..
+
+
+    /// Sorts the items along the axis where their centers spread the most and returns how many
+    /// go left, `None` when splitting is not worth it
+    fn divide<V: Vector>(boxes: &[Aabb<V>], items: &mut [usize], split: Split) -> Option<usize> where V::Scalar: Signed + AsF64 {
+        let center = |item: usize, axis: usize| boxes[item].min.component(axis).as_f64() + boxes[item].max.component(axis).as_f64();
+        let spread = |axis: usize| {
+            let (min, max) = items.iter().fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), &item| (min.min(center(item, axis)), max.max(center(item, axis))));
+            max - min
+        }; // let ..
+
+        let axis = (0usize..V::DIMENSION).max_by(|&a, &b| spread(a).total_cmp(&spread(b)))?;
+        if spread(axis) == 0f64 { return None }
+        items.sort_by(|&a, &b| center(a, axis).total_cmp(&center(b, axis)));
+
+        match split {
+            Split::Median      => Some(items.len() / 2usize),
+            Split::SurfaceArea => {
+                // Cost of a split is the area of each side weighted by its number of boxes
+                let mut right = vec![0f64; items.len()];
+                let mut bounds = boxes[items[items.len() - 1usize]];
+                for index in (1usize..items.len()).rev() {
+                    bounds = union([bounds, boxes[items[index]]]);
+                    right[index] = area(&bounds) * (items.len() - index) as f64;
+                } // for ..
+
+                let mut bounds = boxes[items[0usize]];
+                let mut best   = (f64::INFINITY, items.len() / 2usize);
+                for index in 1usize..items.len() {
+                    bounds = union([bounds, boxes[items[index - 1usize]]]);
+                    let cost = area(&bounds) * index as f64 + right[index];
+                    if cost < best.0 { best = (cost, index) }
+                } // for ..
+
+                // A leaf costs one test per box against the area of the parent
+                let parent = area(&union(items.iter().map(|&item| boxes[item])));
+                if items.len() <= 2usize * LEAF_SIZE && best.0 >= parent * items.len() as f64 { return None }
+                Some(best.1)
+            } // Split::SurfaceArea
+        } // match ..
+    } // fn ..
+
+
+    fn union<V: Vector>(boxes: impl IntoIterator<Item = Aabb<V>>) -> Aabb<V> {
+        boxes.into_iter().reduce(|a, b| Aabb { min: V::min(a.min, b.min), max: V::max(a.max, b.max) }).expect("union of no boxes")
+    } // fn ..
+
+
+    /// Half the surface of the box in any dimension, the sum of its faces' areas
+    fn area<V: Vector>(aabb: &Aabb<V>) -> f64 where V::Scalar: AsF64 {
+        let extent = |axis: usize| aabb.max.component(axis).as_f64() - aabb.min.component(axis).as_f64();
+        (0usize..V::DIMENSION).map(|skipped| (0usize..V::DIMENSION).filter(|&axis| axis != skipped).map(extent).product::<f64>()).sum()
+    } // fn ..
+
+
+    fn overlaps<V: Vector>(a: &Aabb<V>, b: &Aabb<V>) -> bool {
+        (0usize..V::DIMENSION).all(|axis| a.min.component(axis) <= b.max.component(axis) && b.min.component(axis) <= a.max.component(axis))
+    } // fn ..
+
+
+    /// Ray parameter where the ray enters the box, zero when it starts inside
+    fn entry<V: Vector>(ray: &Ray<V>, aabb: &Aabb<V>) -> Option<f64> where V::Scalar: AsF64 {
+        let (mut near, mut far) = (0f64, f64::INFINITY);
+        for axis in 0usize..V::DIMENSION {
+            let origin    = ray.origin.component(axis).as_f64();
+            let direction = ray.direction.component(axis).as_f64();
+            let (min, max) = (aabb.min.component(axis).as_f64(), aabb.max.component(axis).as_f64());
+
+            if direction == 0f64 {
+                if origin < min || origin > max { return None }
+                continue
+            } // if ..
+
+            let (a, b) = ((min - origin) / direction, (max - origin) / direction);
+            near = near.max(a.min(b));
+            far  = far.min(a.max(b));
+            if near > far { return None }
+        } // for ..
+        Some(near)
+    } // fn ..
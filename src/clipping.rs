@@ -0,0 +1,183 @@
+//#########################
+// D E P E N D E N C I E S
+//#########################
+
+    use crate::traits::{Number, AsF64};
+    use crate::vectors::{Vec2, Vec4};
+    use crate::matrices::DepthRange;
+    use crate::geometry::Aabb2;
+    use crate::interpolation::Lerp;
+
+
+//#######################
+// D E F I N I T I O N S
+//#######################
+
+    /// Vertex carrying a clip position, as given by a projection `Mat4` times a `Vec4` point.
+    /// Vertices created on the planes blend every attribute with `Lerp`
+    pub trait ClipVertex: Lerp {
+        fn position(&self) -> Vec4<f32>;
+    } // trait ..
+
+
+    /// Clip space volume, `-w <= x, y <= w` and the depth range between the near and far planes.
+    /// A guard band of `g` widens the sides to `-g * w <= x, y <= g * w`, leaving the triangles
+    /// crossing the screen edges to the rasterizer and only clipping the ones that go far beyond
+    #[derive(Clone, Copy, PartialEq, Default, Debug)]
+    pub struct ClipVolume {
+        pub depth:      DepthRange,
+        pub guard_band: Option<f32>,
+    } // struct ..
+
+
+    /// Cohen–Sutherland outcode, one bit per side a point lies beyond
+    #[derive(Clone, Copy, PartialEq, Eq, Hash, Default, Debug)]
+    pub struct Outcode(pub u8);
+
+
+//###############################
+// I M P L E M E N T A T I O N S
+//###############################
+
+    impl ClipVertex for Vec4<f32> {
+        fn position(&self) -> Vec4<f32> { *self }
+    } // impl ..
+
+
+    impl ClipVolume {
+        pub fn new(depth: DepthRange, guard_band: Option<f32>) -> Self { ClipVolume { depth, guard_band } }
+
+
+        /// Signed distances of a clip position to the six planes, in the order of the outcode
+        /// bits, positive inside
+        pub fn distances(&self, position: Vec4<f32>) -> [f32; 6] {
+            let Vec4(x, y, z, w) = position;
+            let side = w * self.guard_band.unwrap_or(1f32);
+            let near = match self.depth {
+                DepthRange::NegativeOneToOne => w + z,
+                DepthRange::ZeroToOne        => z,
+            }; // let ..
+            [side + x, side - x, side + y, side - y, near, w - z]
+        } // fn ..
+
+
+        pub fn outcode(&self, position: Vec4<f32>) -> Outcode {
+            let distances = self.distances(position);
+            Outcode((0usize..6usize).fold(0u8, |code, plane| if distances[plane] < 0f32 { code | (1u8 << plane) } else { code }))
+        } // fn ..
+
+
+        /// Part of the segment inside the volume, `None` when it misses it
+        pub fn clip_line<V: ClipVertex>(&self, start: V, end: V) -> Option<(V, V)> {
+            let (from, to) = (self.distances(start.position()), self.distances(end.position()));
+            let (mut enter, mut exit) = (0f32, 1f32);
+
+            for plane in 0usize..6usize {
+                let (a, b) = (from[plane], to[plane]);
+                if a < 0f32 && b < 0f32 { return None }
+                if a < 0f32 { enter = enter.max(a / (a - b)) }
+                if b < 0f32 { exit  = exit.min(a / (a - b)) }
+            } // for ..
+
+            if enter > exit { return None }
+            Some((
+                if enter > 0f32 { start.lerp(end, enter) } else { start },
+                if exit  < 1f32 { start.lerp(end, exit) }  else { end },
+            )) // Some()
+        } // fn ..
+
+
+        /// Sutherland–Hodgman clipping of a convex polygon against each plane in turn,
+        /// empty when nothing is left
+        pub fn clip_polygon<V: ClipVertex>(&self, polygon: &[V]) -> Vec<V> {
+            let codes = polygon.iter().map(|vertex| self.outcode(vertex.position()));
+            let (all, any) = codes.fold((Outcode(u8::MAX), Outcode::INSIDE), |(all, any), code| (Outcode(all.0 & code.0), Outcode(any.0 | code.0)));
+            if polygon.is_empty() || !all.is_inside() { return Vec::new() }
+            if any.is_inside() { return polygon.to_vec() }
+
+            let mut output = polygon.to_vec();
+            for plane in 0usize..6usize {
+                if any.0 & (1u8 << plane) == 0u8 { continue }
+                let input = std::mem::take(&mut output);
+                let Some(&last) = input.last() else { break };
+
+                let mut start    = last;
+                let mut distance = self.distances(start.position())[plane];
+                for &end in &input {
+                    let next = self.distances(end.position())[plane];
+                    if (distance < 0f32 && next > 0f32) || (distance > 0f32 && next < 0f32) { output.push(start.lerp(end, distance / (distance - next))) }
+                    if next >= 0f32 { output.push(end) }
+                    (start, distance) = (end, next);
+                } // for ..
+            } // for ..
+
+            output
+        } // fn ..
+    } // impl ..
+
+
+    impl Outcode {
+        pub const INSIDE: Self = Outcode(0u8);
+        pub const LEFT:   Self = Outcode(1u8);
+        pub const RIGHT:  Self = Outcode(2u8);
+        pub const BOTTOM: Self = Outcode(4u8);
+        pub const TOP:    Self = Outcode(8u8);
+        pub const NEAR:   Self = Outcode(16u8);
+        pub const FAR:    Self = Outcode(32u8);
+
+
+        /// Outcode of a point against a rectangle, bottom meaning below its minimum `y`
+        pub fn of_point<T: Number>(point: Vec2<T>, rect: &Aabb2<T>) -> Self {
+            let mut code = 0u8;
+            if point.0 < rect.min.0 { code |= Self::LEFT.0 } else if point.0 > rect.max.0 { code |= Self::RIGHT.0 }
+            if point.1 < rect.min.1 { code |= Self::BOTTOM.0 } else if point.1 > rect.max.1 { code |= Self::TOP.0 }
+            Outcode(code)
+        } // fn ..
+
+
+        pub fn is_inside(self) -> bool { self.0 == 0u8 }
+        pub fn contains(self, other: Self) -> bool { self.0 & other.0 == other.0 }
+
+        /// Whether both points lie beyond the same side, so that the segment between them misses
+        pub fn shares_side(self, other: Self) -> bool { self.0 & other.0 != 0u8 }
+    } // impl ..
+
+
+    /// Cohen–Sutherland clipping of a segment against a rectangle, bounds included, `None` when
+    /// it misses it. Integer crossings are rounded to the nearest pixel inside the rectangle
+    pub fn clip_segment<T: AsF64>(start: Vec2<T>, end: Vec2<T>, rect: &Aabb2<T>) -> Option<(Vec2<T>, Vec2<T>)> {
+        // Crossings are found in `f64` on the original line and only rounded once both ends are in,
+        // so that rounding neither bends the line nor pushes a crossing back out
+        let (min, max)     = ((rect.min.0.as_f64(), rect.min.1.as_f64()), (rect.max.0.as_f64(), rect.max.1.as_f64()));
+        let (x0, y0)       = (start.0.as_f64(), start.1.as_f64());
+        let (x1, y1)       = (end.0.as_f64(), end.1.as_f64());
+        let outcode        = |(x, y): (f64, f64)| Outcode(
+            if x < min.0 { Outcode::LEFT.0 } else if x > max.0 { Outcode::RIGHT.0 } else { 0u8 } |
+            if y < min.1 { Outcode::BOTTOM.0 } else if y > max.1 { Outcode::TOP.0 } else { 0u8 }
+        ); // let ..
+        let snap           = |(x, y): (f64, f64)| Vec2(T::from_f64(x.clamp(min.0, max.0)), T::from_f64(y.clamp(min.1, max.1)));
+
+        let (mut start, mut end) = ((x0, y0), (x1, y1));
+        let (mut start_code, mut end_code) = (outcode(start), outcode(end));
+
+        // Each end moves at most once per axis, more only when float errors bounce it around a corner
+        for _ in 0usize..8usize {
+            if start_code.is_inside() && end_code.is_inside() { return Some((snap(start), snap(end))) }
+            if start_code.shares_side(end_code) { return None }
+
+            // Moves an outside endpoint onto the line of a side it lies beyond
+            let code = if start_code.is_inside() { end_code } else { start_code };
+            let on_x = |x: f64| (x, y0 + (y1 - y0) * (x - x0) / (x1 - x0));
+            let on_y = |y: f64| (x0 + (x1 - x0) * (y - y0) / (y1 - y0), y);
+
+            let point = if code.contains(Outcode::LEFT) { on_x(min.0) }
+                else if code.contains(Outcode::RIGHT)   { on_x(max.0) }
+                else if code.contains(Outcode::BOTTOM)  { on_y(min.1) }
+                else                                    { on_y(max.1) };
+
+            if code == start_code { start = point; start_code = outcode(start); }
+            else                  { end   = point; end_code   = outcode(end); }
+        } // for ..
+
+        None
+    } // fn ..
@@ -0,0 +1,426 @@
+//#########################
+// D E P E N D E N C I E S
+//#########################
+
+    use crate::traits::{Trigonometry, Vector};
+    use crate::vectors::{Vec3, Vec4};
+    use crate::matrices::{Mat3, Mat4};
+    use crate::Angle;
+
+
+//#######################
+// D E F I N I T I O N S
+//#######################
+
+    // Colours are sRGB encoded bytes, alpha is linear. Float colours are in `0..=1`, linear ones
+    // are proportional to light intensity, which is what blending and filtering expect. Hues
+    // are single byte `Angle`s, so conversions to HSV and HSL and back may move a hue by up to
+    // 0.7 degrees.
+
+    pub type Rgb  = Vec3<u8>;
+    pub type Rgba = Vec4<u8>;
+
+
+    #[derive(Clone, Copy, PartialEq, Default, Debug)]
+    pub struct Hsv {
+        pub hue:        Angle,
+        pub saturation: f32,
+        pub value:      f32,
+    } // struct ..
+
+
+    #[derive(Clone, Copy, PartialEq, Default, Debug)]
+    pub struct Hsl {
+        pub hue:        Angle,
+        pub saturation: f32,
+        pub lightness:  f32,
+    } // struct ..
+
+
+    /// How a premultiplied source colour combines with the premultiplied colour under it, the
+    /// alpha of the result is the one of the source over the destination in every mode
+    #[derive(Clone, Copy, PartialEq, Eq, Hash, Default, Debug)]
+    pub enum BlendMode {
+        /// Source over destination
+        #[default]
+        Normal,
+        /// Sum of the colours, saturating, for lights and glows
+        Add,
+        /// Product of the colours where both are opaque, darkens
+        Multiply,
+        /// Inverse product of the inverse colours, lightens
+        Screen,
+    } // enum ..
+
+
+    /// Colour matrix with fixed-point coefficients, applied to bytes with integer arithmetic
+    /// only. Rows give the weights of the input channels in each output channel
+    #[derive(Clone, Copy, PartialEq, Eq, Hash, Default, Debug)]
+    pub struct FixedColourMat {
+        pub rows:   [Vec3<i32>; 3usize],
+        pub offset: Vec3<i32>,
+    } // struct ..
+
+
+    /// Fractional bits of the coefficients of a `FixedColourMat`, which leaves room for
+    /// coefficients and offsets up to 128 in magnitude
+    pub const COLOUR_FRACTION_BITS: u32 = 14u32;
+
+
+    /// BT.601 weights of the channels in the luma
+    const LUMA: Vec3<f32> = Vec3(0.299f32, 0.587f32, 0.114f32);
+
+
+    /// Linear intensity of every sRGB encoded byte
+    pub const SRGB_TO_LINEAR: [f32; 256usize] = [
+        0f32, 0.000303527f32, 0.000607054f32, 0.000910581f32, 0.001214108f32, 0.001517635f32, 0.001821162f32, 0.0021246888f32,
+        0.002428216f32, 0.0027317428f32, 0.00303527f32, 0.0033465358f32, 0.0036765074f32, 0.004024717f32, 0.004391442f32, 0.0047769533f32,
+        0.0051815165f32, 0.0056053917f32, 0.006048833f32, 0.0065120906f32, 0.00699541f32, 0.007499032f32, 0.008023193f32, 0.008568126f32,
+        0.009134059f32, 0.009721218f32, 0.010329823f32, 0.010960094f32, 0.011612245f32, 0.012286488f32, 0.0129830325f32, 0.013702083f32,
+        0.014443844f32, 0.015208514f32, 0.015996294f32, 0.016807375f32, 0.017641954f32, 0.01850022f32, 0.019382361f32, 0.020288562f32,
+        0.02121901f32, 0.022173885f32, 0.023153367f32, 0.024157632f32, 0.02518686f32, 0.026241222f32, 0.027320892f32, 0.02842604f32,
+        0.029556835f32, 0.030713445f32, 0.031896032f32, 0.033104766f32, 0.034339808f32, 0.035601314f32, 0.03688945f32, 0.038204372f32,
+        0.039546236f32, 0.0409152f32, 0.04231141f32, 0.04373503f32, 0.045186203f32, 0.046665087f32, 0.048171826f32, 0.049706567f32,
+        0.051269457f32, 0.052860647f32, 0.054480277f32, 0.05612849f32, 0.05780543f32, 0.059511237f32, 0.061246052f32, 0.063010015f32,
+        0.064803265f32, 0.06662594f32, 0.06847817f32, 0.070360094f32, 0.07227185f32, 0.07421357f32, 0.07618538f32, 0.07818742f32,
+        0.08021982f32, 0.08228271f32, 0.08437621f32, 0.08650046f32, 0.08865558f32, 0.09084171f32, 0.093058966f32, 0.09530747f32,
+        0.09758735f32, 0.099898726f32, 0.10224173f32, 0.104616486f32, 0.107023105f32, 0.10946171f32, 0.11193243f32, 0.114435375f32,
+        0.116970666f32, 0.11953843f32, 0.122138776f32, 0.12477182f32, 0.12743768f32, 0.13013647f32, 0.13286832f32, 0.13563333f32,
+        0.13843161f32, 0.14126329f32, 0.14412847f32, 0.14702727f32, 0.14995979f32, 0.15292615f32, 0.15592647f32, 0.15896083f32,
+        0.16202937f32, 0.1651322f32, 0.1682694f32, 0.17144111f32, 0.1746474f32, 0.17788842f32, 0.18116425f32, 0.18447499f32,
+        0.18782078f32, 0.19120169f32, 0.19461784f32, 0.19806932f32, 0.20155625f32, 0.20507874f32, 0.20863687f32, 0.21223076f32,
+        0.2158605f32, 0.2195262f32, 0.22322796f32, 0.22696587f32, 0.23074006f32, 0.23455058f32, 0.23839757f32, 0.24228112f32,
+        0.24620132f32, 0.25015828f32, 0.2541521f32, 0.25818285f32, 0.26225066f32, 0.2663556f32, 0.2704978f32, 0.2746773f32,
+        0.27889428f32, 0.28314874f32, 0.28744084f32, 0.29177064f32, 0.29613826f32, 0.30054379f32, 0.3049873f32, 0.30946892f32,
+        0.31398872f32, 0.31854677f32, 0.3231432f32, 0.3277781f32, 0.33245152f32, 0.33716363f32, 0.34191442f32, 0.34670407f32,
+        0.3515326f32, 0.35640013f32, 0.3613068f32, 0.3662526f32, 0.3712377f32, 0.37626213f32, 0.38132602f32, 0.38642943f32,
+        0.39157248f32, 0.39675522f32, 0.40197778f32, 0.4072402f32, 0.4125426f32, 0.41788507f32, 0.42326766f32, 0.4286905f32,
+        0.43415365f32, 0.43965718f32, 0.4452012f32, 0.4507858f32, 0.45641103f32, 0.462077f32, 0.4677838f32, 0.47353148f32,
+        0.47932017f32, 0.48514995f32, 0.49102086f32, 0.49693298f32, 0.5028865f32, 0.50888133f32, 0.5149177f32, 0.52099556f32,
+        0.5271151f32, 0.5332764f32, 0.5394795f32, 0.54572445f32, 0.55201143f32, 0.5583404f32, 0.5647115f32, 0.57112485f32,
+        0.57758045f32, 0.58407843f32, 0.59061885f32, 0.59720176f32, 0.60382736f32, 0.61049557f32, 0.6172066f32, 0.6239604f32,
+        0.63075715f32, 0.63759685f32, 0.6444797f32, 0.65140563f32, 0.65837485f32, 0.6653873f32, 0.67244315f32, 0.6795425f32,
+        0.6866853f32, 0.69387174f32, 0.7011019f32, 0.70837575f32, 0.7156935f32, 0.7230551f32, 0.73046076f32, 0.7379104f32,
+        0.7454042f32, 0.7529422f32, 0.7605245f32, 0.76815116f32, 0.7758222f32, 0.7835378f32, 0.7912979f32, 0.7991027f32,
+        0.80695224f32, 0.8148466f32, 0.82278574f32, 0.8307699f32, 0.838799f32, 0.8468732f32, 0.8549926f32, 0.8631572f32,
+        0.8713671f32, 0.8796224f32, 0.8879231f32, 0.8962694f32, 0.9046612f32, 0.91309863f32, 0.92158186f32, 0.9301109f32,
+        0.9386857f32, 0.9473065f32, 0.9559733f32, 0.9646863f32, 0.9734453f32, 0.9822506f32, 0.9911021f32, 1f32,
+    ]; // const ..
+
+
+//###############################
+// I M P L E M E N T A T I O N S
+//###############################
+
+    //#######################
+    // C O N V E R S I O N S
+    //#######################
+
+    impl Vec3<u8> {
+        pub fn to_linear(self) -> Vec3<f32> {
+            Vec3(SRGB_TO_LINEAR[self.0 as usize], SRGB_TO_LINEAR[self.1 as usize], SRGB_TO_LINEAR[self.2 as usize])
+        } // fn ..
+
+
+        /// Clamps the channels into `0..=1`
+        pub fn from_linear(linear: Vec3<f32>) -> Self { Vec3(encode(linear.0), encode(linear.1), encode(linear.2)) }
+
+
+        /// Packs into `0x00RRGGBB`
+        pub fn to_u32(self) -> u32 { u32::from_be_bytes([0u8, self.0, self.1, self.2]) }
+
+        /// Unpacks from `0x00RRGGBB`, ignoring the highest byte
+        pub fn from_u32(packed: u32) -> Self {
+            let [_, r, g, b] = packed.to_be_bytes();
+            Vec3(r, g, b)
+        } // fn ..
+
+
+        /// Packs into 5 bits of red, 6 of green and 5 of blue from the highest bit, rounding
+        pub fn to_rgb565(self) -> u16 { (narrow(self.0, 5u32) << 11u32) | (narrow(self.1, 6u32) << 5u32) | narrow(self.2, 5u32) }
+
+        pub fn from_rgb565(packed: u16) -> Self {
+            Vec3(widen(packed >> 11u32, 5u32), widen(packed >> 5u32 & 0x3F, 6u32), widen(packed & 0x1F, 5u32))
+        } // fn ..
+    } // impl ..
+
+
+    impl Vec4<u8> {
+        pub fn to_linear(self) -> Vec4<f32> {
+            let Vec3(r, g, b) = self.xyz().to_linear();
+            Vec4(r, g, b, self.3 as f32 / 255f32)
+        } // fn ..
+
+
+        /// Clamps the channels into `0..=1`
+        pub fn from_linear(linear: Vec4<f32>) -> Self {
+            Vec4(encode(linear.0), encode(linear.1), encode(linear.2), (linear.3.clamp(0f32, 1f32) * 255f32).round() as u8)
+        } // fn ..
+
+
+        /// Packs into `0xRRGGBBAA`
+        pub fn to_u32(self) -> u32 { u32::from_be_bytes([self.0, self.1, self.2, self.3]) }
+
+        pub fn from_u32(packed: u32) -> Self {
+            let [r, g, b, a] = packed.to_be_bytes();
+            Vec4(r, g, b, a)
+        } // fn ..
+
+
+        /// Packs into 4 bits per channel, red in the highest bits and alpha in the lowest, rounding
+        pub fn to_rgba4444(self) -> u16 {
+            (narrow(self.0, 4u32) << 12u32) | (narrow(self.1, 4u32) << 8u32) | (narrow(self.2, 4u32) << 4u32) | narrow(self.3, 4u32)
+        } // fn ..
+
+
+        pub fn from_rgba4444(packed: u16) -> Self {
+            Vec4(widen(packed >> 12u32, 4u32), widen(packed >> 8u32 & 0xF, 4u32), widen(packed >> 4u32 & 0xF, 4u32), widen(packed & 0xF, 4u32))
+        } // fn ..
+
+
+        /// Scales the colour by its alpha, which blending expects. Works on the encoded bytes like
+        /// most image formats and hardware, rather than on linear intensities
+        pub fn premultiply(self) -> Self { Vec4(mul(self.0, self.3), mul(self.1, self.3), mul(self.2, self.3), self.3) }
+
+
+        /// Inverse of `premultiply`, up to the precision lost by premultiplying
+        pub fn unpremultiply(self) -> Self {
+            let channel = |value: u8| match self.3 {
+                0u8   => 0u8,
+                alpha => ((value as u32 * 255u32 + alpha as u32 / 2u32) / alpha as u32).min(255u32) as u8,
+            }; // let ..
+            Vec4(channel(self.0), channel(self.1), channel(self.2), self.3)
+        } // fn ..
+
+
+        /// Combines `self` over `destination`, both premultiplied
+        pub fn blend(self, destination: Self, mode: BlendMode) -> Self {
+            let (alpha, under) = (self.3, destination.3);
+            let channel = |source: u8, destination: u8| match mode {
+                BlendMode::Normal   => source.saturating_add(mul(destination, 255u8 - alpha)),
+                BlendMode::Add      => source.saturating_add(destination),
+                BlendMode::Multiply => (mul(source, destination) as u16 + mul(source, 255u8 - under) as u16 + mul(destination, 255u8 - alpha) as u16).min(255u16) as u8,
+                BlendMode::Screen   => (source as u16 + destination as u16 - mul(source, destination) as u16) as u8,
+            }; // let ..
+            Vec4(channel(self.0, destination.0), channel(self.1, destination.1), channel(self.2, destination.2), alpha.saturating_add(mul(under, 255u8 - alpha)))
+        } // fn ..
+    } // impl ..
+
+
+    impl From<Rgb> for Hsv {
+        fn from(rgb: Rgb) -> Self {
+            let (hue, min, max) = hue(rgb);
+            Hsv { hue, saturation: if max > 0f32 { (max - min) / max } else { 0f32 }, value: max }
+        } // fn ..
+    } // impl ..
+
+
+    impl From<Hsv> for Rgb {
+        fn from(hsv: Hsv) -> Self {
+            let value  = hsv.value.clamp(0f32, 1f32);
+            let chroma = value * hsv.saturation.clamp(0f32, 1f32);
+            from_hue(hsv.hue, chroma, value - chroma)
+        } // fn ..
+    } // impl ..
+
+
+    impl From<Rgb> for Hsl {
+        fn from(rgb: Rgb) -> Self {
+            let (hue, min, max) = hue(rgb);
+            let lightness       = (max + min) / 2f32;
+            let saturation      = if max > min { (max - min) / (1f32 - (2f32 * lightness - 1f32).abs()) } else { 0f32 };
+            Hsl { hue, saturation: saturation.min(1f32), lightness }
+        } // fn ..
+    } // impl ..
+
+
+    impl From<Hsl> for Rgb {
+        fn from(hsl: Hsl) -> Self {
+            let lightness = hsl.lightness.clamp(0f32, 1f32);
+            let chroma    = (1f32 - (2f32 * lightness - 1f32).abs()) * hsl.saturation.clamp(0f32, 1f32);
+            from_hue(hsl.hue, chroma, lightness - chroma / 2f32)
+        } // fn ..
+    } // impl ..
+
+
+    //#################
+    // M A T R I C E S
+    //#################
+
+    // Colour matrices work on float colours in `0..=1`, `Mat3`s through `Mat3 * Vec3` and `Mat4`s,
+    // which can add offsets, through `Transform::transform_point`. Compose them with `*`.
+
+    impl Mat3<f32> {
+        /// Matrix whose output channels are the dot products of the input with each argument
+        pub fn new_channel_mixing_mat(red: Vec3<f32>, green: Vec3<f32>, blue: Vec3<f32>) -> Self {
+            Mat3(Vec3(red.0, green.0, blue.0), Vec3(red.1, green.1, blue.1), Vec3(red.2, green.2, blue.2))
+        } // fn ..
+
+
+        /// Sets every channel to the BT.601 luma
+        pub fn new_greyscale_mat() -> Self { Mat3::new_channel_mixing_mat(LUMA, LUMA, LUMA) }
+
+
+        /// Blends from greyscale at zero to the identity at one, and beyond to oversaturate
+        pub fn new_saturation_mat(saturation: f32) -> Self {
+            let grey = Mat3::new_greyscale_mat();
+            Mat3(
+                grey.0 * (1f32 - saturation) + Vec3(saturation, 0f32, 0f32),
+                grey.1 * (1f32 - saturation) + Vec3(0f32, saturation, 0f32),
+                grey.2 * (1f32 - saturation) + Vec3(0f32, 0f32, saturation),
+            ) // Mat3()
+        } // fn ..
+
+
+        /// Rotates the colours about the grey axis, keeping greys but not the luma of other colours
+        pub fn new_hue_rotation_mat<T: Trigonometry>(angle: T) -> Self {
+            let (cos, sin) = (angle.cos(), angle.sin());
+            let (same, skew) = ((1f32 - cos) / 3f32, sin / 3f32.sqrt());
+            Mat3(
+                Vec3(cos + same,  same + skew, same - skew),
+                Vec3(same - skew, cos + same,  same + skew),
+                Vec3(same + skew, same - skew, cos + same),
+            ) // Mat3()
+        } // fn ..
+
+
+        pub fn new_sepia_mat() -> Self {
+            Mat3::new_channel_mixing_mat(Vec3(0.393f32, 0.769f32, 0.189f32), Vec3(0.349f32, 0.686f32, 0.168f32), Vec3(0.272f32, 0.534f32, 0.131f32))
+        } // fn ..
+
+
+        /// Analog YUV from BT.601 luma, without offsets so that U and V are centered on zero
+        pub fn new_rgb_to_yuv_mat() -> Self {
+            Mat3::new_channel_mixing_mat(LUMA, Vec3(-0.14713f32, -0.28886f32, 0.436f32), Vec3(0.615f32, -0.51499f32, -0.10001f32))
+        } // fn ..
+
+
+        pub fn new_yuv_to_rgb_mat() -> Self {
+            Mat3::new_channel_mixing_mat(Vec3(1f32, 0f32, 1.13983f32), Vec3(1f32, -0.39465f32, -0.5806f32), Vec3(1f32, 2.03211f32, 0f32))
+        } // fn ..
+    } // impl ..
+
+
+    impl Mat4<f32> {
+        /// Applies `linear` then adds `offset`
+        pub fn new_colour_mat(linear: Mat3<f32>, offset: Vec3<f32>) -> Self {
+            Mat4(
+                Vec4(linear.0.0, linear.0.1, linear.0.2, 0f32),
+                Vec4(linear.1.0, linear.1.1, linear.1.2, 0f32),
+                Vec4(linear.2.0, linear.2.1, linear.2.2, 0f32),
+                Vec4(offset.0,   offset.1,   offset.2,   1f32),
+            ) // Mat4()
+        } // fn ..
+
+
+        /// Adds `brightness` after scaling the channels by `contrast` about the middle grey
+        pub fn new_brightness_contrast_mat(brightness: f32, contrast: f32) -> Self {
+            Mat4::new_colour_mat(Mat3::new_channel_mixing_mat(Vec3(contrast, 0f32, 0f32), Vec3(0f32, contrast, 0f32), Vec3(0f32, 0f32, contrast)), Vec3::splat(0.5f32 * (1f32 - contrast) + brightness))
+        } // fn ..
+
+
+        /// Full range BT.601 YCbCr as used by JPEG, with the chroma centered on one half
+        pub fn new_rgb_to_ycbcr_mat() -> Self {
+            let linear = Mat3::new_channel_mixing_mat(LUMA, Vec3(-0.168736f32, -0.331264f32, 0.5f32), Vec3(0.5f32, -0.418688f32, -0.081312f32));
+            Mat4::new_colour_mat(linear, Vec3(0f32, 0.5f32, 0.5f32))
+        } // fn ..
+
+
+        pub fn new_ycbcr_to_rgb_mat() -> Self {
+            let linear = Mat3::new_channel_mixing_mat(Vec3(1f32, 0f32, 1.402f32), Vec3(1f32, -0.344136f32, -0.714136f32), Vec3(1f32, 1.772f32, 0f32));
+            Mat4::new_colour_mat(linear, linear * Vec3(0f32, -0.5f32, -0.5f32))
+        } // fn ..
+    } // impl ..
+
+
+    impl FixedColourMat {
+        /// Rounds each coefficient to the nearest multiple of `2^-COLOUR_FRACTION_BITS`, saturating
+        /// coefficients and offsets at 128 in magnitude so that `apply` cannot overflow
+        pub fn from_mat(matrix: &Mat4<f32>) -> Self {
+            let fixed  = |value: f32, scale: f32| (value.clamp(-128f32, 128f32) * scale * (1u32 << COLOUR_FRACTION_BITS) as f32).round() as i32;
+            let row    = |index: usize| Vec3(fixed(matrix.0.component(index), 1f32), fixed(matrix.1.component(index), 1f32), fixed(matrix.2.component(index), 1f32));
+            let offset = Vec3(fixed(matrix.3.0, 255f32), fixed(matrix.3.1, 255f32), fixed(matrix.3.2, 255f32));
+            FixedColourMat { rows: [row(0usize), row(1usize), row(2usize)], offset }
+        } // fn ..
+
+
+        /// Rounds and clamps each channel
+        pub fn apply(&self, pixel: Vec3<u8>) -> Vec3<u8> {
+            let pixel   = Vec3(pixel.0 as i32, pixel.1 as i32, pixel.2 as i32);
+            let half    = 1i32 << (COLOUR_FRACTION_BITS - 1u32);
+            let channel = |row: Vec3<i32>, offset: i32| ((Vec3::dot(row, pixel) + offset + half) >> COLOUR_FRACTION_BITS).clamp(0i32, 255i32) as u8;
+            Vec3(channel(self.rows[0usize], self.offset.0), channel(self.rows[1usize], self.offset.1), channel(self.rows[2usize], self.offset.2))
+        } // fn ..
+    } // impl ..
+
+
+    impl From<Mat3<f32>> for FixedColourMat {
+        fn from(matrix: Mat3<f32>) -> Self { FixedColourMat::from_mat(&Mat4::new_colour_mat(matrix, Vec3::splat(0f32))) }
+    } // impl ..
+
+
+    impl From<Mat4<f32>> for FixedColourMat {
+        fn from(matrix: Mat4<f32>) -> Self { FixedColourMat::from_mat(&matrix) }
+    } // impl ..
+
+
+    //###############
+    // H E L P E R S
+    //###############
+
+    /// Hue of a colour along with its smallest and largest channels as floats
+    fn hue(rgb: Rgb) -> (Angle, f32, f32) {
+        let Vec3(r, g, b) = Vec3::<f32>::from(rgb) / 255f32;
+        let (min, max)    = (r.min(g).min(b), r.max(g).max(b));
+        let chroma        = max - min;
+
+        let sextant = if chroma == 0f32 { 0f32 }
+            else if max == r            { (g - b) / chroma }
+            else if max == g            { (b - r) / chroma + 2f32 }
+            else                        { (r - g) / chroma + 4f32 };
+        (Angle::from_degrees(sextant * 60f32), min, max)
+    } // fn ..
+
+
+    /// Colour of a hue with a chroma, lifted by `offset` on every channel
+    fn from_hue(hue: Angle, chroma: f32, offset: f32) -> Rgb {
+        // Bytes within half a step of a primary or secondary hue land on it exactly
+        let degrees = hue.to_degrees();
+        let nearest = (degrees / 60f32).round();
+        let sextant = if (degrees - nearest * 60f32).abs() < 180f32 / 256f32 { nearest % 6f32 } else { degrees / 60f32 };
+        let middle  = chroma * (1f32 - (sextant % 2f32 - 1f32).abs());
+        let (r, g, b) = match sextant as u32 {
+            0u32 => (chroma, middle, 0f32),
+            1u32 => (middle, chroma, 0f32),
+            2u32 => (0f32, chroma, middle),
+            3u32 => (0f32, middle, chroma),
+            4u32 => (middle, 0f32, chroma),
+            _    => (chroma, 0f32, middle),
+        }; // let ..
+        let channel = |value: f32| ((value + offset).clamp(0f32, 1f32) * 255f32).round() as u8;
+        Vec3(channel(r), channel(g), channel(b))
+    } // fn ..
+
+
+    /// sRGB byte whose intensity is the closest to a linear one
+    fn encode(linear: f32) -> u8 {
+        let above = SRGB_TO_LINEAR.partition_point(|&intensity| intensity < linear);
+        match above {
+            0usize   => 0u8,
+            256usize => 255u8,
+            _        => if SRGB_TO_LINEAR[above] - linear < linear - SRGB_TO_LINEAR[above - 1usize] { above as u8 } else { (above - 1usize) as u8 },
+        } // match ..
+    } // fn ..
+
+
+    /// Product of two bytes as fractions of 255, rounded
+    fn mul(a: u8, b: u8) -> u8 {
+        let product = a as u32 * b as u32 + 128u32;
+        ((product + (product >> 8u32)) >> 8u32) as u8
+    } // fn ..
+
+
+    fn narrow(value: u8, bits: u32) -> u16 { ((value as u32 * ((1u32 << bits) - 1u32) + 127u32) / 255u32) as u16 }
+
+    /// Replicates the highest bits into the lowest ones, so that the largest value maps to 255
+    fn widen(value: u16, bits: u32) -> u8 { ((value as u32) << (8u32 - bits) | (value as u32) >> (2u32 * bits - 8u32)) as u8 }
@@ -0,0 +1,364 @@
+//#########################
+// D E P E N D E N C I E S
+//#########################
+
+    use crate::traits::Vector;
+
+
+//#######################
+// D E F I N I T I O N S
+//#######################
+
+    /// Parametric curve going from its start at `t = 0` to its end at `t = 1`
+    pub trait Curve<V: Vector<Scalar = f32>> {
+        fn eval(&self, t: f32) -> V;
+        /// First derivative with respect to `t`
+        fn derivative(&self, t: f32) -> V;
+        /// Tight axis aligned bounding box as a `(min, max)` pair
+        fn bounding_box(&self) -> (V, V);
+
+        /// Unit direction of the curve, or the zero vector where it stalls
+        fn tangent(&self, t: f32) -> V { self.derivative(t).normalized() }
+
+
+        /// Appends the vertices of a polyline staying within `tolerance` of the
+        /// curve, subdividing more where it bends the most
+        fn flatten(&self, tolerance: f32, points: &mut Vec<V>) {
+            let start = self.eval(0f32);
+            let end   = self.eval(1f32);
+            points.push(start);
+            flatten_range(self, (0f32, start), (1f32, end), tolerance * tolerance, 0u32, points);
+        } // fn ..
+
+
+        /// Samples the curve to map distances along it back to parameters
+        fn arc_length_table(&self, samples: usize) -> ArcLengthTable where Self: Sized {
+            ArcLengthTable::new(self, samples)
+        } // fn ..
+    } // trait ..
+
+
+    #[derive(Clone, Copy, PartialEq, Hash, Default, Debug)]
+    pub struct QuadraticBezier<V>(pub V, pub V, pub V);
+
+
+    #[derive(Clone, Copy, PartialEq, Hash, Default, Debug)]
+    pub struct CubicBezier<V>(pub V, pub V, pub V, pub V);
+
+
+    /// Uniform Catmull-Rom spline passing through every control point but the
+    /// first and the last, which only shape the ends
+    #[derive(Clone, PartialEq, Hash, Default, Debug)]
+    pub struct CatmullRom<V>(pub Vec<V>);
+
+
+    /// Uniform cubic B-spline approximating its control points
+    #[derive(Clone, PartialEq, Hash, Default, Debug)]
+    pub struct BSpline<V>(pub Vec<V>);
+
+
+    /// Cumulative arc lengths of a curve sampled at evenly spaced parameters, built by `new`
+    /// only so that it always holds both ends of the curve
+    #[derive(Clone, PartialEq, Debug)]
+    pub struct ArcLengthTable {
+        lengths: Vec<f32>,
+    } // struct ..
+
+
+    /// Subdivisions always made before testing flatness, so that S-shaped
+    /// curves whose middle sits on the chord still get refined
+    const FLATTEN_MIN_DEPTH: u32 = 2u32;
+    const FLATTEN_MAX_DEPTH: u32 = 16u32;
+
+
+    fn flatten_range<V: Vector<Scalar = f32>, C: Curve<V> + ?Sized>(
+        curve:     &C,
+        start:     (f32, V),
+        end:       (f32, V),
+        tolerance: f32,
+        depth:     u32,
+        points:    &mut Vec<V>,
+    ) {
+        let t      = (start.0 + end.0) * 0.5f32;
+        let middle = curve.eval(t);
+        let error  = (middle - (start.1 + end.1) * 0.5f32).squared_magnitude();
+
+        if depth >= FLATTEN_MAX_DEPTH || (depth >= FLATTEN_MIN_DEPTH && error <= tolerance) {
+            points.push(end.1);
+        } else {
+            flatten_range(curve, start, (t, middle), tolerance, depth + 1u32, points);
+            flatten_range(curve, (t, middle), end, tolerance, depth + 1u32, points);
+        } // if ..
+    } // fn ..
+
+
+    /// Roots in `]0, 1[` of `a t² + b t + c`
+    fn unit_roots(a: f32, b: f32, c: f32) -> [Option<f32>; 2usize] {
+        let in_range = |t: f32| if t > 0f32 && t < 1f32 { Some(t) } else { None };
+        if a.abs() <= f32::EPSILON {
+            if b.abs() <= f32::EPSILON { [None, None] } else { [in_range(-c / b), None] }
+        } else {
+            let discriminant = b * b - 4f32 * a * c;
+            if discriminant < 0f32 { return [None, None] }
+            let root = discriminant.sqrt();
+            [in_range((-b - root) / (2f32 * a)), in_range((-b + root) / (2f32 * a))]
+        } // if ..
+    } // fn ..
+
+
+    /// Maps a spline parameter to a segment index and its local parameter
+    fn locate_segment(t: f32, segments: usize) -> (usize, f32) {
+        let position = t.clamp(0f32, 1f32) * segments as f32;
+        let index    = (position as usize).min(segments - 1usize);
+        (index, position - index as f32)
+    } // fn ..
+
+
+    fn spline_bounding_box<V: Vector<Scalar = f32>>(segments: impl Iterator<Item = CubicBezier<V>>) -> (V, V) {
+        segments.map(|segment| segment.bounding_box())
+            .reduce(|a, b| (V::min(a.0, b.0), V::max(a.1, b.1)))
+            .expect("spline without segments")
+    } // fn ..
+
+
+//###############################
+// I M P L E M E N T A T I O N S
+//###############################
+
+    //#####################
+    // Q U A D R A T I C
+    //#####################
+
+    impl<V: Vector<Scalar = f32>> QuadraticBezier<V> {
+        /// Splits the curve at `t` with de Casteljau's algorithm
+        pub fn split(&self, t: f32) -> (Self, Self) {
+            let p01  = self.0 + (self.1 - self.0) * t;
+            let p12  = self.1 + (self.2 - self.1) * t;
+            let p012 = p01 + (p12 - p01) * t;
+            (QuadraticBezier(self.0, p01, p012), QuadraticBezier(p012, p12, self.2))
+        } // fn ..
+
+
+        /// Degree elevation to the exact same curve
+        pub fn to_cubic(&self) -> CubicBezier<V> {
+            CubicBezier(
+                self.0,
+                self.0 + (self.1 - self.0) * (2f32 / 3f32),
+                self.2 + (self.1 - self.2) * (2f32 / 3f32),
+                self.2,
+            ) // CubicBezier()
+        } // fn ..
+    } // impl ..
+
+
+    impl<V: Vector<Scalar = f32>> Curve<V> for QuadraticBezier<V> {
+        fn eval(&self, t: f32) -> V {
+            let u = 1f32 - t;
+            self.0 * (u * u) + self.1 * (2f32 * u * t) + self.2 * (t * t)
+        } // fn ..
+
+
+        fn derivative(&self, t: f32) -> V {
+            (self.1 - self.0) * (2f32 * (1f32 - t)) + (self.2 - self.1) * (2f32 * t)
+        } // fn ..
+
+
+        fn bounding_box(&self) -> (V, V) {
+            let mut min = V::min(self.0, self.2);
+            let mut max = V::max(self.0, self.2);
+
+            for index in 0usize..V::DIMENSION {
+                let (p0, p1, p2) = (self.0.component(index), self.1.component(index), self.2.component(index));
+                if let [Some(t), _] = unit_roots(0f32, 2f32 * (p0 - 2f32 * p1 + p2), 2f32 * (p1 - p0)) {
+                    let extremum = self.eval(t).component(index);
+                    *min.component_mut(index) = min.component(index).min(extremum);
+                    *max.component_mut(index) = max.component(index).max(extremum);
+                } // if ..
+            } // for ..
+
+            (min, max)
+        } // fn ..
+    } // impl ..
+
+
+    //#############
+    // C U B I C
+    //#############
+
+    impl<V: Vector<Scalar = f32>> CubicBezier<V> {
+        /// Splits the curve at `t` with de Casteljau's algorithm
+        pub fn split(&self, t: f32) -> (Self, Self) {
+            let p01   = self.0 + (self.1 - self.0) * t;
+            let p12   = self.1 + (self.2 - self.1) * t;
+            let p23   = self.2 + (self.3 - self.2) * t;
+            let p012  = p01 + (p12 - p01) * t;
+            let p123  = p12 + (p23 - p12) * t;
+            let p0123 = p012 + (p123 - p012) * t;
+            (CubicBezier(self.0, p01, p012, p0123), CubicBezier(p0123, p123, p23, self.3))
+        } // fn ..
+    } // impl ..
+
+
+    impl<V: Vector<Scalar = f32>> Curve<V> for CubicBezier<V> {
+        fn eval(&self, t: f32) -> V {
+            let u = 1f32 - t;
+            self.0 * (u * u * u) + self.1 * (3f32 * u * u * t) + self.2 * (3f32 * u * t * t) + self.3 * (t * t * t)
+        } // fn ..
+
+
+        fn derivative(&self, t: f32) -> V {
+            let u = 1f32 - t;
+            (self.1 - self.0) * (3f32 * u * u) + (self.2 - self.1) * (6f32 * u * t) + (self.3 - self.2) * (3f32 * t * t)
+        } // fn ..
+
+
+        fn bounding_box(&self) -> (V, V) {
+            let mut min = V::min(self.0, self.3);
+            let mut max = V::max(self.0, self.3);
+
+            for index in 0usize..V::DIMENSION {
+                let (p0, p1, p2, p3) = (self.0.component(index), self.1.component(index), self.2.component(index), self.3.component(index));
+                let roots = unit_roots(3f32 * (p3 - p0) + 9f32 * (p1 - p2), 6f32 * (p0 - 2f32 * p1 + p2), 3f32 * (p1 - p0));
+                for t in roots.into_iter().flatten() {
+                    let extremum = self.eval(t).component(index);
+                    *min.component_mut(index) = min.component(index).min(extremum);
+                    *max.component_mut(index) = max.component(index).max(extremum);
+                } // for ..
+            } // for ..
+
+            (min, max)
+        } // fn ..
+    } // impl ..
+
+
+    //#####################
+    // C A T M U L L R O M
+    //#####################
+
+    impl<V: Vector<Scalar = f32>> CatmullRom<V> {
+        /// Number of cubic segments, one less than the interpolated points
+        pub fn segment_count(&self) -> usize {
+            assert!(self.0.len() >= 4usize, "a Catmull-Rom spline needs at least four control points");
+            self.0.len() - 3usize
+        } // fn ..
+
+
+        /// Segment `index` expressed as the equivalent cubic Bézier curve
+        pub fn segment(&self, index: usize) -> CubicBezier<V> {
+            let (p0, p1, p2, p3) = (self.0[index], self.0[index + 1usize], self.0[index + 2usize], self.0[index + 3usize]);
+            CubicBezier(p1, p1 + (p2 - p0) / 6f32, p2 - (p3 - p1) / 6f32, p2)
+        } // fn ..
+    } // impl ..
+
+
+    impl<V: Vector<Scalar = f32>> Curve<V> for CatmullRom<V> {
+        fn eval(&self, t: f32) -> V {
+            let (index, t) = locate_segment(t, self.segment_count());
+            self.segment(index).eval(t)
+        } // fn ..
+
+
+        fn derivative(&self, t: f32) -> V {
+            let segments   = self.segment_count();
+            let (index, t) = locate_segment(t, segments);
+            self.segment(index).derivative(t) * segments as f32
+        } // fn ..
+
+
+        fn bounding_box(&self) -> (V, V) {
+            spline_bounding_box((0usize..self.segment_count()).map(|index| self.segment(index)))
+        } // fn ..
+    } // impl ..
+
+
+    //#################
+    // B - S P L I N E
+    //#################
+
+    impl<V: Vector<Scalar = f32>> BSpline<V> {
+        /// Number of cubic segments, three less than the control points
+        pub fn segment_count(&self) -> usize {
+            assert!(self.0.len() >= 4usize, "a cubic B-spline needs at least four control points");
+            self.0.len() - 3usize
+        } // fn ..
+
+
+        /// Segment `index` expressed as the equivalent cubic Bézier curve
+        pub fn segment(&self, index: usize) -> CubicBezier<V> {
+            let (p0, p1, p2, p3) = (self.0[index], self.0[index + 1usize], self.0[index + 2usize], self.0[index + 3usize]);
+            CubicBezier(
+                (p0 + p1 * 4f32 + p2) / 6f32,
+                (p1 * 2f32 + p2) / 3f32,
+                (p1 + p2 * 2f32) / 3f32,
+                (p1 + p2 * 4f32 + p3) / 6f32,
+            ) // CubicBezier()
+        } // fn ..
+    } // impl ..
+
+
+    impl<V: Vector<Scalar = f32>> Curve<V> for BSpline<V> {
+        fn eval(&self, t: f32) -> V {
+            let (index, t) = locate_segment(t, self.segment_count());
+            self.segment(index).eval(t)
+        } // fn ..
+
+
+        fn derivative(&self, t: f32) -> V {
+            let segments   = self.segment_count();
+            let (index, t) = locate_segment(t, segments);
+            self.segment(index).derivative(t) * segments as f32
+        } // fn ..
+
+
+        fn bounding_box(&self) -> (V, V) {
+            spline_bounding_box((0usize..self.segment_count()).map(|index| self.segment(index)))
+        } // fn ..
+    } // impl ..
+
+
+    //#######################
+    // A R C   L E N G T H
+    //#######################
+
+    impl ArcLengthTable {
+        /// Measures the polyline through `samples + 1` evenly spaced points
+        pub fn new<V: Vector<Scalar = f32>, C: Curve<V>>(curve: &C, samples: usize) -> Self {
+            let samples     = samples.max(1usize);
+            let mut lengths = Vec::with_capacity(samples + 1usize);
+            let mut length  = 0f32;
+            let mut last    = curve.eval(0f32);
+
+            lengths.push(0f32);
+            for index in 1usize..=samples {
+                let point = curve.eval(index as f32 / samples as f32);
+                length   += (point - last).magnitude();
+                last      = point;
+                lengths.push(length);
+            } // for ..
+
+            ArcLengthTable { lengths }
+        } // fn ..
+
+
+        pub fn length(&self) -> f32 { self.lengths[self.lengths.len() - 1usize] }
+
+
+        /// Parameter of the point lying `distance` along the curve, clamped to its ends
+        pub fn parameter_at(&self, distance: f32) -> f32 {
+            let samples = self.lengths.len() - 1usize;
+            if distance <= 0f32           { return 0f32 }
+            if distance >= self.length() { return 1f32 }
+
+            let index = self.lengths.partition_point(|&length| length < distance);
+            let (before, after) = (self.lengths[index - 1usize], self.lengths[index]);
+            let fraction = if after > before { (distance - before) / (after - before) } else { 0f32 };
+            (index as f32 - 1f32 + fraction) / samples as f32
+        } // fn ..
+
+
+        /// Parameter of the point lying at `fraction` of the total length,
+        /// so that evenly spaced fractions move at constant speed
+        pub fn parameter_at_fraction(&self, fraction: f32) -> f32 {
+            self.parameter_at(fraction * self.length())
+        } // fn ..
+    } // impl ..
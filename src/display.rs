@@ -0,0 +1,212 @@
+//#########################
+// D E P E N D E N C I E S
+//#########################
+
+    use std::fmt;
+    use std::error::Error;
+    use std::str::FromStr;
+
+    use crate::traits::Number;
+    use crate::vectors::{Vec2, Vec3, Vec4};
+    use crate::matrices::{Mat3, Mat4};
+    use crate::Angle;
+
+
+//#############
+// M A C R O S
+//#############
+
+    macro_rules! impl_vec_text(
+        ($($V: ident($($i: tt),*) => $len: literal);*$(;)*) => {$(
+
+            impl<T: Number + fmt::Display> fmt::Display for $V<T> {
+                /// Writes `(x, y, ..)`, width and precision apply to every component
+                fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                    let components = [$(self.$i),*];
+                    f.write_str("(")?;
+                    for (index, component) in components.iter().enumerate() {
+                        if index > 0usize { f.write_str(", ")? }
+                        fmt::Display::fmt(component, f)?;
+                    } // for ..
+                    f.write_str(")")
+                } // fn fmt()
+            } // impl fmt::Display ..
+
+
+            impl<T: Number + FromStr> FromStr for $V<T> {
+                type Err = ParseError;
+                /// Parses `(x, y, ..)` or `[x, y, ..]`
+                fn from_str(s: &str) -> Result<Self, Self::Err> {
+                    let components = parse_components::<T, $len>(strip_delimiters(s)?, 0usize)?;
+                    Ok($V($(components[$i]),*))
+                } // fn from_str()
+            } // impl FromStr ..
+
+        )*}
+    ); // impl_vec_text()
+
+
+    macro_rules! impl_mat_text(
+        ($($M: ident<$V: ident>($($i: tt),*) => $len: literal);*$(;)*) => {$(
+
+            impl<T: Number + fmt::Display> fmt::Display for $M<T> {
+                /// Writes the matrix row by row with aligned columns,
+                /// width and precision apply to every element
+                fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                    let columns  = [$(<[T; $len]>::from(self.$i)),*];
+                    let elements = columns.map(|column| column.map(|element| match f.precision() {
+                        Some(precision) => format!("{:.*}", precision, element),
+                        None            => format!("{}", element),
+                    })); // let ..
+                    let width = elements.iter().flatten().map(String::len).max().unwrap_or(0usize).max(f.width().unwrap_or(0usize));
+
+                    for row in 0usize..$len {
+                        f.write_str(if row == 0usize { "[[" } else { " [" })?;
+                        for column in 0usize..$len {
+                            if column > 0usize { f.write_str(", ")? }
+                            write!(f, "{:>width$}", elements[column][row], width = width)?;
+                        } // for ..
+                        f.write_str(if row + 1usize == $len { "]]" } else { "],\n" })?;
+                    } // for ..
+
+                    Ok(())
+                } // fn fmt()
+            } // impl fmt::Display ..
+
+
+            impl<T: Number + FromStr> FromStr for $M<T> {
+                type Err = ParseError;
+                /// Parses `[[..], [..], ..]` row by row, parentheses may replace any pair of brackets
+                fn from_str(s: &str) -> Result<Self, Self::Err> {
+                    let mut rows  = [[T::ZERO; $len]; $len];
+                    let mut count = 0usize;
+                    let mut rest  = strip_delimiters(s)?.trim_start();
+
+                    while !rest.is_empty() {
+                        if count > 0usize { rest = rest.strip_prefix(',').ok_or(ParseError::Delimiter)?.trim_start() }
+                        let (row, after) = split_row(rest)?;
+                        if count < $len { rows[count] = parse_components::<T, $len>(row, count * $len)? }
+                        count += 1usize;
+                        rest   = after.trim_start();
+                    } // while ..
+
+                    if count != $len { return Err(ParseError::Length { expected: $len, found: count }) }
+                    let column = |index: usize| $V::from(rows.map(|row| row[index]));
+                    Ok($M($(column($i)),*))
+                } // fn from_str()
+            } // impl FromStr ..
+
+        )*}
+    ); // impl_mat_text()
+
+
+//#######################
+// D E F I N I T I O N S
+//#######################
+
+    /// Error returned when parsing vectors, matrices or angles from text
+    #[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+    pub enum ParseError {
+        /// Brackets are missing or mismatched
+        Delimiter,
+        /// Wrong number of components or rows
+        Length { expected: usize, found: usize },
+        /// Element at `index`, counted row by row, is not a valid scalar
+        Component { index: usize },
+        /// Angle does not end with a `deg`, `°` or `b` unit
+        Unit,
+    } // enum ..
+
+
+    fn strip_delimiters(s: &str) -> Result<&str, ParseError> {
+        let s = s.trim();
+        s.strip_prefix('(').and_then(|s| s.strip_suffix(')'))
+            .or_else(|| s.strip_prefix('[').and_then(|s| s.strip_suffix(']')))
+            .ok_or(ParseError::Delimiter)
+    } // fn ..
+
+
+    /// Splits the row at the start of `s` from what follows, the row closes with the bracket
+    /// matching its opening one
+    fn split_row(s: &str) -> Result<(&str, &str), ParseError> {
+        let closing = match s.chars().next() {
+            Some('(') => ')',
+            Some('[') => ']',
+            _         => return Err(ParseError::Delimiter),
+        }; // let ..
+        let end = s.find(closing).ok_or(ParseError::Delimiter)?;
+        let row = &s[1usize..end];
+        if row.contains(['(', ')', '[', ']']) { return Err(ParseError::Delimiter) }
+        Ok((row, &s[end + 1usize..]))
+    } // fn ..
+
+
+    fn parse_components<T: Number + FromStr, const N: usize>(s: &str, first_index: usize) -> Result<[T; N], ParseError> {
+        let mut components = [T::ZERO; N];
+        let mut count      = 0usize;
+
+        for (index, component) in s.split(',').enumerate() {
+            if index < N { components[index] = component.trim().parse().map_err(|_| ParseError::Component { index: first_index + index })? }
+            count += 1usize;
+        } // for ..
+
+        if count != N { return Err(ParseError::Length { expected: N, found: count }) }
+        Ok(components)
+    } // fn ..
+
+
+//###############################
+// I M P L E M E N T A T I O N S
+//###############################
+
+    impl_vec_text!(
+        Vec2(0, 1)       => 2;
+        Vec3(0, 1, 2)    => 3;
+        Vec4(0, 1, 2, 3) => 4;
+    ); // impl_vec_text!()
+
+    impl_mat_text!(
+        Mat3<Vec3>(0, 1, 2)    => 3;
+        Mat4<Vec4>(0, 1, 2, 3) => 4;
+    ); // impl_mat_text!()
+
+
+    impl fmt::Display for Angle {
+        /// Writes degrees such as `90deg`, or the raw byte such as `64b` with `{:#}`
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            if f.alternate() { return write!(f, "{}b", self.0) }
+            match f.precision() {
+                Some(precision) => write!(f, "{:.*}deg", precision, self.to_degrees()),
+                None            => write!(f, "{}deg", self.to_degrees()),
+            } // match ..
+        } // fn ..
+    } // impl ..
+
+
+    impl FromStr for Angle {
+        type Err = ParseError;
+        /// Parses degrees such as `90deg` or `90°`, or a raw byte such as `64b`
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            let s = s.trim();
+            if let Some(degrees) = s.strip_suffix("deg").or_else(|| s.strip_suffix('°')) {
+                degrees.trim().parse::<f32>().map(Angle::from_degrees).map_err(|_| ParseError::Component { index: 0usize })
+            } else if let Some(byte) = s.strip_suffix('b') {
+                byte.trim().parse::<u8>().map(Angle).map_err(|_| ParseError::Component { index: 0usize })
+            } else { Err(ParseError::Unit) }
+        } // fn ..
+    } // impl ..
+
+
+    impl fmt::Display for ParseError {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            match self {
+                ParseError::Delimiter                  => f.write_str("missing or mismatched brackets"),
+                ParseError::Length { expected, found } => write!(f, "expected {} elements, found {}", expected, found),
+                ParseError::Component { index }        => write!(f, "element {} is not a valid number", index),
+                ParseError::Unit                       => f.write_str("missing `deg`, `°` or `b` angle unit"),
+            } // match ..
+        } // fn ..
+    } // impl ..
+
+
+    impl Error for ParseError {}
@@ -0,0 +1,150 @@
+//#########################
+// D E P E N D E N C I E S
+//#########################
+
+    use crate::traits::Zero;
+    use crate::vectors::{Vec2, Vec3};
+
+
+//#######################
+// D E F I N I T I O N S
+//#######################
+
+    // Threshold tables are indexed `[y][x]` and tile the screen, their ranks are spread so that
+    // any threshold level lights evenly scattered pixels. A channel goes up to the next level
+    // when its remainder beats the threshold of its pixel, see `quantize`. Buffers are rows of
+    // `width` pixels, colours are compared by squared distance in RGB.
+
+    pub const BAYER_2X2: [[u8; 2usize]; 2usize] = [
+        [0, 2],
+        [3, 1],
+    ]; // const ..
+
+
+    pub const BAYER_4X4: [[u8; 4usize]; 4usize] = [
+        [ 0,  8,  2, 10],
+        [12,  4, 14,  6],
+        [ 3, 11,  1,  9],
+        [15,  7, 13,  5],
+    ]; // const ..
+
+
+    pub const BAYER_8X8: [[u8; 8usize]; 8usize] = [
+        [ 0, 32,  8, 40,  2, 34, 10, 42],
+        [48, 16, 56, 24, 50, 18, 58, 26],
+        [12, 44,  4, 36, 14, 46,  6, 38],
+        [60, 28, 52, 20, 62, 30, 54, 22],
+        [ 3, 35, 11, 43,  1, 33,  9, 41],
+        [51, 19, 59, 27, 49, 17, 57, 25],
+        [15, 47,  7, 39, 13, 45,  5, 37],
+        [63, 31, 55, 23, 61, 29, 53, 21],
+    ]; // const ..
+
+
+    /// Void-and-cluster ranks of every pixel, free of the cross-hatch patterns of Bayer tables
+    pub const BLUE_NOISE_16X16: [[u8; 16usize]; 16usize] = [
+        [138, 155, 113,  83, 199,  34, 104, 211, 167, 240,   3, 221,  26, 133, 103, 244],
+        [ 73, 209,  25, 245, 160,  62, 185,  17, 130,  81, 142, 112, 184,  44, 195,  23],
+        [ 94, 225,  58, 135,   1, 227, 118, 254,  55, 217,  38, 165, 247,  87, 232, 171],
+        [  8, 178, 108, 189,  79, 150,  92,  32, 162, 106, 198,  66,  14, 148,  59, 125],
+        [152,  43, 250,  29, 214,  48, 181, 206, 136,   7, 229,  98, 128, 219,  33, 205],
+        [235, 131,  90, 164, 123, 233,  11,  69, 243,  86, 175,  46, 190, 158, 109,  78],
+        [ 22,  68, 207,  16,  63, 141, 100, 169, 116,  27, 144, 239,  74,   5, 249, 172],
+        [191, 143, 228, 110, 194, 248,  42, 188, 224,  56, 208,  20, 119, 201,  52,  99],
+        [121,   2,  50, 173,  28,  80, 154,  18,  75, 129, 163,  97, 182, 140,  37, 220],
+        [ 72, 255, 153,  93, 203, 132, 216, 107, 196, 253,  40, 226,  61, 242,  91, 157],
+        [183,  35, 114, 238,  65,   9, 236,  45, 146,   0, 115,  85,  24, 168,  10, 213],
+        [134,  84, 197,  21, 179, 122, 170,  95,  60, 180, 156, 193, 127, 204, 105,  51],
+        [ 19, 231, 147,  47, 212,  82,  31, 202, 241,  76, 218,  49, 234,  70, 145, 246],
+        [124,  67, 166, 101, 137, 251, 159, 111,  15, 139,  30, 102,  12, 174,  36, 186],
+        [ 96, 215,  13, 237,  71,   6,  54, 187, 230, 120, 200, 151, 252, 117,  77, 223],
+        [ 53, 192,  41, 176, 126, 222, 149,  88,  39,  64, 177,  89,  57, 210, 161,   4],
+    ]; // const ..
+
+
+    /// Error shares of the neighbours ahead as `(dx, dy, weight)` over the divisor
+    const FLOYD_STEINBERG: ([(isize, usize, i32); 4usize], i32) = ([(1, 0, 7), (-1, 1, 3), (0, 1, 5), (1, 1, 1)], 16i32);
+
+    /// Only diffuses three quarters of the error, trading accuracy for contrast
+    const ATKINSON: ([(isize, usize, i32); 6usize], i32) = ([(1, 0, 1), (2, 0, 1), (-1, 1, 1), (0, 1, 1), (1, 1, 1), (0, 2, 1)], 8i32);
+
+
+//###############################
+// I M P L E M E N T A T I O N S
+//###############################
+
+    /// Threshold of a pixel in a Bayer table, scaled to the middle of its rank's share of `0..=255`
+    pub fn bayer_threshold<const N: usize>(matrix: &[[u8; N]; N], pixel: Vec2<u16>) -> u8 {
+        let rank = matrix[pixel.1 as usize % N][pixel.0 as usize % N] as usize;
+        ((2usize * rank + 1usize) * 128usize / (N * N)) as u8
+    } // fn ..
+
+
+    pub fn blue_noise_threshold(pixel: Vec2<u16>) -> u8 {
+        BLUE_NOISE_16X16[pixel.1 as usize % 16usize][pixel.0 as usize % 16usize]
+    } // fn ..
+
+
+    /// Rounds a channel to one of `levels` evenly spaced values, up when its remainder beats the
+    /// threshold, so that a channel halfway between two levels goes up for half the thresholds
+    pub fn quantize(value: u8, levels: u8, threshold: u8) -> u8 {
+        if levels < 2u8 { return 0u8 }
+        let steps          = levels as u32 - 1u32;
+        let scaled         = value as u32 * steps;
+        let (base, remain) = (scaled / 255u32, scaled % 255u32);
+        let level          = if remain * 256u32 > threshold as u32 * 255u32 { base + 1u32 } else { base };
+        (level * 255u32 / steps) as u8
+    } // fn ..
+
+
+    /// Index of the palette colour closest to `colour`, the first one on ties, `None` when the
+    /// palette is empty
+    pub fn nearest_colour(palette: &[Vec3<u8>], colour: Vec3<u8>) -> Option<usize> {
+        let squared_distance = |other: Vec3<u8>| {
+            let (x, y, z) = (colour.0 as i32 - other.0 as i32, colour.1 as i32 - other.1 as i32, colour.2 as i32 - other.2 as i32);
+            x * x + y * y + z * z
+        }; // let ..
+        (0usize..palette.len()).min_by_key(|&index| squared_distance(palette[index]))
+    } // fn ..
+
+
+    /// Replaces every pixel with its nearest palette colour, passing the error on to the
+    /// pixels to the right and below, leaves the pixels as they are when the palette is empty
+    pub fn floyd_steinberg(pixels: &mut [Vec3<u8>], width: usize, palette: &[Vec3<u8>]) {
+        diffuse(pixels, width, palette, &FLOYD_STEINBERG.0, FLOYD_STEINBERG.1)
+    } // fn ..
+
+
+    /// Like `floyd_steinberg` with Bill Atkinson's kernel, which keeps flat areas cleaner but
+    /// washes out the shadows and highlights
+    pub fn atkinson(pixels: &mut [Vec3<u8>], width: usize, palette: &[Vec3<u8>]) {
+        diffuse(pixels, width, palette, &ATKINSON.0, ATKINSON.1)
+    } // fn ..
+
+
+    fn diffuse(pixels: &mut [Vec3<u8>], width: usize, palette: &[Vec3<u8>], kernel: &[(isize, usize, i32)], divisor: i32) {
+        if palette.is_empty() || width == 0usize { return }
+
+        // Errors of the rows ahead, times the divisor, padded by two pixels on each side
+        let rows       = kernel.iter().map(|&(_, dy, _)| dy).max().unwrap_or(0usize) + 1usize;
+        let stride     = width + 4usize;
+        let mut errors = vec![Vec3::<i32>::ZERO; rows * stride];
+
+        for (y, row) in pixels.chunks_mut(width).enumerate() {
+            let line = (y % rows) * stride + 2usize;
+            for (x, pixel) in row.iter_mut().enumerate() {
+                let error   = errors[line + x];
+                let channel = |value: u8, error: i32| (value as i32 + error / divisor).clamp(0i32, 255i32);
+                let wanted  = Vec3(channel(pixel.0, error.0), channel(pixel.1, error.1), channel(pixel.2, error.2));
+
+                let Some(index) = nearest_colour(palette, Vec3(wanted.0 as u8, wanted.1 as u8, wanted.2 as u8)) else { return };
+                *pixel = palette[index];
+
+                let error = wanted - Vec3(pixel.0 as i32, pixel.1 as i32, pixel.2 as i32);
+                for &(dx, dy, weight) in kernel {
+                    errors[((y + dy) % rows) * stride + (x + 2usize).wrapping_add_signed(dx)] += error * weight;
+                } // for ..
+            } // for ..
+            errors[line - 2usize..line - 2usize + stride].fill(Vec3::ZERO);
+        } // for ..
+    } // fn ..
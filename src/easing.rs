@@ -0,0 +1,1627 @@
+//#########################
+// D E P E N D E N C I E S
+//#########################
+
+    use crate::interpolation::{Lerp, FixedLerp};
+    use crate::Angle;
+
+
+//#######################
+// D E F I N I T I O N S
+//#######################
+
+    /// Standard easing curves mapping a progress in `[0, 1]` to an eased progress.
+    ///
+    /// Back and elastic curves overshoot that range, which the float and signed
+    /// forms preserve and the unsigned fixed-point forms clamp.
+    #[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+    pub enum Easing {
+        QuadIn,    QuadOut,    QuadInOut,
+        CubicIn,   CubicOut,   CubicInOut,
+        SineIn,    SineOut,    SineInOut,
+        ExpoIn,    ExpoOut,    ExpoInOut,
+        BackIn,    BackOut,    BackInOut,
+        ElasticIn, ElasticOut, ElasticInOut,
+        BounceIn,  BounceOut,  BounceInOut,
+    } // enum ..
+
+
+    const BACK_C1:    f32 = 1.70158f32;
+    const BACK_C2:    f32 = BACK_C1 * 1.525f32;
+    const BACK_C3:    f32 = BACK_C1 + 1f32;
+    const ELASTIC_C4: f32 = std::f32::consts::TAU / 3f32;
+    const ELASTIC_C5: f32 = std::f32::consts::TAU / 4.5f32;
+    const BOUNCE_N1:  f32 = 7.5625f32;
+    const BOUNCE_D1:  f32 = 2.75f32;
+    const RAD_TO_BYTE_RATIO: f32 = 256f32 / std::f32::consts::TAU;
+
+
+    // Curves sampled at 65 evenly spaced progresses, `65535` standing for one
+    const QUAD_IN_LOOKUP: [i32; 65usize] = [
+        0i32,
+        16i32,
+        64i32,
+        144i32,
+        256i32,
+        400i32,
+        576i32,
+        784i32,
+        1024i32,
+        1296i32,
+        1600i32,
+        1936i32,
+        2304i32,
+        2704i32,
+        3136i32,
+        3600i32,
+        4096i32,
+        4624i32,
+        5184i32,
+        5776i32,
+        6400i32,
+        7056i32,
+        7744i32,
+        8464i32,
+        9216i32,
+        10000i32,
+        10816i32,
+        11664i32,
+        12544i32,
+        13456i32,
+        14400i32,
+        15376i32,
+        16384i32,
+        17424i32,
+        18496i32,
+        19600i32,
+        20736i32,
+        21904i32,
+        23104i32,
+        24336i32,
+        25600i32,
+        26896i32,
+        28224i32,
+        29584i32,
+        30976i32,
+        32400i32,
+        33855i32,
+        35343i32,
+        36863i32,
+        38415i32,
+        39999i32,
+        41615i32,
+        43263i32,
+        44943i32,
+        46655i32,
+        48399i32,
+        50175i32,
+        51983i32,
+        53823i32,
+        55695i32,
+        57599i32,
+        59535i32,
+        61503i32,
+        63503i32,
+        65535i32,
+    ]; // const ..
+
+
+    const QUAD_OUT_LOOKUP: [i32; 65usize] = [
+        0i32,
+        2032i32,
+        4032i32,
+        6000i32,
+        7936i32,
+        9840i32,
+        11712i32,
+        13552i32,
+        15360i32,
+        17136i32,
+        18880i32,
+        20592i32,
+        22272i32,
+        23920i32,
+        25536i32,
+        27120i32,
+        28672i32,
+        30192i32,
+        31680i32,
+        33135i32,
+        34559i32,
+        35951i32,
+        37311i32,
+        38639i32,
+        39935i32,
+        41199i32,
+        42431i32,
+        43631i32,
+        44799i32,
+        45935i32,
+        47039i32,
+        48111i32,
+        49151i32,
+        50159i32,
+        51135i32,
+        52079i32,
+        52991i32,
+        53871i32,
+        54719i32,
+        55535i32,
+        56319i32,
+        57071i32,
+        57791i32,
+        58479i32,
+        59135i32,
+        59759i32,
+        60351i32,
+        60911i32,
+        61439i32,
+        61935i32,
+        62399i32,
+        62831i32,
+        63231i32,
+        63599i32,
+        63935i32,
+        64239i32,
+        64511i32,
+        64751i32,
+        64959i32,
+        65135i32,
+        65279i32,
+        65391i32,
+        65471i32,
+        65519i32,
+        65535i32,
+    ]; // const ..
+
+
+    const QUAD_IN_OUT_LOOKUP: [i32; 65usize] = [
+        0i32,
+        32i32,
+        128i32,
+        288i32,
+        512i32,
+        800i32,
+        1152i32,
+        1568i32,
+        2048i32,
+        2592i32,
+        3200i32,
+        3872i32,
+        4608i32,
+        5408i32,
+        6272i32,
+        7200i32,
+        8192i32,
+        9248i32,
+        10368i32,
+        11552i32,
+        12800i32,
+        14112i32,
+        15488i32,
+        16928i32,
+        18432i32,
+        20000i32,
+        21632i32,
+        23328i32,
+        25088i32,
+        26912i32,
+        28800i32,
+        30752i32,
+        32768i32,
+        34783i32,
+        36735i32,
+        38623i32,
+        40447i32,
+        42207i32,
+        43903i32,
+        45535i32,
+        47103i32,
+        48607i32,
+        50047i32,
+        51423i32,
+        52735i32,
+        53983i32,
+        55167i32,
+        56287i32,
+        57343i32,
+        58335i32,
+        59263i32,
+        60127i32,
+        60927i32,
+        61663i32,
+        62335i32,
+        62943i32,
+        63487i32,
+        63967i32,
+        64383i32,
+        64735i32,
+        65023i32,
+        65247i32,
+        65407i32,
+        65503i32,
+        65535i32,
+    ]; // const ..
+
+
+    const CUBIC_IN_LOOKUP: [i32; 65usize] = [
+        0i32,
+        0i32,
+        2i32,
+        7i32,
+        16i32,
+        31i32,
+        54i32,
+        86i32,
+        128i32,
+        182i32,
+        250i32,
+        333i32,
+        432i32,
+        549i32,
+        686i32,
+        844i32,
+        1024i32,
+        1228i32,
+        1458i32,
+        1715i32,
+        2000i32,
+        2315i32,
+        2662i32,
+        3042i32,
+        3456i32,
+        3906i32,
+        4394i32,
+        4921i32,
+        5488i32,
+        6097i32,
+        6750i32,
+        7448i32,
+        8192i32,
+        8984i32,
+        9826i32,
+        10719i32,
+        11664i32,
+        12663i32,
+        13718i32,
+        14830i32,
+        16000i32,
+        17230i32,
+        18522i32,
+        19876i32,
+        21296i32,
+        22781i32,
+        24334i32,
+        25955i32,
+        27648i32,
+        29412i32,
+        31250i32,
+        33162i32,
+        35151i32,
+        37219i32,
+        39365i32,
+        41593i32,
+        43903i32,
+        46298i32,
+        48777i32,
+        51344i32,
+        53999i32,
+        56744i32,
+        59581i32,
+        62511i32,
+        65535i32,
+    ]; // const ..
+
+
+    const CUBIC_OUT_LOOKUP: [i32; 65usize] = [
+        0i32,
+        3024i32,
+        5954i32,
+        8791i32,
+        11536i32,
+        14191i32,
+        16758i32,
+        19237i32,
+        21632i32,
+        23942i32,
+        26170i32,
+        28316i32,
+        30384i32,
+        32373i32,
+        34285i32,
+        36123i32,
+        37887i32,
+        39580i32,
+        41201i32,
+        42754i32,
+        44239i32,
+        45659i32,
+        47013i32,
+        48305i32,
+        49535i32,
+        50705i32,
+        51817i32,
+        52872i32,
+        53871i32,
+        54816i32,
+        55709i32,
+        56551i32,
+        57343i32,
+        58087i32,
+        58785i32,
+        59438i32,
+        60047i32,
+        60614i32,
+        61141i32,
+        61629i32,
+        62079i32,
+        62493i32,
+        62873i32,
+        63220i32,
+        63535i32,
+        63820i32,
+        64077i32,
+        64307i32,
+        64511i32,
+        64691i32,
+        64849i32,
+        64986i32,
+        65103i32,
+        65202i32,
+        65285i32,
+        65353i32,
+        65407i32,
+        65449i32,
+        65481i32,
+        65504i32,
+        65519i32,
+        65528i32,
+        65533i32,
+        65535i32,
+        65535i32,
+    ]; // const ..
+
+
+    const CUBIC_IN_OUT_LOOKUP: [i32; 65usize] = [
+        0i32,
+        1i32,
+        8i32,
+        27i32,
+        64i32,
+        125i32,
+        216i32,
+        343i32,
+        512i32,
+        729i32,
+        1000i32,
+        1331i32,
+        1728i32,
+        2197i32,
+        2744i32,
+        3375i32,
+        4096i32,
+        4913i32,
+        5832i32,
+        6859i32,
+        8000i32,
+        9261i32,
+        10648i32,
+        12167i32,
+        13824i32,
+        15625i32,
+        17576i32,
+        19683i32,
+        21952i32,
+        24389i32,
+        27000i32,
+        29791i32,
+        32768i32,
+        35744i32,
+        38535i32,
+        41146i32,
+        43583i32,
+        45852i32,
+        47959i32,
+        49910i32,
+        51711i32,
+        53368i32,
+        54887i32,
+        56274i32,
+        57535i32,
+        58676i32,
+        59703i32,
+        60622i32,
+        61439i32,
+        62160i32,
+        62791i32,
+        63338i32,
+        63807i32,
+        64204i32,
+        64535i32,
+        64806i32,
+        65023i32,
+        65192i32,
+        65319i32,
+        65410i32,
+        65471i32,
+        65508i32,
+        65527i32,
+        65534i32,
+        65535i32,
+    ]; // const ..
+
+
+    const SINE_IN_LOOKUP: [i32; 65usize] = [
+        0i32,
+        20i32,
+        79i32,
+        178i32,
+        316i32,
+        493i32,
+        709i32,
+        965i32,
+        1259i32,
+        1592i32,
+        1964i32,
+        2374i32,
+        2822i32,
+        3308i32,
+        3831i32,
+        4391i32,
+        4989i32,
+        5622i32,
+        6292i32,
+        6998i32,
+        7738i32,
+        8514i32,
+        9324i32,
+        10168i32,
+        11045i32,
+        11955i32,
+        12897i32,
+        13871i32,
+        14876i32,
+        15911i32,
+        16977i32,
+        18071i32,
+        19195i32,
+        20346i32,
+        21524i32,
+        22729i32,
+        23960i32,
+        25216i32,
+        26496i32,
+        27799i32,
+        29126i32,
+        30474i32,
+        31843i32,
+        33233i32,
+        34642i32,
+        36070i32,
+        37515i32,
+        38978i32,
+        40456i32,
+        41949i32,
+        43457i32,
+        44978i32,
+        46511i32,
+        48056i32,
+        49611i32,
+        51176i32,
+        52750i32,
+        54331i32,
+        55919i32,
+        57513i32,
+        59111i32,
+        60714i32,
+        62319i32,
+        63927i32,
+        65535i32,
+    ]; // const ..
+
+
+    const SINE_OUT_LOOKUP: [i32; 65usize] = [
+        0i32,
+        1608i32,
+        3216i32,
+        4821i32,
+        6424i32,
+        8022i32,
+        9616i32,
+        11204i32,
+        12785i32,
+        14359i32,
+        15924i32,
+        17479i32,
+        19024i32,
+        20557i32,
+        22078i32,
+        23586i32,
+        25079i32,
+        26557i32,
+        28020i32,
+        29465i32,
+        30893i32,
+        32302i32,
+        33692i32,
+        35061i32,
+        36409i32,
+        37736i32,
+        39039i32,
+        40319i32,
+        41575i32,
+        42806i32,
+        44011i32,
+        45189i32,
+        46340i32,
+        47464i32,
+        48558i32,
+        49624i32,
+        50659i32,
+        51664i32,
+        52638i32,
+        53580i32,
+        54490i32,
+        55367i32,
+        56211i32,
+        57021i32,
+        57797i32,
+        58537i32,
+        59243i32,
+        59913i32,
+        60546i32,
+        61144i32,
+        61704i32,
+        62227i32,
+        62713i32,
+        63161i32,
+        63571i32,
+        63943i32,
+        64276i32,
+        64570i32,
+        64826i32,
+        65042i32,
+        65219i32,
+        65357i32,
+        65456i32,
+        65515i32,
+        65535i32,
+    ]; // const ..
+
+
+    const SINE_IN_OUT_LOOKUP: [i32; 65usize] = [
+        0i32,
+        39i32,
+        158i32,
+        355i32,
+        630i32,
+        982i32,
+        1411i32,
+        1915i32,
+        2494i32,
+        3146i32,
+        3869i32,
+        4662i32,
+        5522i32,
+        6448i32,
+        7438i32,
+        8488i32,
+        9597i32,
+        10762i32,
+        11980i32,
+        13248i32,
+        14563i32,
+        15922i32,
+        17321i32,
+        18758i32,
+        20228i32,
+        21728i32,
+        23256i32,
+        24806i32,
+        26375i32,
+        27960i32,
+        29556i32,
+        31160i32,
+        32767i32,
+        34375i32,
+        35979i32,
+        37575i32,
+        39160i32,
+        40729i32,
+        42279i32,
+        43807i32,
+        45307i32,
+        46777i32,
+        48214i32,
+        49613i32,
+        50972i32,
+        52287i32,
+        53555i32,
+        54773i32,
+        55938i32,
+        57047i32,
+        58097i32,
+        59087i32,
+        60013i32,
+        60873i32,
+        61666i32,
+        62389i32,
+        63041i32,
+        63620i32,
+        64124i32,
+        64553i32,
+        64905i32,
+        65180i32,
+        65377i32,
+        65496i32,
+        65535i32,
+    ]; // const ..
+
+
+    const EXPO_IN_LOOKUP: [i32; 65usize] = [
+        0i32,
+        71i32,
+        79i32,
+        89i32,
+        99i32,
+        110i32,
+        123i32,
+        137i32,
+        152i32,
+        170i32,
+        189i32,
+        211i32,
+        235i32,
+        262i32,
+        292i32,
+        325i32,
+        362i32,
+        403i32,
+        450i32,
+        501i32,
+        558i32,
+        622i32,
+        693i32,
+        773i32,
+        861i32,
+        960i32,
+        1069i32,
+        1192i32,
+        1328i32,
+        1480i32,
+        1649i32,
+        1838i32,
+        2048i32,
+        2282i32,
+        2543i32,
+        2834i32,
+        3158i32,
+        3520i32,
+        3922i32,
+        4371i32,
+        4871i32,
+        5428i32,
+        6049i32,
+        6741i32,
+        7512i32,
+        8371i32,
+        9329i32,
+        10396i32,
+        11585i32,
+        12910i32,
+        14387i32,
+        16033i32,
+        17867i32,
+        19910i32,
+        22188i32,
+        24726i32,
+        27554i32,
+        30706i32,
+        34218i32,
+        38132i32,
+        42494i32,
+        47355i32,
+        52772i32,
+        58808i32,
+        65535i32,
+    ]; // const ..
+
+
+    const EXPO_OUT_LOOKUP: [i32; 65usize] = [
+        0i32,
+        6727i32,
+        12763i32,
+        18180i32,
+        23041i32,
+        27403i32,
+        31317i32,
+        34829i32,
+        37981i32,
+        40809i32,
+        43347i32,
+        45625i32,
+        47668i32,
+        49502i32,
+        51148i32,
+        52625i32,
+        53950i32,
+        55139i32,
+        56206i32,
+        57164i32,
+        58023i32,
+        58794i32,
+        59486i32,
+        60107i32,
+        60664i32,
+        61164i32,
+        61613i32,
+        62015i32,
+        62377i32,
+        62701i32,
+        62992i32,
+        63253i32,
+        63487i32,
+        63697i32,
+        63886i32,
+        64055i32,
+        64207i32,
+        64343i32,
+        64466i32,
+        64575i32,
+        64674i32,
+        64762i32,
+        64842i32,
+        64913i32,
+        64977i32,
+        65034i32,
+        65085i32,
+        65132i32,
+        65173i32,
+        65210i32,
+        65243i32,
+        65273i32,
+        65300i32,
+        65324i32,
+        65346i32,
+        65365i32,
+        65383i32,
+        65398i32,
+        65412i32,
+        65425i32,
+        65436i32,
+        65446i32,
+        65456i32,
+        65464i32,
+        65535i32,
+    ]; // const ..
+
+
+    const EXPO_IN_OUT_LOOKUP: [i32; 65usize] = [
+        0i32,
+        40i32,
+        49i32,
+        61i32,
+        76i32,
+        95i32,
+        117i32,
+        146i32,
+        181i32,
+        225i32,
+        279i32,
+        347i32,
+        431i32,
+        535i32,
+        664i32,
+        825i32,
+        1024i32,
+        1272i32,
+        1579i32,
+        1961i32,
+        2435i32,
+        3024i32,
+        3756i32,
+        4664i32,
+        5793i32,
+        7193i32,
+        8933i32,
+        11094i32,
+        13777i32,
+        17109i32,
+        21247i32,
+        26386i32,
+        32768i32,
+        39149i32,
+        44288i32,
+        48426i32,
+        51758i32,
+        54441i32,
+        56602i32,
+        58342i32,
+        59742i32,
+        60871i32,
+        61779i32,
+        62511i32,
+        63100i32,
+        63574i32,
+        63956i32,
+        64263i32,
+        64511i32,
+        64710i32,
+        64871i32,
+        65000i32,
+        65104i32,
+        65188i32,
+        65256i32,
+        65310i32,
+        65354i32,
+        65389i32,
+        65418i32,
+        65440i32,
+        65459i32,
+        65474i32,
+        65486i32,
+        65495i32,
+        65535i32,
+    ]; // const ..
+
+
+    const BACK_IN_LOOKUP: [i32; 65usize] = [
+        0i32,
+        -27i32,
+        -103i32,
+        -227i32,
+        -392i32,
+        -596i32,
+        -834i32,
+        -1102i32,
+        -1397i32,
+        -1713i32,
+        -2047i32,
+        -2395i32,
+        -2753i32,
+        -3117i32,
+        -3483i32,
+        -3846i32,
+        -4203i32,
+        -4550i32,
+        -4882i32,
+        -5196i32,
+        -5487i32,
+        -5751i32,
+        -5985i32,
+        -6185i32,
+        -6345i32,
+        -6463i32,
+        -6533i32,
+        -6553i32,
+        -6518i32,
+        -6424i32,
+        -6267i32,
+        -6043i32,
+        -5747i32,
+        -5377i32,
+        -4927i32,
+        -4393i32,
+        -3773i32,
+        -3061i32,
+        -2253i32,
+        -1346i32,
+        -335i32,
+        783i32,
+        2013i32,
+        3359i32,
+        4825i32,
+        6414i32,
+        8131i32,
+        9981i32,
+        11966i32,
+        14091i32,
+        16361i32,
+        18779i32,
+        21348i32,
+        24075i32,
+        26961i32,
+        30012i32,
+        33231i32,
+        36623i32,
+        40191i32,
+        43940i32,
+        47874i32,
+        51996i32,
+        56311i32,
+        60822i32,
+        65535i32,
+    ]; // const ..
+
+
+    const BACK_OUT_LOOKUP: [i32; 65usize] = [
+        0i32,
+        4713i32,
+        9224i32,
+        13539i32,
+        17661i32,
+        21595i32,
+        25344i32,
+        28912i32,
+        32304i32,
+        35523i32,
+        38574i32,
+        41460i32,
+        44187i32,
+        46756i32,
+        49174i32,
+        51444i32,
+        53569i32,
+        55554i32,
+        57404i32,
+        59121i32,
+        60710i32,
+        62176i32,
+        63522i32,
+        64752i32,
+        65870i32,
+        66881i32,
+        67788i32,
+        68596i32,
+        69308i32,
+        69928i32,
+        70462i32,
+        70912i32,
+        71282i32,
+        71578i32,
+        71802i32,
+        71959i32,
+        72053i32,
+        72088i32,
+        72068i32,
+        71998i32,
+        71880i32,
+        71720i32,
+        71520i32,
+        71286i32,
+        71022i32,
+        70731i32,
+        70417i32,
+        70085i32,
+        69738i32,
+        69381i32,
+        69018i32,
+        68652i32,
+        68288i32,
+        67930i32,
+        67582i32,
+        67248i32,
+        66932i32,
+        66637i32,
+        66369i32,
+        66131i32,
+        65927i32,
+        65762i32,
+        65638i32,
+        65562i32,
+        65535i32,
+    ]; // const ..
+
+
+    const BACK_IN_OUT_LOOKUP: [i32; 65usize] = [
+        0i32,
+        -79i32,
+        -303i32,
+        -650i32,
+        -1099i32,
+        -1627i32,
+        -2213i32,
+        -2836i32,
+        -3474i32,
+        -4105i32,
+        -4709i32,
+        -5263i32,
+        -5745i32,
+        -6135i32,
+        -6411i32,
+        -6550i32,
+        -6533i32,
+        -6336i32,
+        -5938i32,
+        -5319i32,
+        -4455i32,
+        -3327i32,
+        -1911i32,
+        -187i32,
+        1867i32,
+        4272i32,
+        7051i32,
+        10224i32,
+        13814i32,
+        17842i32,
+        22329i32,
+        27297i32,
+        32768i32,
+        38238i32,
+        43206i32,
+        47693i32,
+        51721i32,
+        55311i32,
+        58484i32,
+        61263i32,
+        63668i32,
+        65722i32,
+        67446i32,
+        68862i32,
+        69990i32,
+        70854i32,
+        71473i32,
+        71871i32,
+        72068i32,
+        72085i32,
+        71946i32,
+        71670i32,
+        71280i32,
+        70798i32,
+        70244i32,
+        69640i32,
+        69009i32,
+        68371i32,
+        67748i32,
+        67162i32,
+        66634i32,
+        66185i32,
+        65838i32,
+        65614i32,
+        65535i32,
+    ]; // const ..
+
+
+    const ELASTIC_IN_LOOKUP: [i32; 65usize] = [
+        0i32,
+        -14i32,
+        10i32,
+        39i32,
+        70i32,
+        99i32,
+        122i32,
+        134i32,
+        132i32,
+        112i32,
+        72i32,
+        14i32,
+        -61i32,
+        -145i32,
+        -231i32,
+        -308i32,
+        -362i32,
+        -382i32,
+        -357i32,
+        -278i32,
+        -145i32,
+        41i32,
+        265i32,
+        509i32,
+        746i32,
+        941i32,
+        1060i32,
+        1069i32,
+        939i32,
+        655i32,
+        215i32,
+        -359i32,
+        -1024i32,
+        -1716i32,
+        -2350i32,
+        -2828i32,
+        -3051i32,
+        -2927i32,
+        -2388i32,
+        -1405i32,
+        0i32,
+        1745i32,
+        3682i32,
+        5605i32,
+        7256i32,
+        8353i32,
+        8619i32,
+        7816i32,
+        5793i32,
+        2519i32,
+        -1878i32,
+        -7091i32,
+        -12634i32,
+        -17857i32,
+        -21998i32,
+        -24251i32,
+        -23863i32,
+        -20246i32,
+        -13095i32,
+        -2494i32,
+        10998i32,
+        26309i32,
+        41867i32,
+        55687i32,
+        65535i32,
+    ]; // const ..
+
+
+    const ELASTIC_OUT_LOOKUP: [i32; 65usize] = [
+        0i32,
+        9848i32,
+        23668i32,
+        39226i32,
+        54537i32,
+        68029i32,
+        78630i32,
+        85781i32,
+        89398i32,
+        89786i32,
+        87533i32,
+        83392i32,
+        78169i32,
+        72626i32,
+        67413i32,
+        63016i32,
+        59742i32,
+        57719i32,
+        56916i32,
+        57182i32,
+        58279i32,
+        59930i32,
+        61853i32,
+        63790i32,
+        65535i32,
+        66940i32,
+        67923i32,
+        68462i32,
+        68586i32,
+        68363i32,
+        67885i32,
+        67251i32,
+        66559i32,
+        65894i32,
+        65320i32,
+        64880i32,
+        64596i32,
+        64466i32,
+        64475i32,
+        64594i32,
+        64789i32,
+        65026i32,
+        65270i32,
+        65494i32,
+        65680i32,
+        65813i32,
+        65892i32,
+        65917i32,
+        65897i32,
+        65843i32,
+        65766i32,
+        65680i32,
+        65596i32,
+        65521i32,
+        65463i32,
+        65423i32,
+        65403i32,
+        65401i32,
+        65413i32,
+        65436i32,
+        65465i32,
+        65496i32,
+        65525i32,
+        65549i32,
+        65535i32,
+    ]; // const ..
+
+
+    const ELASTIC_IN_OUT_LOOKUP: [i32; 65usize] = [
+        0i32,
+        23i32,
+        43i32,
+        61i32,
+        72i32,
+        67i32,
+        40i32,
+        -13i32,
+        -91i32,
+        -184i32,
+        -275i32,
+        -335i32,
+        -330i32,
+        -226i32,
+        0i32,
+        348i32,
+        784i32,
+        1228i32,
+        1555i32,
+        1606i32,
+        1218i32,
+        264i32,
+        -1285i32,
+        -3298i32,
+        -5443i32,
+        -7166i32,
+        -7736i32,
+        -6363i32,
+        -2392i32,
+        4428i32,
+        13657i32,
+        23914i32,
+        32768i32,
+        41621i32,
+        51878i32,
+        61107i32,
+        67927i32,
+        71898i32,
+        73271i32,
+        72701i32,
+        70978i32,
+        68833i32,
+        66820i32,
+        65271i32,
+        64317i32,
+        63929i32,
+        63980i32,
+        64307i32,
+        64751i32,
+        65187i32,
+        65535i32,
+        65761i32,
+        65865i32,
+        65870i32,
+        65810i32,
+        65719i32,
+        65626i32,
+        65548i32,
+        65495i32,
+        65468i32,
+        65463i32,
+        65474i32,
+        65492i32,
+        65512i32,
+        65535i32,
+    ]; // const ..
+
+
+    const BOUNCE_IN_LOOKUP: [i32; 65usize] = [
+        0i32,
+        583i32,
+        924i32,
+        1023i32,
+        880i32,
+        495i32,
+        252i32,
+        1495i32,
+        2496i32,
+        3255i32,
+        3772i32,
+        4047i32,
+        4080i32,
+        3871i32,
+        3420i32,
+        2727i32,
+        1792i32,
+        615i32,
+        1500i32,
+        4063i32,
+        6384i32,
+        8463i32,
+        10300i32,
+        11895i32,
+        13248i32,
+        14359i32,
+        15228i32,
+        15855i32,
+        16240i32,
+        16383i32,
+        16284i32,
+        15943i32,
+        15360i32,
+        14535i32,
+        13468i32,
+        12159i32,
+        10608i32,
+        8815i32,
+        6780i32,
+        4503i32,
+        1984i32,
+        1527i32,
+        6972i32,
+        12175i32,
+        17136i32,
+        21855i32,
+        26332i32,
+        30567i32,
+        34559i32,
+        38310i32,
+        41819i32,
+        45086i32,
+        48111i32,
+        50894i32,
+        53435i32,
+        55734i32,
+        57791i32,
+        59606i32,
+        61179i32,
+        62510i32,
+        63599i32,
+        64446i32,
+        65051i32,
+        65414i32,
+        65535i32,
+    ]; // const ..
+
+
+    const BOUNCE_OUT_LOOKUP: [i32; 65usize] = [
+        0i32,
+        121i32,
+        484i32,
+        1089i32,
+        1936i32,
+        3025i32,
+        4356i32,
+        5929i32,
+        7744i32,
+        9801i32,
+        12100i32,
+        14641i32,
+        17424i32,
+        20449i32,
+        23716i32,
+        27225i32,
+        30976i32,
+        34968i32,
+        39203i32,
+        43680i32,
+        48399i32,
+        53360i32,
+        58563i32,
+        64008i32,
+        63551i32,
+        61032i32,
+        58755i32,
+        56720i32,
+        54927i32,
+        53376i32,
+        52067i32,
+        51000i32,
+        50175i32,
+        49592i32,
+        49251i32,
+        49152i32,
+        49295i32,
+        49680i32,
+        50307i32,
+        51176i32,
+        52287i32,
+        53640i32,
+        55235i32,
+        57072i32,
+        59151i32,
+        61472i32,
+        64035i32,
+        64920i32,
+        63743i32,
+        62808i32,
+        62115i32,
+        61664i32,
+        61455i32,
+        61488i32,
+        61763i32,
+        62280i32,
+        63039i32,
+        64040i32,
+        65283i32,
+        65040i32,
+        64655i32,
+        64512i32,
+        64611i32,
+        64952i32,
+        65535i32,
+    ]; // const ..
+
+
+    const BOUNCE_IN_OUT_LOOKUP: [i32; 65usize] = [
+        0i32,
+        462i32,
+        440i32,
+        126i32,
+        1248i32,
+        1886i32,
+        2040i32,
+        1710i32,
+        896i32,
+        750i32,
+        3192i32,
+        5150i32,
+        6624i32,
+        7614i32,
+        8120i32,
+        8142i32,
+        7680i32,
+        6734i32,
+        5304i32,
+        3390i32,
+        992i32,
+        3486i32,
+        8568i32,
+        13166i32,
+        17280i32,
+        20910i32,
+        24056i32,
+        26718i32,
+        28896i32,
+        30590i32,
+        31800i32,
+        32526i32,
+        32768i32,
+        33009i32,
+        33735i32,
+        34945i32,
+        36639i32,
+        38817i32,
+        41479i32,
+        44625i32,
+        48255i32,
+        52369i32,
+        56967i32,
+        62049i32,
+        64543i32,
+        62145i32,
+        60231i32,
+        58801i32,
+        57855i32,
+        57393i32,
+        57415i32,
+        57921i32,
+        58911i32,
+        60385i32,
+        62343i32,
+        64785i32,
+        64639i32,
+        63825i32,
+        63495i32,
+        63649i32,
+        64287i32,
+        65409i32,
+        65095i32,
+        65073i32,
+        65535i32,
+    ]; // const ..
+
+
+    /// Sine of an angle given in fractional bytes, interpolating the lookup table
+    fn sin_bytes(bytes: f32) -> f32 {
+        let floor = bytes.floor();
+        let angle = Angle((floor as i32) as u8);
+        angle.sinf().lerp((angle + Angle(1u8)).sinf(), bytes - floor)
+    } // fn ..
+
+
+    fn cos_bytes(bytes: f32) -> f32 { sin_bytes(bytes + 64f32) }
+
+
+    fn bounce_out(t: f32) -> f32 {
+        if t < 1f32 / BOUNCE_D1        { BOUNCE_N1 * t * t }
+        else if t < 2f32 / BOUNCE_D1   { let t = t - 1.5f32 / BOUNCE_D1;   BOUNCE_N1 * t * t + 0.75f32 }
+        else if t < 2.5f32 / BOUNCE_D1 { let t = t - 2.25f32 / BOUNCE_D1;  BOUNCE_N1 * t * t + 0.9375f32 }
+        else                           { let t = t - 2.625f32 / BOUNCE_D1; BOUNCE_N1 * t * t + 0.984375f32 }
+    } // fn ..
+
+
+//###############################
+// I M P L E M E N T A T I O N S
+//###############################
+
+    impl Easing {
+        pub const ALL: [Easing; 21usize] = [
+            Easing::QuadIn,    Easing::QuadOut,    Easing::QuadInOut,
+            Easing::CubicIn,   Easing::CubicOut,   Easing::CubicInOut,
+            Easing::SineIn,    Easing::SineOut,    Easing::SineInOut,
+            Easing::ExpoIn,    Easing::ExpoOut,    Easing::ExpoInOut,
+            Easing::BackIn,    Easing::BackOut,    Easing::BackInOut,
+            Easing::ElasticIn, Easing::ElasticOut, Easing::ElasticInOut,
+            Easing::BounceIn,  Easing::BounceOut,  Easing::BounceInOut,
+        ]; // const ..
+
+
+        /// Eases a progress in `[0, 1]`, sine based curves going through the `Angle` lookup table
+        pub fn ease(self, t: f32) -> f32 {
+            match self {
+                Easing::QuadIn       => t * t,
+                Easing::QuadOut      => 1f32 - (1f32 - t) * (1f32 - t),
+                Easing::QuadInOut    => if t < 0.5f32 { 2f32 * t * t } else { 1f32 - (2f32 - 2f32 * t).powi(2i32) / 2f32 },
+                Easing::CubicIn      => t * t * t,
+                Easing::CubicOut     => 1f32 - (1f32 - t).powi(3i32),
+                Easing::CubicInOut   => if t < 0.5f32 { 4f32 * t * t * t } else { 1f32 - (2f32 - 2f32 * t).powi(3i32) / 2f32 },
+                Easing::SineIn       => 1f32 - cos_bytes(t * 64f32),
+                Easing::SineOut      => sin_bytes(t * 64f32),
+                Easing::SineInOut    => (1f32 - cos_bytes(t * 128f32)) / 2f32,
+                Easing::ExpoIn       => if t <= 0f32 { 0f32 } else { (10f32 * t - 10f32).exp2() },
+                Easing::ExpoOut      => if t >= 1f32 { 1f32 } else { 1f32 - (-10f32 * t).exp2() },
+                Easing::ExpoInOut    =>
+                    if t <= 0f32        { 0f32 }
+                    else if t >= 1f32   { 1f32 }
+                    else if t < 0.5f32  { (20f32 * t - 10f32).exp2() / 2f32 }
+                    else                { (2f32 - (10f32 - 20f32 * t).exp2()) / 2f32 },
+                Easing::BackIn       => BACK_C3 * t * t * t - BACK_C1 * t * t,
+                Easing::BackOut      => 1f32 + BACK_C3 * (t - 1f32).powi(3i32) + BACK_C1 * (t - 1f32).powi(2i32),
+                Easing::BackInOut    =>
+                    if t < 0.5f32 { (2f32 * t).powi(2i32) * ((BACK_C2 + 1f32) * 2f32 * t - BACK_C2) / 2f32 }
+                    else          { ((2f32 * t - 2f32).powi(2i32) * ((BACK_C2 + 1f32) * (2f32 * t - 2f32) + BACK_C2) + 2f32) / 2f32 },
+                Easing::ElasticIn    =>
+                    if t <= 0f32      { 0f32 }
+                    else if t >= 1f32 { 1f32 }
+                    else              { -(10f32 * t - 10f32).exp2() * sin_bytes((10f32 * t - 10.75f32) * ELASTIC_C4 * RAD_TO_BYTE_RATIO) },
+                Easing::ElasticOut   =>
+                    if t <= 0f32      { 0f32 }
+                    else if t >= 1f32 { 1f32 }
+                    else              { (-10f32 * t).exp2() * sin_bytes((10f32 * t - 0.75f32) * ELASTIC_C4 * RAD_TO_BYTE_RATIO) + 1f32 },
+                Easing::ElasticInOut =>
+                    if t <= 0f32       { 0f32 }
+                    else if t >= 1f32  { 1f32 }
+                    else if t < 0.5f32 { -(20f32 * t - 10f32).exp2() * sin_bytes((20f32 * t - 11.125f32) * ELASTIC_C5 * RAD_TO_BYTE_RATIO) / 2f32 }
+                    else               { (10f32 - 20f32 * t).exp2() * sin_bytes((20f32 * t - 11.125f32) * ELASTIC_C5 * RAD_TO_BYTE_RATIO) / 2f32 + 1f32 },
+                Easing::BounceIn     => 1f32 - bounce_out(1f32 - t),
+                Easing::BounceOut    => bounce_out(t),
+                Easing::BounceInOut  => if t < 0.5f32 { (1f32 - bounce_out(1f32 - 2f32 * t)) / 2f32 } else { (1f32 + bounce_out(2f32 * t - 1f32)) / 2f32 },
+            } // match ..
+        } // fn ..
+
+
+        /// Eases a 16-bit progress through the lookup table, `65535` standing
+        /// for one in both input and output, overshoots included
+        pub fn ease16_signed(self, t: u16) -> i32 {
+            let lookup   = self.lookup();
+            let position = t as u32 * 64u32;
+            let index    = (position / u16::MAX as u32) as usize;
+            let fraction = (position % u16::MAX as u32) as i64;
+            if index >= 64usize { return lookup[64usize] }
+            (lookup[index] as i64 + (lookup[index + 1usize] as i64 - lookup[index] as i64) * fraction / u16::MAX as i64) as i32
+        } // fn ..
+
+
+        /// Eases a 16-bit progress through the lookup table, overshoots are clamped
+        pub fn ease16(self, t: u16) -> u16 {
+            self.ease16_signed(t).clamp(0i32, u16::MAX as i32) as u16
+        } // fn ..
+
+
+        /// Eases an 8-bit progress through the lookup table, overshoots are clamped
+        pub fn ease8(self, t: u8) -> u8 {
+            ((self.ease16(t as u16 * 257u16) as u32 + 128u32) / 257u32) as u8
+        } // fn ..
+
+
+        /// Interpolates between `a` and `b` along the curve, overshooting if it does
+        pub fn lerp<T: Lerp>(self, a: T, b: T, t: f32) -> T { a.lerp(b, self.ease(t)) }
+
+        /// Interpolates between `a` and `b` along the curve with 8-bit fractions
+        pub fn lerp8<T: FixedLerp>(self, a: T, b: T, t: u8) -> T { a.lerp8(b, self.ease8(t)) }
+
+        /// Interpolates between `a` and `b` along the curve with 16-bit fractions
+        pub fn lerp16<T: FixedLerp>(self, a: T, b: T, t: u16) -> T { a.lerp16(b, self.ease16(t)) }
+
+
+        fn lookup(self) -> &'static [i32; 65usize] {
+            match self {
+                Easing::QuadIn       => &QUAD_IN_LOOKUP,
+                Easing::QuadOut      => &QUAD_OUT_LOOKUP,
+                Easing::QuadInOut    => &QUAD_IN_OUT_LOOKUP,
+                Easing::CubicIn      => &CUBIC_IN_LOOKUP,
+                Easing::CubicOut     => &CUBIC_OUT_LOOKUP,
+                Easing::CubicInOut   => &CUBIC_IN_OUT_LOOKUP,
+                Easing::SineIn       => &SINE_IN_LOOKUP,
+                Easing::SineOut      => &SINE_OUT_LOOKUP,
+                Easing::SineInOut    => &SINE_IN_OUT_LOOKUP,
+                Easing::ExpoIn       => &EXPO_IN_LOOKUP,
+                Easing::ExpoOut      => &EXPO_OUT_LOOKUP,
+                Easing::ExpoInOut    => &EXPO_IN_OUT_LOOKUP,
+                Easing::BackIn       => &BACK_IN_LOOKUP,
+                Easing::BackOut      => &BACK_OUT_LOOKUP,
+                Easing::BackInOut    => &BACK_IN_OUT_LOOKUP,
+                Easing::ElasticIn    => &ELASTIC_IN_LOOKUP,
+                Easing::ElasticOut   => &ELASTIC_OUT_LOOKUP,
+                Easing::ElasticInOut => &ELASTIC_IN_OUT_LOOKUP,
+                Easing::BounceIn     => &BOUNCE_IN_LOOKUP,
+                Easing::BounceOut    => &BOUNCE_OUT_LOOKUP,
+                Easing::BounceInOut  => &BOUNCE_IN_OUT_LOOKUP,
+            } // match ..
+        } // fn ..
+    } // impl ..
@@ -0,0 +1,98 @@
+//#########################
+// D E P E N D E N C I E S
+//#########################
+
+    use crate::traits::{Zero, Signed, Float, Sqrt, Vector};
+    use crate::vectors::{Vec3, Vec4};
+    use crate::matrices::{Mat4, DepthRange};
+    use crate::geometry::{Plane, Sphere, Aabb3, Obb};
+
+
+//#######################
+// D E F I N I T I O N S
+//#######################
+
+    /// Where a shape lies relative to a frustum
+    #[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+    pub enum Containment {
+        Outside,
+        /// Crosses a plane, or may be outside near the edges and corners of the frustum
+        Intersecting,
+        Inside,
+    } // enum ..
+
+
+    /// Volume seen through a projection, as six planes with normalized normals facing inside,
+    /// ordered left, right, bottom, top, near and far like the bits of `clipping::Outcode`
+    #[derive(Clone, Copy, PartialEq, Eq, Hash, Default, Debug)]
+    pub struct Frustum<T: Signed> {
+        pub planes: [Plane<T>; 6usize],
+    } // struct ..
+
+
+//###############################
+// I M P L E M E N T A T I O N S
+//###############################
+
+    impl<T: Float + Signed + Sqrt> Frustum<T> {
+        /// Gribb–Hartmann extraction from a view-projection matrix, the planes are in world
+        /// space, or in the space the matrix transforms from. A plane without a normal, like the
+        /// far plane of an infinite projection, is kept as is and then contains every point or none
+        pub fn from_matrix(matrix: &Mat4<T>, depth: DepthRange) -> Self {
+            let Mat4(x, y, z, w) = *matrix;
+            let row = |index: usize| Vec4(x.component(index), y.component(index), z.component(index), w.component(index));
+            let (x, y, z, w) = (row(0usize), row(1usize), row(2usize), row(3usize));
+
+            let near = match depth {
+                DepthRange::NegativeOneToOne => w + z,
+                DepthRange::ZeroToOne        => z,
+            }; // let ..
+            Frustum { planes: [w + x, w - x, w + y, w - y, near, w - z].map(|plane| {
+                let normal    = plane.xyz();
+                let magnitude = normal.magnitude();
+                if magnitude == T::ZERO { Plane { normal, distance: -plane.3 } }
+                else                    { Plane { normal: normal / magnitude, distance: -plane.3 / magnitude } }
+            })} // Frustum
+        } // fn ..
+
+
+        /// Bounds included
+        pub fn contains(&self, point: Vec3<T>) -> bool {
+            self.planes.iter().all(|plane| Vec3::dot(plane.normal, point) >= plane.distance)
+        } // fn ..
+
+
+        pub fn test_sphere(&self, sphere: &Sphere<T>) -> Containment {
+            self.test(sphere.center, |_| sphere.radius)
+        } // fn ..
+
+
+        pub fn test_aabb(&self, aabb: &Aabb3<T>) -> Containment {
+            let two    = T::ONE + T::ONE;
+            let extent = (aabb.max - aabb.min) / two;
+            self.test((aabb.min + aabb.max) / two, |normal| Vec3::dot(abs(normal), extent))
+        } // fn ..
+
+
+        pub fn test_obb(&self, obb: &Obb<T>) -> Containment {
+            let projected = |normal: Vec3<T>| Vec3(Vec3::dot(normal, obb.axes[0usize]), Vec3::dot(normal, obb.axes[1usize]), Vec3::dot(normal, obb.axes[2usize]));
+            self.test(obb.center, |normal| Vec3::dot(abs(projected(normal)), obb.half_extents))
+        } // fn ..
+
+
+        /// Compares the distance of the center of a shape to each plane with the radius of the
+        /// shape along its normal
+        fn test(&self, center: Vec3<T>, radius: impl Fn(Vec3<T>) -> T) -> Containment {
+            let mut containment = Containment::Inside;
+            for plane in &self.planes {
+                let distance = Vec3::dot(plane.normal, center) - plane.distance;
+                let radius   = radius(plane.normal);
+                if distance < -radius { return Containment::Outside }
+                if distance <  radius { containment = Containment::Intersecting }
+            } // for ..
+            containment
+        } // fn ..
+    } // impl ..
+
+
+    fn abs<T: Signed>(vector: Vec3<T>) -> Vec3<T> { Vec3::max(vector, Vec3::ZERO - vector) }
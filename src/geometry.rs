@@ -0,0 +1,552 @@
+//#########################
+// D E P E N D E N C I E S
+//#########################
+
+    use crate::traits::{Number, Zero, One, Signed, Float, Sqrt, Widening, Vector};
+    use crate::vectors::{Vec2, Vec3};
+    use crate::matrices::Transform;
+
+
+//#######################
+// D E F I N I T I O N S
+//#######################
+
+    /// Half-line starting at `origin`, the direction does not need to be normalized
+    #[derive(Clone, Copy, PartialEq, Eq, Hash, Default, Debug)]
+    pub struct Ray<V: Vector> {
+        pub origin:    V,
+        pub direction: V,
+    } // struct ..
+
+
+    #[derive(Clone, Copy, PartialEq, Eq, Hash, Default, Debug)]
+    pub struct Segment<V: Vector> {
+        pub start: V,
+        pub end:   V,
+    } // struct ..
+
+
+    /// Infinite line through `point`, the direction does not need to be normalized
+    #[derive(Clone, Copy, PartialEq, Eq, Hash, Default, Debug)]
+    pub struct Line<V: Vector> {
+        pub point:     V,
+        pub direction: V,
+    } // struct ..
+
+
+    /// Points `p` such that `dot(normal, p) == distance`, distances to the plane
+    /// are measured in multiples of the normal magnitude
+    #[derive(Clone, Copy, PartialEq, Eq, Hash, Default, Debug)]
+    pub struct Plane<T: Number> {
+        pub normal:   Vec3<T>,
+        pub distance: T,
+    } // struct ..
+
+
+    /// Solid disc or ball, see the `Circle` and `Sphere` aliases
+    #[derive(Clone, Copy, PartialEq, Eq, Hash, Default, Debug)]
+    pub struct Ball<V: Vector> {
+        pub center: V,
+        pub radius: V::Scalar,
+    } // struct ..
+
+
+    pub type Circle<T> = Ball<Vec2<T>>;
+    pub type Sphere<T> = Ball<Vec3<T>>;
+
+
+    /// Axis-aligned box, bounds included, see the `Aabb2` and `Aabb3` aliases
+    #[derive(Clone, Copy, PartialEq, Eq, Hash, Default, Debug)]
+    pub struct Aabb<V: Vector> {
+        pub min: V,
+        pub max: V,
+    } // struct ..
+
+
+    pub type Aabb2<T> = Aabb<Vec2<T>>;
+    pub type Aabb3<T> = Aabb<Vec3<T>>;
+
+
+    /// Solid triangle, its vertices should not be collinear
+    #[derive(Clone, Copy, PartialEq, Eq, Hash, Default, Debug)]
+    pub struct Triangle<V: Vector>(pub V, pub V, pub V);
+
+
+    /// Points within `radius` of a segment
+    #[derive(Clone, Copy, PartialEq, Eq, Hash, Default, Debug)]
+    pub struct Capsule<V: Vector> {
+        pub segment: Segment<V>,
+        pub radius:  V::Scalar,
+    } // struct ..
+
+
+    /// Oriented box, bounds included, its axes should be normalized and orthogonal
+    #[derive(Clone, Copy, PartialEq, Eq, Hash, Default, Debug)]
+    pub struct Obb<T: Number> {
+        pub center:       Vec3<T>,
+        pub axes:         [Vec3<T>; 3usize],
+        pub half_extents: Vec3<T>,
+    } // struct ..
+
+
+    fn min_scalar<T: Number>(a: T, b: T) -> T { if b < a { b } else { a }}
+    fn max_scalar<T: Number>(a: T, b: T) -> T { if b > a { b } else { a }}
+
+
+    /// Closest point of `origin + direction * t` to `point`, with `t` kept
+    /// above zero and below one when asked, multiplies before dividing so that
+    /// integer results are only rounded once
+    fn project<V: Vector>(origin: V, direction: V, point: V, clamp_start: bool, clamp_end: bool) -> V
+    where V::Scalar: Widening {
+        let zero        = <V::Scalar as Widening>::Wide::ZERO;
+        let numerator   = wide_dot(point - origin, direction);
+        let denominator = wide_dot(direction, direction);
+
+        let at_start    = denominator == zero || (clamp_start && numerator <= zero);
+
+        if at_start                                   { origin }
+        else if clamp_end && numerator >= denominator { origin + direction }
+        else                                          { offset(origin, &[(direction, numerator)], denominator) }
+    } // fn ..
+
+
+    /// Dot product in the wide type, integer products of four coordinates overflow 32 bits
+    fn wide_dot<V: Vector>(a: V, b: V) -> <V::Scalar as Widening>::Wide where V::Scalar: Widening {
+        (0usize..V::DIMENSION).fold(<V::Scalar as Widening>::Wide::ZERO, |sum, index| {
+            sum + a.component(index).to_wide() * b.component(index).to_wide()
+        }) // fold()
+    } // fn ..
+
+
+    /// `origin` plus the sum of `direction * weight` over `denominator`, computed in the wide type
+    fn offset<V: Vector>(origin: V, terms: &[(V, <V::Scalar as Widening>::Wide)], denominator: <V::Scalar as Widening>::Wide) -> V
+    where V::Scalar: Widening {
+        let mut point = origin;
+        for index in 0usize..V::DIMENSION {
+            let sum = terms.iter().fold(<V::Scalar as Widening>::Wide::ZERO, |sum, &(direction, weight)| sum + direction.component(index).to_wide() * weight);
+            *point.component_mut(index) += V::Scalar::from_wide(sum / denominator);
+        } // for ..
+        point
+    } // fn ..
+
+
+    /// Gives a 2D distance the sign of the side of `direction` the point lies on,
+    /// positive counter-clockwise
+    fn signed_side<T: Signed>(origin: Vec2<T>, direction: Vec2<T>, point: Vec2<T>, distance: T) -> T {
+        if Vec2::cross(direction, point - origin) < T::ZERO { -distance } else { distance }
+    } // fn ..
+
+
+    /// Scaling factor of a transformation without shear nor non-uniform scaling
+    fn uniform_scale<V: Vector, M: Transform<V>>(matrix: &M) -> V::Scalar where V::Scalar: Sqrt {
+        let mut axis = V::ZERO;
+        *axis.component_mut(0usize) = V::Scalar::ONE;
+        matrix.transform_vector(axis).magnitude()
+    } // fn ..
+
+
+//###############################
+// I M P L E M E N T A T I O N S
+//###############################
+
+    //#######
+    // R A Y
+    //#######
+
+    impl<V: Vector> Ray<V> where V::Scalar: Signed {
+        pub fn new(origin: V, direction: V) -> Self { Ray { origin, direction } }
+
+        /// Point at `origin + direction * t`
+        pub fn at(&self, t: V::Scalar) -> V { self.origin + self.direction * t }
+
+        pub fn closest_point(&self, point: V) -> V where V::Scalar: Widening { project(self.origin, self.direction, point, true, false) }
+
+        /// Exact for integers, compare the squared distance to a tolerance for floats
+        pub fn contains(&self, point: V) -> bool where V::Scalar: Widening { self.closest_point(point) == point }
+
+        pub fn squared_distance(&self, point: V) -> V::Scalar where V::Scalar: Widening { (point - self.closest_point(point)).squared_magnitude() }
+
+        pub fn distance(&self, point: V) -> V::Scalar where V::Scalar: Widening + Sqrt { self.squared_distance(point).sqrt() }
+
+        pub fn transform<M: Transform<V>>(&self, matrix: &M) -> Self {
+            Ray::new(matrix.transform_point(self.origin), matrix.transform_vector(self.direction))
+        } // fn ..
+    } // impl ..
+
+
+    impl<T: Widening + Sqrt> Ray<Vec2<T>> {
+        /// Distance to the ray, negative on the clockwise side of its direction
+        pub fn signed_distance(&self, point: Vec2<T>) -> T {
+            signed_side(self.origin, self.direction, point, self.distance(point))
+        } // fn ..
+    } // impl ..
+
+
+    //###############
+    // S E G M E N T
+    //###############
+
+    impl<V: Vector> Segment<V> where V::Scalar: Signed {
+        pub fn new(start: V, end: V) -> Self { Segment { start, end } }
+
+        pub fn closest_point(&self, point: V) -> V where V::Scalar: Widening { project(self.start, self.end - self.start, point, true, true) }
+
+        /// Exact for integers, compare the squared distance to a tolerance for floats
+        pub fn contains(&self, point: V) -> bool where V::Scalar: Widening { self.closest_point(point) == point }
+
+        pub fn squared_distance(&self, point: V) -> V::Scalar where V::Scalar: Widening { (point - self.closest_point(point)).squared_magnitude() }
+
+        pub fn distance(&self, point: V) -> V::Scalar where V::Scalar: Widening + Sqrt { self.squared_distance(point).sqrt() }
+
+        pub fn transform<M: Transform<V>>(&self, matrix: &M) -> Self {
+            Segment::new(matrix.transform_point(self.start), matrix.transform_point(self.end))
+        } // fn ..
+    } // impl ..
+
+
+    impl<T: Widening + Sqrt> Segment<Vec2<T>> {
+        /// Distance to the segment, negative on the clockwise side of `start -> end`
+        pub fn signed_distance(&self, point: Vec2<T>) -> T {
+            signed_side(self.start, self.end - self.start, point, self.distance(point))
+        } // fn ..
+    } // impl ..
+
+
+    //#########
+    // L I N E
+    //#########
+
+    impl<V: Vector> Line<V> where V::Scalar: Signed {
+        pub fn new(point: V, direction: V) -> Self { Line { point, direction } }
+
+        pub fn closest_point(&self, point: V) -> V where V::Scalar: Widening { project(self.point, self.direction, point, false, false) }
+
+        /// Exact for integers, compare the squared distance to a tolerance for floats
+        pub fn contains(&self, point: V) -> bool where V::Scalar: Widening { self.closest_point(point) == point }
+
+        pub fn squared_distance(&self, point: V) -> V::Scalar where V::Scalar: Widening { (point - self.closest_point(point)).squared_magnitude() }
+
+        pub fn distance(&self, point: V) -> V::Scalar where V::Scalar: Widening + Sqrt { self.squared_distance(point).sqrt() }
+
+        pub fn transform<M: Transform<V>>(&self, matrix: &M) -> Self {
+            Line::new(matrix.transform_point(self.point), matrix.transform_vector(self.direction))
+        } // fn ..
+    } // impl ..
+
+
+    impl<T: Widening + Sqrt> Line<Vec2<T>> {
+        /// Distance to the line, negative on the clockwise side of its direction
+        pub fn signed_distance(&self, point: Vec2<T>) -> T {
+            Vec2::cross(self.direction, point - self.point) / Vec2::dot(self.direction, self.direction).sqrt()
+        } // fn ..
+    } // impl ..
+
+
+    //###########
+    // P L A N E
+    //###########
+
+    impl<T: Signed> Plane<T> {
+        pub fn new(normal: Vec3<T>, distance: T) -> Self { Plane { normal, distance } }
+
+        pub fn from_point_normal(point: Vec3<T>, normal: Vec3<T>) -> Self {
+            Plane { normal, distance: Vec3::dot(normal, point) }
+        } // fn ..
+
+
+        /// Plane of a triangle, its normal faces the side the vertices turn counter-clockwise from
+        pub fn from_triangle(triangle: &Triangle<Vec3<T>>) -> Self {
+            Plane::from_point_normal(triangle.0, triangle.normal())
+        } // fn ..
+
+
+        pub fn closest_point(&self, point: Vec3<T>) -> Vec3<T> {
+            point - self.normal * (Vec3::dot(self.normal, point) - self.distance) / Vec3::dot(self.normal, self.normal)
+        } // fn ..
+
+
+        pub fn contains(&self, point: Vec3<T>) -> bool { Vec3::dot(self.normal, point) == self.distance }
+
+        pub fn squared_distance(&self, point: Vec3<T>) -> T {
+            let offset = Vec3::dot(self.normal, point) - self.distance;
+            offset * offset / Vec3::dot(self.normal, self.normal)
+        } // fn ..
+
+
+        /// Distance to the plane, positive on the side the normal points to
+        pub fn signed_distance(&self, point: Vec3<T>) -> T where T: Sqrt {
+            (Vec3::dot(self.normal, point) - self.distance) / Vec3::dot(self.normal, self.normal).sqrt()
+        } // fn ..
+
+
+        /// Expects a transformation without shear nor non-uniform scaling,
+        /// the normal is scaled along with the plane
+        pub fn transform<M: Transform<Vec3<T>>>(&self, matrix: &M) -> Self {
+            let normal      = matrix.transform_vector(self.normal);
+            let translation = matrix.transform_point(Vec3(T::ZERO, T::ZERO, T::ZERO));
+            let distance    = Vec3::dot(normal, normal) * self.distance / Vec3::dot(self.normal, self.normal) + Vec3::dot(normal, translation);
+            Plane { normal, distance }
+        } // fn ..
+    } // impl ..
+
+
+    //#########
+    // B A L L
+    //#########
+
+    impl<V: Vector> Ball<V> where V::Scalar: Signed {
+        pub fn new(center: V, radius: V::Scalar) -> Self { Ball { center, radius } }
+
+        pub fn contains(&self, point: V) -> bool { (point - self.center).squared_magnitude() <= self.radius * self.radius }
+    } // impl ..
+
+
+    impl<V: Vector> Ball<V> where V::Scalar: Signed + Sqrt {
+        /// Returns `point` itself when inside
+        pub fn closest_point(&self, point: V) -> V {
+            let offset   = point - self.center;
+            let distance = offset.magnitude();
+            if distance <= self.radius { point } else { self.center + offset * self.radius / distance }
+        } // fn ..
+
+
+        pub fn squared_distance(&self, point: V) -> V::Scalar {
+            let distance = max_scalar(self.signed_distance(point), V::Scalar::ZERO);
+            distance * distance
+        } // fn ..
+
+
+        /// Distance to the boundary, negative inside
+        pub fn signed_distance(&self, point: V) -> V::Scalar { (point - self.center).magnitude() - self.radius }
+
+
+        /// Expects a transformation without shear nor non-uniform scaling
+        pub fn transform<M: Transform<V>>(&self, matrix: &M) -> Self {
+            Ball::new(matrix.transform_point(self.center), self.radius * uniform_scale(matrix))
+        } // fn ..
+    } // impl ..
+
+
+    //#########
+    // A A B B
+    //#########
+
+    impl<V: Vector> Aabb<V> where V::Scalar: Signed {
+        pub fn new(min: V, max: V) -> Self { Aabb { min, max } }
+
+        /// Smallest box holding every point, `None` when there are none
+        pub fn from_points(points: impl IntoIterator<Item = V>) -> Option<Self> {
+            let mut points = points.into_iter();
+            let first      = points.next()?;
+            Some(points.fold(Aabb::new(first, first), |aabb, point| Aabb::new(V::min(aabb.min, point), V::max(aabb.max, point))))
+        } // fn ..
+
+
+        /// Clamps `point` inside the box
+        pub fn closest_point(&self, point: V) -> V { V::min(V::max(point, self.min), self.max) }
+
+
+        pub fn contains(&self, point: V) -> bool {
+            (0usize..V::DIMENSION).all(|index| point.component(index) >= self.min.component(index) && point.component(index) <= self.max.component(index))
+        } // fn ..
+
+
+        pub fn squared_distance(&self, point: V) -> V::Scalar { (point - self.closest_point(point)).squared_magnitude() }
+
+
+        /// Distance to the boundary, negative inside
+        pub fn signed_distance(&self, point: V) -> V::Scalar where V::Scalar: Sqrt {
+            if !self.contains(point) { return self.squared_distance(point).sqrt() }
+
+            let mut depth = point.component(0usize) - self.min.component(0usize);
+            for index in 0usize..V::DIMENSION {
+                depth = min_scalar(depth, point.component(index) - self.min.component(index));
+                depth = min_scalar(depth, self.max.component(index) - point.component(index));
+            } // for ..
+            -depth
+        } // fn ..
+
+
+        /// Box around the transformed corners, which may be larger than the transformed box
+        pub fn transform<M: Transform<V>>(&self, matrix: &M) -> Self {
+            let corners = (0usize..1usize << V::DIMENSION).map(|corner| {
+                let mut point = self.min;
+                for index in 0usize..V::DIMENSION { if corner >> index & 1usize == 1usize { *point.component_mut(index) = self.max.component(index) }}
+                matrix.transform_point(point)
+            }); // let ..
+            Aabb::from_points(corners).unwrap_or(*self)
+        } // fn ..
+    } // impl ..
+
+
+    //#################
+    // T R I A N G L E
+    //#################
+
+    impl<V: Vector> Triangle<V> where V::Scalar: Signed {
+        /// Closest point by Voronoi regions, see Ericson's Real-Time Collision Detection,
+        /// integer products of five coordinates must fit `i128` so they stay within ±2^23
+        pub fn closest_point(&self, point: V) -> V where V::Scalar: Widening {
+            let Triangle(a, b, c) = *self;
+            let zero              = <V::Scalar as Widening>::Wide::ZERO;
+            let (ab, ac)          = (b - a, c - a);
+
+            let (d1, d2) = (wide_dot(ab, point - a), wide_dot(ac, point - a));
+            if d1 <= zero && d2 <= zero { return a }
+
+            let (d3, d4) = (wide_dot(ab, point - b), wide_dot(ac, point - b));
+            if d3 >= zero && d4 <= d3 { return b }
+
+            let vc = d1 * d4 - d3 * d2;
+            if vc <= zero && d1 >= zero && d3 <= zero { return offset(a, &[(ab, d1)], d1 - d3) }
+
+            let (d5, d6) = (wide_dot(ab, point - c), wide_dot(ac, point - c));
+            if d6 >= zero && d5 <= d6 { return c }
+
+            let vb = d5 * d2 - d1 * d6;
+            if vb <= zero && d2 >= zero && d6 <= zero { return offset(a, &[(ac, d2)], d2 - d6) }
+
+            let va = d3 * d6 - d5 * d4;
+            if va <= zero && d4 - d3 >= zero && d5 - d6 >= zero { return offset(b, &[(c - b, d4 - d3)], (d4 - d3) + (d5 - d6)) }
+
+            offset(a, &[(ab, vb), (ac, vc)], va + vb + vc)
+        } // fn ..
+
+
+        pub fn squared_distance(&self, point: V) -> V::Scalar where V::Scalar: Widening { (point - self.closest_point(point)).squared_magnitude() }
+
+        pub fn distance(&self, point: V) -> V::Scalar where V::Scalar: Widening + Sqrt { self.squared_distance(point).sqrt() }
+
+        pub fn transform<M: Transform<V>>(&self, matrix: &M) -> Self {
+            Triangle(matrix.transform_point(self.0), matrix.transform_point(self.1), matrix.transform_point(self.2))
+        } // fn ..
+    } // impl ..
+
+
+    impl<T: Signed> Triangle<Vec2<T>> {
+        /// Edges included, whatever the winding
+        pub fn contains(&self, point: Vec2<T>) -> bool {
+            let Triangle(a, b, c) = *self;
+            let edges             = [Vec2::cross(b - a, point - a), Vec2::cross(c - b, point - b), Vec2::cross(a - c, point - c)];
+            edges.iter().all(|&edge| edge >= T::ZERO) || edges.iter().all(|&edge| edge <= T::ZERO)
+        } // fn ..
+
+
+        /// Distance to the boundary, negative inside
+        pub fn signed_distance(&self, point: Vec2<T>) -> T where T: Widening + Sqrt {
+            let Triangle(a, b, c) = *self;
+            let distance          = min_scalar(min_scalar(
+                Segment::new(a, b).squared_distance(point),
+                Segment::new(b, c).squared_distance(point)),
+                Segment::new(c, a).squared_distance(point),
+            ).sqrt(); // let ..
+            if self.contains(point) { -distance } else { distance }
+        } // fn ..
+    } // impl ..
+
+
+    impl<T: Signed> Triangle<Vec3<T>> {
+        /// Unnormalized normal, facing the side the vertices turn counter-clockwise from
+        pub fn normal(&self) -> Vec3<T> { Vec3::cross(self.1 - self.0, self.2 - self.0) }
+
+
+        /// Exact for integers, the point must lie in the plane of the triangle
+        pub fn contains(&self, point: Vec3<T>) -> bool {
+            let Triangle(a, b, c) = *self;
+            let normal            = self.normal();
+            Vec3::dot(normal, point - a) == T::ZERO
+                && Vec3::dot(normal, Vec3::cross(b - a, point - a)) >= T::ZERO
+                && Vec3::dot(normal, Vec3::cross(c - b, point - b)) >= T::ZERO
+                && Vec3::dot(normal, Vec3::cross(a - c, point - c)) >= T::ZERO
+        } // fn ..
+
+
+        /// Distance to the triangle, negative behind it
+        pub fn signed_distance(&self, point: Vec3<T>) -> T where T: Widening + Sqrt {
+            let distance = self.distance(point);
+            if Vec3::dot(self.normal(), point - self.0) < T::ZERO { -distance } else { distance }
+        } // fn ..
+    } // impl ..
+
+
+    //###############
+    // C A P S U L E
+    //###############
+
+    impl<V: Vector> Capsule<V> where V::Scalar: Signed {
+        pub fn new(start: V, end: V, radius: V::Scalar) -> Self { Capsule { segment: Segment::new(start, end), radius } }
+
+        pub fn contains(&self, point: V) -> bool where V::Scalar: Widening { self.segment.squared_distance(point) <= self.radius * self.radius }
+    } // impl ..
+
+
+    impl<V: Vector> Capsule<V> where V::Scalar: Widening + Sqrt {
+        /// Returns `point` itself when inside
+        pub fn closest_point(&self, point: V) -> V {
+            Ball::new(self.segment.closest_point(point), self.radius).closest_point(point)
+        } // fn ..
+
+
+        pub fn squared_distance(&self, point: V) -> V::Scalar {
+            let distance = max_scalar(self.signed_distance(point), V::Scalar::ZERO);
+            distance * distance
+        } // fn ..
+
+
+        /// Distance to the boundary, negative inside
+        pub fn signed_distance(&self, point: V) -> V::Scalar { self.segment.distance(point) - self.radius }
+
+
+        /// Expects a transformation without shear nor non-uniform scaling
+        pub fn transform<M: Transform<V>>(&self, matrix: &M) -> Self {
+            Capsule { segment: self.segment.transform(matrix), radius: self.radius * uniform_scale(matrix) }
+        } // fn ..
+    } // impl ..
+
+
+    //#######
+    // O B B
+    //#######
+
+    impl<T: Signed> Obb<T> {
+        pub fn new(center: Vec3<T>, axes: [Vec3<T>; 3usize], half_extents: Vec3<T>) -> Self { Obb { center, axes, half_extents } }
+
+        pub fn from_aabb(aabb: &Aabb3<T>) -> Self {
+            let two  = T::ONE + T::ONE;
+            let axes = [Vec3(T::ONE, T::ZERO, T::ZERO), Vec3(T::ZERO, T::ONE, T::ZERO), Vec3(T::ZERO, T::ZERO, T::ONE)];
+            Obb { center: (aabb.min + aabb.max) / two, axes, half_extents: (aabb.max - aabb.min) / two }
+        } // fn ..
+
+
+        /// Clamps `point` inside the box along each axis
+        pub fn closest_point(&self, point: Vec3<T>) -> Vec3<T> {
+            let offset = point - self.center;
+            (0usize..3usize).fold(self.center, |closest, index| {
+                let extent = self.half_extents.component(index);
+                closest + self.axes[index] * max_scalar(-extent, min_scalar(Vec3::dot(offset, self.axes[index]), extent))
+            }) // fold()
+        } // fn ..
+
+
+        pub fn contains(&self, point: Vec3<T>) -> bool {
+            let offset = point - self.center;
+            (0usize..3usize).all(|index| {
+                let (along, extent) = (Vec3::dot(offset, self.axes[index]), self.half_extents.component(index));
+                along >= -extent && along <= extent
+            }) // all()
+        } // fn ..
+
+
+        pub fn squared_distance(&self, point: Vec3<T>) -> T { (point - self.closest_point(point)).squared_magnitude() }
+
+
+        /// Expects a transformation without shear nor non-uniform scaling
+        pub fn transform<M: Transform<Vec3<T>>>(&self, matrix: &M) -> Self where T: Float + Sqrt {
+            let scale = uniform_scale::<Vec3<T>, M>(matrix);
+            Obb {
+                center:       matrix.transform_point(self.center),
+                axes:         self.axes.map(|axis| matrix.transform_vector(axis) / scale),
+                half_extents: self.half_extents * scale,
+            } // Obb
+        } // fn ..
+    } // impl ..
@@ -0,0 +1,343 @@
+//#########################
+// D E P E N D E N C I E S
+//#########################
+
+    use crate::traits::{Zero, One, Signed, Float, Sqrt, AsF64, Vector};
+    use crate::vectors::{Vec2, Vec3};
+    use crate::matrices::{Mat3, Mat4, Transform};
+    use crate::geometry::{Segment, Ball, Aabb, Triangle, Capsule};
+    use crate::intersection::Hit;
+
+
+//#######################
+// D E F I N I T I O N S
+//#######################
+
+    /// Convex shape known through its support function, the point of the shape going
+    /// the furthest along a direction, which does not need to be normalized
+    pub trait Support<V: Vector> {
+        fn support(&self, direction: V) -> V;
+    } // trait ..
+
+
+    /// Shape moved by an affine transform, the matrix may rotate, scale and shear
+    #[derive(Clone, Copy, PartialEq, Eq, Hash, Default, Debug)]
+    pub struct Transformed<S, M> {
+        pub shape:  S,
+        pub matrix: M,
+    } // struct ..
+
+
+    /// Closest points of two disjoint shapes
+    #[derive(Clone, Copy, PartialEq, Eq, Hash, Default, Debug)]
+    pub struct Separation<V: Vector> {
+        pub distance: V::Scalar,
+        pub point_a:  V,
+        pub point_b:  V,
+    } // struct ..
+
+
+    /// Vertex of the Minkowski difference `a - b` along with the support points it comes from
+    #[derive(Clone, Copy)]
+    struct Vertex<V: Vector> {
+        point: V,
+        a:     V,
+        b:     V,
+    } // struct ..
+
+
+    /// Face of the expanding polytope, its normal points outwards
+    struct Facet<V: Vector> {
+        vertices: Vec<usize>,
+        normal:   V,
+        distance: V::Scalar,
+    } // struct ..
+
+
+    // Both algorithms run on the Minkowski difference `a - b`, which contains the origin
+    // when the shapes overlap. Scalars are floats, integers would round the tolerances below
+    // to zero and the normalized directions to axes, and the tolerances are relative so that
+    // they do not depend on the scale.
+
+    const MAX_ITERATIONS: usize = 64usize;
+    const TOLERANCE:      f64   = 1e-5f64;
+
+    /// Squared sine under which a simplex counts as flat
+    const FLATNESS:       f64   = 1e-6f64;
+
+
+//###############################
+// I M P L E M E N T A T I O N S
+//###############################
+
+    impl<V: Vector> Support<V> for V {
+        fn support(&self, _direction: V) -> V { *self }
+    } // impl ..
+
+
+    impl<V: Vector> Support<V> for [V] {
+        fn support(&self, direction: V) -> V { farthest(self.iter().copied(), direction) }
+    } // impl ..
+
+
+    impl<V: Vector> Support<V> for Segment<V> {
+        fn support(&self, direction: V) -> V { farthest([self.start, self.end], direction) }
+    } // impl ..
+
+
+    impl<V: Vector> Support<V> for Triangle<V> {
+        fn support(&self, direction: V) -> V { farthest([self.0, self.1, self.2], direction) }
+    } // impl ..
+
+
+    impl<V: Vector> Support<V> for Aabb<V> {
+        fn support(&self, direction: V) -> V {
+            let mut point = self.min;
+            for index in 0usize..V::DIMENSION {
+                if direction.component(index) > V::Scalar::ZERO { *point.component_mut(index) = self.max.component(index) }
+            } // for ..
+            point
+        } // fn ..
+    } // impl ..
+
+
+    impl<V: Vector> Support<V> for Ball<V> where V::Scalar: Sqrt {
+        fn support(&self, direction: V) -> V { self.center + direction.normalized() * self.radius }
+    } // impl ..
+
+
+    impl<V: Vector> Support<V> for Capsule<V> where V::Scalar: Sqrt {
+        fn support(&self, direction: V) -> V { self.segment.support(direction) + direction.normalized() * self.radius }
+    } // impl ..
+
+
+    impl<T: Signed, S: Support<Vec2<T>>> Support<Vec2<T>> for Transformed<S, Mat3<T>> {
+        fn support(&self, direction: Vec2<T>) -> Vec2<T> {
+            let local = Vec2(Vec2::dot(self.matrix.0.xy(), direction), Vec2::dot(self.matrix.1.xy(), direction));
+            self.matrix.transform_point(self.shape.support(local))
+        } // fn ..
+    } // impl ..
+
+
+    impl<T: Signed, S: Support<Vec3<T>>> Support<Vec3<T>> for Transformed<S, Mat4<T>> {
+        fn support(&self, direction: Vec3<T>) -> Vec3<T> {
+            let local = Vec3(
+                Vec3::dot(self.matrix.0.xyz(), direction),
+                Vec3::dot(self.matrix.1.xyz(), direction),
+                Vec3::dot(self.matrix.2.xyz(), direction),
+            ); // let ..
+            self.matrix.transform_point(self.shape.support(local))
+        } // fn ..
+    } // impl ..
+
+
+    /// Whether two convex shapes share at least one point
+    pub fn overlap<V, A, B>(a: &A, b: &B) -> bool
+    where V: Vector, V::Scalar: Float + Signed + Sqrt + AsF64, A: Support<V> + ?Sized, B: Support<V> + ?Sized
+    {
+        gjk(a, b).is_err()
+    } // fn ..
+
+
+    /// GJK distance between two convex shapes, `None` when they overlap
+    pub fn distance<V, A, B>(a: &A, b: &B) -> Option<Separation<V>>
+    where V: Vector, V::Scalar: Float + Signed + Sqrt + AsF64, A: Support<V> + ?Sized, B: Support<V> + ?Sized
+    {
+        gjk(a, b).ok()
+    } // fn ..
+
+
+    /// EPA penetration of two overlapping convex shapes, `None` when they are disjoint.
+    /// Moving `b` by `normal * distance` separates them, and the contact point lies in
+    /// the middle of the overlap
+    pub fn penetration<V, A, B>(a: &A, b: &B) -> Option<Hit<V>>
+    where V: Vector, V::Scalar: Float + Signed + Sqrt + AsF64, A: Support<V> + ?Sized, B: Support<V> + ?Sized
+    {
+        let mut simplex = gjk(a, b).err()?;
+        let half        = V::Scalar::ONE / (V::Scalar::ONE + V::Scalar::ONE);
+
+        // The polytope needs volume, flat differences have a zero depth across themselves
+        for index in 0usize..V::DIMENSION {
+            for sign in [V::Scalar::ONE, -V::Scalar::ONE] {
+                if simplex.len() > V::DIMENSION { continue }
+                let mut direction = V::ZERO;
+                *direction.component_mut(index) = sign;
+                let vertex = minkowski(a, b, direction);
+                let points: Vec<V> = simplex.iter().map(|vertex| vertex.point).chain([vertex.point]).collect();
+                if project(&points, V::ZERO).is_some() { simplex.push(vertex) }
+            } // for ..
+        } // for ..
+
+        if simplex.len() <= V::DIMENSION {
+            let points: Vec<V> = simplex.iter().map(|vertex| vertex.point).collect();
+            let normal = (0usize..V::DIMENSION).find_map(|index| {
+                let mut axis = points[0usize];
+                *axis.component_mut(index) += V::Scalar::ONE;
+                let (foot, _) = project(&points, axis)?;
+                let normal = (axis - foot).normalized();
+                (normal != V::ZERO).then_some(normal)
+            }).unwrap_or(V::ZERO); // let ..
+            let (_, weights) = project(&points, V::ZERO)?;
+            let (point_a, point_b) = witnesses(&simplex, &(0usize..simplex.len()).collect::<Vec<_>>(), &weights);
+            return Some(Hit { distance: V::Scalar::ZERO, point: (point_a + point_b) * half, normal })
+        } // if ..
+
+        // Every facet keeps facing away from the centroid of the first simplex, which stays inside
+        let interior = simplex.iter().fold(V::ZERO, |sum, vertex| sum + vertex.point) / V::Scalar::from_f64(simplex.len() as f64);
+        let mut facets: Vec<Facet<V>> = (0usize..simplex.len())
+            .filter_map(|skipped| facet(&simplex, (0usize..simplex.len()).filter(|&index| index != skipped).collect(), interior))
+            .collect();
+
+        for _ in 0usize..MAX_ITERATIONS {
+            let nearest = nearest_facet(&facets)?;
+            let normal  = facets[nearest].normal;
+            let vertex  = minkowski(a, b, normal);
+            let reach   = V::dot(vertex.point, normal);
+            if reach - facets[nearest].distance <= V::Scalar::from_f64(TOLERANCE) * (V::Scalar::ONE + facets[nearest].distance) { break }
+
+            // Facets seen from the new vertex go away, their ridges seen only once make the horizon
+            simplex.push(vertex);
+            let mut horizon: Vec<Vec<usize>> = Vec::new();
+            facets.retain(|facet| {
+                let visible = V::dot(facet.normal, vertex.point - simplex[facet.vertices[0usize]].point) > V::Scalar::ZERO;
+                if visible {
+                    for skipped in 0usize..facet.vertices.len() {
+                        let ridge: Vec<usize> = facet.vertices.iter().enumerate().filter(|&(index, _)| index != skipped).map(|(_, &vertex)| vertex).collect();
+                        match horizon.iter().position(|other| other == &ridge) {
+                            Some(position) => { horizon.swap_remove(position); }
+                            None           => horizon.push(ridge),
+                        } // match ..
+                    } // for ..
+                } // if ..
+                !visible
+            }); // retain()
+
+            let new = simplex.len() - 1usize;
+            for mut ridge in horizon {
+                ridge.push(new);
+                ridge.sort_unstable();
+                if let Some(facet) = facet(&simplex, ridge, interior) { facets.push(facet) }
+            } // for ..
+        } // for ..
+
+        let nearest = &facets[nearest_facet(&facets)?];
+        let points: Vec<V> = nearest.vertices.iter().map(|&index| simplex[index].point).collect();
+        let (_, weights) = project(&points, V::ZERO)?;
+        let (point_a, point_b) = witnesses(&simplex, &nearest.vertices, &weights);
+        Some(Hit { distance: nearest.distance, point: (point_a + point_b) * half, normal: nearest.normal })
+    } // fn ..
+
+
+    /// Runs GJK, returning the separation of disjoint shapes or the simplex holding the origin
+    fn gjk<V, A, B>(a: &A, b: &B) -> Result<Separation<V>, Vec<Vertex<V>>>
+    where V: Vector, V::Scalar: Float + Signed + Sqrt + AsF64, A: Support<V> + ?Sized, B: Support<V> + ?Sized
+    {
+        let mut simplex = vec![minkowski(a, b, V::splat(V::Scalar::ONE))];
+        let mut closest = simplex[0usize].point;
+        let mut weights = vec![V::Scalar::ONE];
+
+        for _ in 0usize..MAX_ITERATIONS {
+            // The origin counts as reached once it is this close relative to the size of the simplex
+            let squared = V::dot(closest, closest);
+            let scale   = simplex.iter().fold(V::Scalar::ZERO, |scale, vertex| { let size = V::dot(vertex.point, vertex.point); if size > scale { size } else { scale }});
+            if squared <= V::Scalar::from_f64(TOLERANCE * TOLERANCE) * scale { return Err(simplex) }
+
+            let vertex = minkowski(a, b, V::ZERO - closest);
+            let gain   = squared - V::dot(closest, vertex.point);
+            if gain <= V::Scalar::from_f64(TOLERANCE) * squared || simplex.iter().any(|other| other.point == vertex.point) { break }
+            simplex.push(vertex);
+
+            // Keeps the smallest face of the simplex whose projection of the origin falls inside it
+            let best = (1usize..(1usize << simplex.len())).filter_map(|subset| {
+                let indices: Vec<usize> = (0usize..simplex.len()).filter(|&index| subset & (1usize << index) != 0usize).collect();
+                let points:  Vec<V>     = indices.iter().map(|&index| simplex[index].point).collect();
+                let (foot, face_weights) = project(&points, V::ZERO)?;
+                if face_weights.iter().any(|&weight| weight <= V::Scalar::ZERO) { return None }
+                Some((V::dot(foot, foot), foot, indices, face_weights))
+            }).reduce(|best, face| if face.0 < best.0 { face } else { best }); // let ..
+
+            let Some((_, foot, indices, face_weights)) = best else { break };
+            simplex = indices.iter().map(|&index| simplex[index]).collect();
+            closest = foot;
+            weights = face_weights;
+            if simplex.len() > V::DIMENSION { return Err(simplex) }
+        } // for ..
+
+        let (point_a, point_b) = witnesses(&simplex, &(0usize..simplex.len()).collect::<Vec<_>>(), &weights);
+        Ok(Separation { distance: closest.magnitude(), point_a, point_b })
+    } // fn ..
+
+
+    fn minkowski<V: Vector, A: Support<V> + ?Sized, B: Support<V> + ?Sized>(a: &A, b: &B, direction: V) -> Vertex<V>
+    where V::Scalar: Signed
+    {
+        let (a, b) = (a.support(direction), b.support(V::ZERO - direction));
+        Vertex { point: a - b, a, b }
+    } // fn ..
+
+
+    fn farthest<V: Vector>(points: impl IntoIterator<Item = V>, direction: V) -> V {
+        let mut points = points.into_iter();
+        let first      = points.next().unwrap_or(V::ZERO);
+        points.fold((first, V::dot(first, direction)), |(best, reach), point| {
+            let distance = V::dot(point, direction);
+            if distance > reach { (point, distance) } else { (best, reach) }
+        }).0 // fold()
+    } // fn ..
+
+
+    /// Projection of `target` on the affine hull of the points along with its barycentric
+    /// weights, `None` when the points are too close to being affinely dependent
+    fn project<V: Vector>(points: &[V], target: V) -> Option<(V, Vec<V::Scalar>)> where V::Scalar: Signed + AsF64 {
+        let origin       = points[0usize];
+        let edges:  Vec<V> = points[1usize..].iter().map(|&point| point - origin).collect();
+        let size         = edges.len();
+
+        // Normal equations of the least squares, the Gram matrix is symmetric positive definite
+        // for independent edges so elimination needs no pivoting
+        let mut rows: Vec<Vec<V::Scalar>> = edges.iter().map(|&row| {
+            edges.iter().map(|&column| V::dot(row, column)).chain([V::dot(row, target - origin)]).collect()
+        }).collect(); // let ..
+
+        for pivot in 0usize..size {
+            if rows[pivot][pivot] <= V::Scalar::from_f64(FLATNESS) * V::dot(edges[pivot], edges[pivot]) { return None }
+            let (above, below) = rows.split_at_mut(pivot + 1usize);
+            for row in below {
+                let factor = row[pivot] / above[pivot][pivot];
+                for (value, &reference) in row[pivot..].iter_mut().zip(&above[pivot][pivot..]) { *value -= factor * reference }
+            } // for ..
+        } // for ..
+
+        let mut weights = vec![V::Scalar::ZERO; size + 1usize];
+        for row in (0usize..size).rev() {
+            let known = (row + 1usize..size).fold(rows[row][size], |known, column| known - rows[row][column] * weights[column + 1usize]);
+            weights[row + 1usize] = known / rows[row][row];
+        } // for ..
+
+        weights[0usize] = weights[1usize..].iter().fold(V::Scalar::ONE, |first, &weight| first - weight);
+        let foot = edges.iter().zip(&weights[1usize..]).fold(origin, |foot, (&edge, &weight)| foot + edge * weight);
+        Some((foot, weights))
+    } // fn ..
+
+
+    fn witnesses<V: Vector>(simplex: &[Vertex<V>], indices: &[usize], weights: &[V::Scalar]) -> (V, V) {
+        indices.iter().zip(weights).fold((V::ZERO, V::ZERO), |(a, b), (&index, &weight)| {
+            (a + simplex[index].a * weight, b + simplex[index].b * weight)
+        }) // fold()
+    } // fn ..
+
+
+    /// Facet through the vertices with its normal facing away from the interior point
+    fn facet<V: Vector>(simplex: &[Vertex<V>], vertices: Vec<usize>, interior: V) -> Option<Facet<V>> where V::Scalar: Signed + Sqrt + AsF64 {
+        let points: Vec<V> = vertices.iter().map(|&index| simplex[index].point).collect();
+        let (foot, _)      = project(&points, interior)?;
+        let normal         = (foot - interior).normalized();
+        if normal == V::ZERO { return None }
+        Some(Facet { distance: V::dot(normal, points[0usize]), vertices, normal })
+    } // fn ..
+
+
+    fn nearest_facet<V: Vector>(facets: &[Facet<V>]) -> Option<usize> {
+        (0usize..facets.len()).reduce(|nearest, index| if facets[index].distance < facets[nearest].distance { index } else { nearest })
+    } // fn ..
+
@@ -0,0 +1,219 @@
+//#############
+// M A C R O S
+//#############
+
+    /// Bridges a vector type to an external vector type sharing its scalar
+    #[cfg(feature = "glam")]
+    macro_rules! impl_glam_vec(
+        ($($V: ident<$T: ty> <=> $G: ty { $($c: ident => $i: tt),* });*$(;)*) => {$(
+
+            impl From<$V<$T>> for $G {
+                fn from(vector: $V<$T>) -> Self { <$G>::new($(vector.$i),*) }
+            } // impl From ..
+
+
+            impl From<$G> for $V<$T> {
+                fn from(vector: $G) -> Self { $V($(vector.$c),*) }
+            } // impl From ..
+
+        )*}
+    ); // impl_glam_vec()
+
+
+//###############################
+// I M P L E M E N T A T I O N S
+//###############################
+
+    //#########
+    // M I N T
+    //#########
+
+    #[cfg(feature = "mint")]
+    mod mint_impls {
+        use crate::traits::Number;
+        use crate::vectors::{Vec2, Vec3, Vec4};
+        use crate::matrices::{Mat3, Mat4, Affine2};
+
+
+        impl<T: Number> From<Vec2<T>> for mint::Vector2<T> { fn from(vec2: Vec2<T>) -> Self { mint::Vector2 { x: vec2.0, y: vec2.1 } }}
+        impl<T: Number> From<Vec3<T>> for mint::Vector3<T> { fn from(vec3: Vec3<T>) -> Self { mint::Vector3 { x: vec3.0, y: vec3.1, z: vec3.2 } }}
+        impl<T: Number> From<Vec4<T>> for mint::Vector4<T> { fn from(vec4: Vec4<T>) -> Self { mint::Vector4 { x: vec4.0, y: vec4.1, z: vec4.2, w: vec4.3 } }}
+        impl<T: Number> From<mint::Vector2<T>> for Vec2<T> { fn from(vec2: mint::Vector2<T>) -> Self { Vec2(vec2.x, vec2.y) }}
+        impl<T: Number> From<mint::Vector3<T>> for Vec3<T> { fn from(vec3: mint::Vector3<T>) -> Self { Vec3(vec3.x, vec3.y, vec3.z) }}
+        impl<T: Number> From<mint::Vector4<T>> for Vec4<T> { fn from(vec4: mint::Vector4<T>) -> Self { Vec4(vec4.x, vec4.y, vec4.z, vec4.w) }}
+
+        impl<T: Number> From<Vec2<T>> for mint::Point2<T> { fn from(vec2: Vec2<T>) -> Self { mint::Point2 { x: vec2.0, y: vec2.1 } }}
+        impl<T: Number> From<Vec3<T>> for mint::Point3<T> { fn from(vec3: Vec3<T>) -> Self { mint::Point3 { x: vec3.0, y: vec3.1, z: vec3.2 } }}
+        impl<T: Number> From<mint::Point2<T>> for Vec2<T> { fn from(point2: mint::Point2<T>) -> Self { Vec2(point2.x, point2.y) }}
+        impl<T: Number> From<mint::Point3<T>> for Vec3<T> { fn from(point3: mint::Point3<T>) -> Self { Vec3(point3.x, point3.y, point3.z) }}
+
+
+        impl<T: Number> From<Mat3<T>> for mint::ColumnMatrix3<T> {
+            fn from(mat3: Mat3<T>) -> Self { mint::ColumnMatrix3 { x: mat3.0.into(), y: mat3.1.into(), z: mat3.2.into() } }
+        } // impl ..
+
+
+        impl<T: Number> From<mint::ColumnMatrix3<T>> for Mat3<T> {
+            fn from(mat3: mint::ColumnMatrix3<T>) -> Self { Mat3(mat3.x.into(), mat3.y.into(), mat3.z.into()) }
+        } // impl ..
+
+
+        impl<T: Number> From<Mat4<T>> for mint::ColumnMatrix4<T> {
+            fn from(mat4: Mat4<T>) -> Self { mint::ColumnMatrix4 { x: mat4.0.into(), y: mat4.1.into(), z: mat4.2.into(), w: mat4.3.into() } }
+        } // impl ..
+
+
+        impl<T: Number> From<mint::ColumnMatrix4<T>> for Mat4<T> {
+            fn from(mat4: mint::ColumnMatrix4<T>) -> Self { Mat4(mat4.x.into(), mat4.y.into(), mat4.z.into(), mat4.w.into()) }
+        } // impl ..
+
+
+        impl<T: Number> From<Affine2<T>> for mint::ColumnMatrix2x3<T> {
+            fn from(affine2: Affine2<T>) -> Self { mint::ColumnMatrix2x3 { x: affine2.0.into(), y: affine2.1.into(), z: affine2.2.into() } }
+        } // impl ..
+
+
+        impl<T: Number> From<mint::ColumnMatrix2x3<T>> for Affine2<T> {
+            fn from(affine2: mint::ColumnMatrix2x3<T>) -> Self { Affine2(affine2.x.into(), affine2.y.into(), affine2.z.into()) }
+        } // impl ..
+    } // mod ..
+
+
+    //#########
+    // G L A M
+    //#########
+
+    #[cfg(feature = "glam")]
+    mod glam_impls {
+        use crate::vectors::{Vec2, Vec3, Vec4};
+        use crate::matrices::{Mat3, Mat4, Affine2};
+
+
+        impl_glam_vec!(
+            Vec2<f32> <=> glam::Vec2     { x => 0, y => 1 };
+            Vec3<f32> <=> glam::Vec3     { x => 0, y => 1, z => 2 };
+            Vec4<f32> <=> glam::Vec4     { x => 0, y => 1, z => 2, w => 3 };
+            Vec2<i32> <=> glam::IVec2    { x => 0, y => 1 };
+            Vec3<i32> <=> glam::IVec3    { x => 0, y => 1, z => 2 };
+            Vec4<i32> <=> glam::IVec4    { x => 0, y => 1, z => 2, w => 3 };
+            Vec2<u32> <=> glam::UVec2    { x => 0, y => 1 };
+            Vec3<u32> <=> glam::UVec3    { x => 0, y => 1, z => 2 };
+            Vec4<u32> <=> glam::UVec4    { x => 0, y => 1, z => 2, w => 3 };
+            Vec2<i16> <=> glam::I16Vec2  { x => 0, y => 1 };
+            Vec3<i16> <=> glam::I16Vec3  { x => 0, y => 1, z => 2 };
+            Vec4<i16> <=> glam::I16Vec4  { x => 0, y => 1, z => 2, w => 3 };
+            Vec2<u16> <=> glam::U16Vec2  { x => 0, y => 1 };
+            Vec3<u16> <=> glam::U16Vec3  { x => 0, y => 1, z => 2 };
+            Vec4<u16> <=> glam::U16Vec4  { x => 0, y => 1, z => 2, w => 3 };
+            Vec2<i8>  <=> glam::I8Vec2   { x => 0, y => 1 };
+            Vec3<i8>  <=> glam::I8Vec3   { x => 0, y => 1, z => 2 };
+            Vec4<i8>  <=> glam::I8Vec4   { x => 0, y => 1, z => 2, w => 3 };
+            Vec2<u8>  <=> glam::U8Vec2   { x => 0, y => 1 };
+            Vec3<u8>  <=> glam::U8Vec3   { x => 0, y => 1, z => 2 };
+            Vec4<u8>  <=> glam::U8Vec4   { x => 0, y => 1, z => 2, w => 3 };
+        ); // impl_glam_vec!()
+
+
+        impl From<Mat3<f32>> for glam::Mat3 {
+            fn from(mat3: Mat3<f32>) -> Self { glam::Mat3::from_cols(mat3.0.into(), mat3.1.into(), mat3.2.into()) }
+        } // impl ..
+
+
+        impl From<glam::Mat3> for Mat3<f32> {
+            fn from(mat3: glam::Mat3) -> Self { Mat3(mat3.x_axis.into(), mat3.y_axis.into(), mat3.z_axis.into()) }
+        } // impl ..
+
+
+        impl From<Mat4<f32>> for glam::Mat4 {
+            fn from(mat4: Mat4<f32>) -> Self { glam::Mat4::from_cols(mat4.0.into(), mat4.1.into(), mat4.2.into(), mat4.3.into()) }
+        } // impl ..
+
+
+        impl From<glam::Mat4> for Mat4<f32> {
+            fn from(mat4: glam::Mat4) -> Self { Mat4(mat4.x_axis.into(), mat4.y_axis.into(), mat4.z_axis.into(), mat4.w_axis.into()) }
+        } // impl ..
+
+
+        impl From<Affine2<f32>> for glam::Affine2 {
+            fn from(affine2: Affine2<f32>) -> Self { glam::Affine2::from_cols(affine2.0.into(), affine2.1.into(), affine2.2.into()) }
+        } // impl ..
+
+
+        impl From<glam::Affine2> for Affine2<f32> {
+            fn from(affine2: glam::Affine2) -> Self { Affine2(affine2.matrix2.x_axis.into(), affine2.matrix2.y_axis.into(), affine2.translation.into()) }
+        } // impl ..
+    } // mod ..
+
+
+    //#################
+    // N A L G E B R A
+    //#################
+
+    #[cfg(feature = "nalgebra")]
+    mod nalgebra_impls {
+        use nalgebra::Scalar;
+
+        use crate::traits::Number;
+        use crate::vectors::{Vec2, Vec3, Vec4};
+        use crate::matrices::{Mat3, Mat4};
+
+
+        impl<T: Number + Scalar> From<Vec2<T>> for nalgebra::Vector2<T> { fn from(vec2: Vec2<T>) -> Self { nalgebra::Vector2::new(vec2.0, vec2.1) }}
+        impl<T: Number + Scalar> From<Vec3<T>> for nalgebra::Vector3<T> { fn from(vec3: Vec3<T>) -> Self { nalgebra::Vector3::new(vec3.0, vec3.1, vec3.2) }}
+        impl<T: Number + Scalar> From<Vec4<T>> for nalgebra::Vector4<T> { fn from(vec4: Vec4<T>) -> Self { nalgebra::Vector4::new(vec4.0, vec4.1, vec4.2, vec4.3) }}
+        impl<T: Number + Scalar> From<nalgebra::Vector2<T>> for Vec2<T> { fn from(vec2: nalgebra::Vector2<T>) -> Self { Vec2(vec2[0usize], vec2[1usize]) }}
+        impl<T: Number + Scalar> From<nalgebra::Vector3<T>> for Vec3<T> { fn from(vec3: nalgebra::Vector3<T>) -> Self { Vec3(vec3[0usize], vec3[1usize], vec3[2usize]) }}
+        impl<T: Number + Scalar> From<nalgebra::Vector4<T>> for Vec4<T> { fn from(vec4: nalgebra::Vector4<T>) -> Self { Vec4(vec4[0usize], vec4[1usize], vec4[2usize], vec4[3usize]) }}
+
+        impl<T: Number + Scalar> From<Vec2<T>> for nalgebra::Point2<T> { fn from(vec2: Vec2<T>) -> Self { nalgebra::Point2::new(vec2.0, vec2.1) }}
+        impl<T: Number + Scalar> From<Vec3<T>> for nalgebra::Point3<T> { fn from(vec3: Vec3<T>) -> Self { nalgebra::Point3::new(vec3.0, vec3.1, vec3.2) }}
+        impl<T: Number + Scalar> From<nalgebra::Point2<T>> for Vec2<T> { fn from(point2: nalgebra::Point2<T>) -> Self { Vec2(point2[0usize], point2[1usize]) }}
+        impl<T: Number + Scalar> From<nalgebra::Point3<T>> for Vec3<T> { fn from(point3: nalgebra::Point3<T>) -> Self { Vec3(point3[0usize], point3[1usize], point3[2usize]) }}
+
+
+        impl<T: Number + Scalar> From<Mat3<T>> for nalgebra::Matrix3<T> {
+            /// Both sides are column-major, so columns map one to one
+            fn from(mat3: Mat3<T>) -> Self {
+                nalgebra::Matrix3::from_column_slice(&[
+                    mat3.0.0, mat3.0.1, mat3.0.2,
+                    mat3.1.0, mat3.1.1, mat3.1.2,
+                    mat3.2.0, mat3.2.1, mat3.2.2,
+                ]) // Matrix3
+            } // fn ..
+        } // impl ..
+
+
+        impl<T: Number + Scalar> From<nalgebra::Matrix3<T>> for Mat3<T> {
+            fn from(mat3: nalgebra::Matrix3<T>) -> Self {
+                Mat3(
+                    Vec3(mat3[(0usize, 0usize)], mat3[(1usize, 0usize)], mat3[(2usize, 0usize)]),
+                    Vec3(mat3[(0usize, 1usize)], mat3[(1usize, 1usize)], mat3[(2usize, 1usize)]),
+                    Vec3(mat3[(0usize, 2usize)], mat3[(1usize, 2usize)], mat3[(2usize, 2usize)]),
+                ) // Mat3()
+            } // fn ..
+        } // impl ..
+
+
+        impl<T: Number + Scalar> From<Mat4<T>> for nalgebra::Matrix4<T> {
+            fn from(mat4: Mat4<T>) -> Self {
+                nalgebra::Matrix4::from_column_slice(&[
+                    mat4.0.0, mat4.0.1, mat4.0.2, mat4.0.3,
+                    mat4.1.0, mat4.1.1, mat4.1.2, mat4.1.3,
+                    mat4.2.0, mat4.2.1, mat4.2.2, mat4.2.3,
+                    mat4.3.0, mat4.3.1, mat4.3.2, mat4.3.3,
+                ]) // Matrix4
+            } // fn ..
+        } // impl ..
+
+
+        impl<T: Number + Scalar> From<nalgebra::Matrix4<T>> for Mat4<T> {
+            fn from(mat4: nalgebra::Matrix4<T>) -> Self {
+                Mat4(
+                    Vec4(mat4[(0usize, 0usize)], mat4[(1usize, 0usize)], mat4[(2usize, 0usize)], mat4[(3usize, 0usize)]),
+                    Vec4(mat4[(0usize, 1usize)], mat4[(1usize, 1usize)], mat4[(2usize, 1usize)], mat4[(3usize, 1usize)]),
+                    Vec4(mat4[(0usize, 2usize)], mat4[(1usize, 2usize)], mat4[(2usize, 2usize)], mat4[(3usize, 2usize)]),
+                    Vec4(mat4[(0usize, 3usize)], mat4[(1usize, 3usize)], mat4[(2usize, 3usize)], mat4[(3usize, 3usize)]),
+                ) // Mat4()
+            } // fn ..
+        } // impl ..
+    } // mod ..
@@ -0,0 +1,187 @@
+//#########################
+// D E P E N D E N C I E S
+//#########################
+
+    use crate::traits::Number;
+    use crate::vectors::{Vec2, Vec3, Vec4};
+    use crate::matrices::{Mat3, Mat4};
+    use crate::Angle;
+
+
+//#############
+// M A C R O S
+//#############
+
+    macro_rules! impl_integer_lerp(
+        ($($T: ty),*$(,)*) => {$(
+
+            impl Lerp for $T {
+                /// Computed in `f64`, whose mantissa holds every 32-bit integer exactly
+                fn lerp(self, other: Self, t: f32) -> Self {
+                    (self as f64 + (other as f64 - self as f64) * t as f64).round() as $T
+                } // fn lerp()
+            } // impl Lerp ..
+
+
+            impl FixedLerp for $T {
+                fn lerp8(self, other: Self, t: u8) -> Self {
+                    (self as i64 + div_round((other as i64 - self as i64) * t as i64, u8::MAX as i64)) as $T
+                } // fn lerp8()
+
+                fn lerp16(self, other: Self, t: u16) -> Self {
+                    (self as i64 + div_round((other as i64 - self as i64) * t as i64, u16::MAX as i64)) as $T
+                } // fn lerp16()
+            } // impl FixedLerp ..
+
+        )*}
+    ); // impl_integer_lerp()
+
+
+    macro_rules! impl_componentwise_lerp(
+        ($($S: ident($($i: tt),*)),*$(,)*) => {$(
+
+            impl<T: Number + Lerp> Lerp for $S<T> {
+                fn lerp(self, other: Self, t: f32) -> Self {
+                    $S($(self.$i.lerp(other.$i, t)),*)
+                } // fn lerp()
+            } // impl Lerp ..
+
+
+            impl<T: Number + FixedLerp> FixedLerp for $S<T> {
+                fn lerp8(self, other: Self, t: u8) -> Self {
+                    $S($(self.$i.lerp8(other.$i, t)),*)
+                } // fn lerp8()
+
+                fn lerp16(self, other: Self, t: u16) -> Self {
+                    $S($(self.$i.lerp16(other.$i, t)),*)
+                } // fn lerp16()
+            } // impl FixedLerp ..
+
+        )*}
+    ); // impl_componentwise_lerp()
+
+
+//#######################
+// D E F I N I T I O N S
+//#######################
+
+    /// Linear interpolation where `t = 0` gives `self` and `t = 1` gives `other`,
+    /// values of `t` outside of that range extrapolate
+    pub trait Lerp: Copy {
+        fn lerp(self, other: Self, t: f32) -> Self;
+    } // trait ..
+
+
+    /// Linear interpolation for FPU-less targets where the fraction is stored
+    /// on 8 or 16 bits, the maximum value of `t` giving `other`
+    pub trait FixedLerp: Copy {
+        fn lerp8(self, other: Self, t: u8) -> Self;
+        fn lerp16(self, other: Self, t: u16) -> Self;
+    } // trait ..
+
+
+    /// Rounds to the nearest integer, halves away from zero
+    const fn div_round(numerator: i64, denominator: i64) -> i64 {
+        if numerator >= 0i64 { (numerator + denominator / 2i64) / denominator }
+        else                 { (numerator - denominator / 2i64) / denominator }
+    } // fn ..
+
+
+    pub fn lerp<T: Lerp>(a: T, b: T, t: f32) -> T { a.lerp(b, t) }
+
+
+    /// Returns `t` such that `lerp(a, b, t) == value`, or zero when `a == b`. Only takes
+    /// `f32`, integers are converted by the caller and `lerp8` or `lerp16` used on FPU-less targets
+    pub fn inverse_lerp(a: f32, b: f32, value: f32) -> f32 {
+        if a == b { 0f32 } else { (value - a) / (b - a) }
+    } // fn ..
+
+
+    /// Maps `value` from the `from` range to the `to` range, in `f32` like `inverse_lerp`
+    pub fn remap(value: f32, from: (f32, f32), to: (f32, f32)) -> f32 {
+        to.0.lerp(to.1, inverse_lerp(from.0, from.1, value))
+    } // fn ..
+
+
+    /// Hermite interpolation between 0 and 1 as `x` goes from `edge0` to `edge1`
+    pub fn smoothstep(edge0: f32, edge1: f32, x: f32) -> f32 {
+        let t = inverse_lerp(edge0, edge1, x).clamp(0f32, 1f32);
+        t * t * (3f32 - 2f32 * t)
+    } // fn ..
+
+
+    /// Smoothstep with zero first and second derivatives at both edges
+    pub fn smootherstep(edge0: f32, edge1: f32, x: f32) -> f32 {
+        let t = inverse_lerp(edge0, edge1, x).clamp(0f32, 1f32);
+        t * t * t * (t * (t * 6f32 - 15f32) + 10f32)
+    } // fn ..
+
+
+    /// Smoothstep of an 8-bit fraction
+    pub const fn smoothstep8(t: u8) -> u8 {
+        let (t, n) = (t as u64, u8::MAX as u64);
+        ((t * t * (3u64 * n - 2u64 * t) + n * n / 2u64) / (n * n)) as u8
+    } // fn ..
+
+
+    /// Smoothstep of a 16-bit fraction
+    pub const fn smoothstep16(t: u16) -> u16 {
+        let (t, n) = (t as u64, u16::MAX as u64);
+        ((t * t * (3u64 * n - 2u64 * t) + n * n / 2u64) / (n * n)) as u16
+    } // fn ..
+
+
+    /// Smootherstep of an 8-bit fraction
+    pub const fn smootherstep8(t: u8) -> u8 {
+        let (t, n) = (t as u128, u8::MAX as u128);
+        ((t * t * t * (6u128 * t * t + 10u128 * n * n - 15u128 * t * n) + n * n * n * n / 2u128) / (n * n * n * n)) as u8
+    } // fn ..
+
+
+    /// Smootherstep of a 16-bit fraction
+    pub const fn smootherstep16(t: u16) -> u16 {
+        let (t, n) = (t as u128, u16::MAX as u128);
+        ((t * t * t * (6u128 * t * t + 10u128 * n * n - 15u128 * t * n) + n * n * n * n / 2u128) / (n * n * n * n)) as u16
+    } // fn ..
+
+
+//###############################
+// I M P L E M E N T A T I O N S
+//###############################
+
+    impl_integer_lerp!(u8, u16, u32, i8, i16, i32);
+    impl_componentwise_lerp!(Vec2(0, 1), Vec3(0, 1, 2), Vec4(0, 1, 2, 3), Mat3(0, 1, 2), Mat4(0, 1, 2, 3));
+
+
+    impl Lerp for f32 {
+        fn lerp(self, other: Self, t: f32) -> Self { self + (other - self) * t }
+    } // impl ..
+
+
+    impl FixedLerp for f32 {
+        fn lerp8(self, other: Self, t: u8)   -> Self { self.lerp(other, t as f32 / u8::MAX as f32) }
+        fn lerp16(self, other: Self, t: u16) -> Self { self.lerp(other, t as f32 / u16::MAX as f32) }
+    } // impl ..
+
+
+    impl Lerp for Angle {
+        /// Turns along the shortest way around the circle
+        fn lerp(self, other: Self, t: f32) -> Self {
+            let difference = other.0.wrapping_sub(self.0) as i8 as f32;
+            Angle(self.0.wrapping_add((difference * t).round() as i32 as u8))
+        } // fn ..
+    } // impl ..
+
+
+    impl FixedLerp for Angle {
+        /// Turns along the shortest way around the circle
+        fn lerp8(self, other: Self, t: u8) -> Self {
+            let difference = other.0.wrapping_sub(self.0) as i8 as i64;
+            Angle(self.0.wrapping_add(div_round(difference * t as i64, u8::MAX as i64) as u8))
+        } // fn ..
+
+        fn lerp16(self, other: Self, t: u16) -> Self {
+            let difference = other.0.wrapping_sub(self.0) as i8 as i64;
+            Angle(self.0.wrapping_add(div_round(difference * t as i64, u16::MAX as i64) as u8))
+        } // fn ..
+    } // impl ..
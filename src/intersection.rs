@@ -0,0 +1,426 @@
+//#########################
+// D E P E N D E N C I E S
+//#########################
+
+    use crate::traits::{Zero, One, Signed, Vector};
+    use crate::vectors::{Vec2, Vec3};
+    use crate::geometry::{Ray, Segment, Line, Plane, Ball, Circle, Aabb, Aabb2, Triangle};
+
+
+//#######################
+// D E F I N I T I O N S
+//#######################
+
+    /// Result of a ray cast or of an overlap test
+    ///
+    /// - ray casts report the ray parameter of the first hit in `distance`, which is a
+    ///   distance when the direction is normalized, and a normal facing the ray
+    /// - overlap tests report the penetration depth in `distance` and a normal going from the
+    ///   first shape to the second, moving the second shape by `normal * distance` separates them
+    ///
+    /// The normal is zero when the ray starts inside the shape or runs along a segment.
+    #[derive(Clone, Copy, PartialEq, Eq, Hash, Default, Debug)]
+    pub struct Hit<V: Vector> {
+        pub distance: V::Scalar,
+        pub point:    V,
+        pub normal:   V,
+    } // struct ..
+
+
+    /// Hit of an integer ray cast, kept as fractions over a shared positive denominator
+    /// so that nothing gets rounded
+    #[derive(Clone, Copy, PartialEq, Eq, Hash, Default, Debug)]
+    pub struct ExactHit {
+        /// Ray parameter of the hit is `time / denominator`
+        pub time:        i128,
+        /// Hit point is `point / denominator`
+        pub point:       Vec2<i128>,
+        pub denominator: i128,
+        /// Normal facing the ray, not normalized
+        pub normal:      Vec2<i128>,
+    } // struct ..
+
+
+    fn axis<V: Vector>(index: usize, value: V::Scalar) -> V {
+        let mut axis = V::ZERO;
+        *axis.component_mut(index) = value;
+        axis
+    } // fn ..
+
+
+    fn widen(vector: Vec2<i32>) -> Vec2<i128> { Vec2(vector.0 as i128, vector.1 as i128) }
+
+
+    /// Perpendicular of `vector` turned to face against `direction`
+    fn facing(vector: Vec2<i128>, direction: Vec2<i128>) -> Vec2<i128> {
+        let normal = Vec2(-vector.1, vector.0);
+        if Vec2::dot(normal, direction) > 0i128 { -normal } else { normal }
+    } // fn ..
+
+
+    /// Parameters where `p + r * t` meets `q + s * u`, as `(t, u, denominator)`
+    /// with a positive denominator, or `None` when both lines are parallel
+    fn line_parameters(p: Vec2<i128>, r: Vec2<i128>, q: Vec2<i128>, s: Vec2<i128>) -> Option<(i128, i128, i128)> {
+        let denominator = Vec2::cross(r, s);
+        let (t, u)      = (Vec2::cross(q - p, s), Vec2::cross(q - p, r));
+
+        if denominator == 0i128     { None }
+        else if denominator < 0i128 { Some((-t, -u, -denominator)) }
+        else                        { Some((t, u, denominator)) }
+    } // fn ..
+
+
+    /// Smallest integer at least the square root of `value`
+    fn ceil_sqrt(value: i128) -> i128 {
+        let root = (value as u128).isqrt() as i128;
+        if root * root < value { root + 1i128 } else { root }
+    } // fn ..
+
+
+    //###########
+    // F L O A T
+    //###########
+
+    /// Slab test, works in 2D and 3D
+    pub fn ray_aabb<V: Vector<Scalar = f32>>(ray: &Ray<V>, aabb: &Aabb<V>) -> Option<Hit<V>> {
+        let (mut enter, mut exit) = (0f32, f32::INFINITY);
+        let mut normal            = V::ZERO;
+
+        for index in 0usize..V::DIMENSION {
+            let (origin, direction) = (ray.origin.component(index), ray.direction.component(index));
+            let (min, max)          = (aabb.min.component(index), aabb.max.component(index));
+
+            if direction == 0f32 {
+                if origin < min || origin > max { return None }
+                continue;
+            } // if ..
+
+            let (near, far, side) = if direction > 0f32 { ((min - origin) / direction, (max - origin) / direction, -1f32) }
+                                    else                { ((max - origin) / direction, (min - origin) / direction,  1f32) };
+            if near > enter { enter = near; normal = axis(index, side); }
+            exit = exit.min(far);
+            if enter > exit { return None }
+        } // for ..
+
+        Some(Hit { distance: enter, point: ray.at(enter), normal })
+    } // fn ..
+
+
+    /// Works with circles and spheres
+    pub fn ray_sphere<V: Vector<Scalar = f32>>(ray: &Ray<V>, sphere: &Ball<V>) -> Option<Hit<V>> {
+        let offset = ray.origin - sphere.center;
+        let a      = V::dot(ray.direction, ray.direction);
+        let b      = V::dot(offset, ray.direction);
+        let c      = V::dot(offset, offset) - sphere.radius * sphere.radius;
+
+        if c <= 0f32 { return Some(Hit { distance: 0f32, point: ray.origin, normal: V::ZERO }) }
+        if b > 0f32 || a == 0f32 { return None }
+
+        let discriminant = b * b - a * c;
+        if discriminant < 0f32 { return None }
+
+        let distance = (-b - discriminant.sqrt()) / a;
+        let point    = ray.at(distance);
+        Some(Hit { distance, point, normal: (point - sphere.center).normalized() })
+    } // fn ..
+
+
+    pub fn ray_plane(ray: &Ray<Vec3<f32>>, plane: &Plane<f32>) -> Option<Hit<Vec3<f32>>> {
+        let denominator = Vec3::dot(plane.normal, ray.direction);
+        if denominator == 0f32 { return None }
+
+        let distance = (plane.distance - Vec3::dot(plane.normal, ray.origin)) / denominator;
+        if distance < 0f32 { return None }
+
+        let normal = plane.normal.normalized();
+        Some(Hit { distance, point: ray.at(distance), normal: if denominator > 0f32 { -normal } else { normal }})
+    } // fn ..
+
+
+    /// Möller–Trumbore test, both faces are hit
+    pub fn ray_triangle(ray: &Ray<Vec3<f32>>, triangle: &Triangle<Vec3<f32>>) -> Option<Hit<Vec3<f32>>> {
+        let Triangle(a, b, c) = *triangle;
+        let (edge1, edge2)    = (b - a, c - a);
+        let p                 = Vec3::cross(ray.direction, edge2);
+        let determinant       = Vec3::dot(edge1, p);
+        if determinant == 0f32 { return None }
+
+        let inverse = 1f32 / determinant;
+        let s       = ray.origin - a;
+        let u       = Vec3::dot(s, p) * inverse;
+        if !(0f32..=1f32).contains(&u) { return None }
+
+        let q = Vec3::cross(s, edge1);
+        let v = Vec3::dot(ray.direction, q) * inverse;
+        if v < 0f32 || u + v > 1f32 { return None }
+
+        let distance = Vec3::dot(edge2, q) * inverse;
+        if distance < 0f32 { return None }
+
+        let normal = Vec3::cross(edge1, edge2).normalized();
+        Some(Hit { distance, point: ray.at(distance), normal: if determinant < 0f32 { -normal } else { normal }})
+    } // fn ..
+
+
+    /// First point of `a` touching `b`, the distance is the parameter along `a` from 0 to 1
+    /// and the normal is the one of `b` facing the start of `a`
+    pub fn segment_segment(a: &Segment<Vec2<f32>>, b: &Segment<Vec2<f32>>) -> Option<Hit<Vec2<f32>>> {
+        let (p, r)      = (a.start, a.end - a.start);
+        let (q, s)      = (b.start, b.end - b.start);
+        let denominator = Vec2::cross(r, s);
+
+        if denominator == 0f32 {
+            if Vec2::cross(q - p, r) != 0f32 { return None }
+            let length = Vec2::dot(r, r);
+            if length == 0f32 { return b.contains(p).then_some(Hit { distance: 0f32, point: p, normal: Vec2::ZERO }) }
+
+            let start    = Vec2::dot(q - p, r) / length;
+            let end      = start + Vec2::dot(s, r) / length;
+            let (lo, hi) = if start < end { (start, end) } else { (end, start) };
+            if hi < 0f32 || lo > 1f32 { return None }
+
+            let distance = lo.max(0f32);
+            return Some(Hit { distance, point: p + r * distance, normal: Vec2::ZERO })
+        } // if ..
+
+        let distance = Vec2::cross(q - p, s) / denominator;
+        let u        = Vec2::cross(q - p, r) / denominator;
+        if !(0f32..=1f32).contains(&distance) || !(0f32..=1f32).contains(&u) { return None }
+
+        let normal = Vec2(-s.1, s.0).normalized();
+        Some(Hit { distance, point: p + r * distance, normal: if Vec2::dot(normal, p - q) < 0f32 { -normal } else { normal }})
+    } // fn ..
+
+
+    /// Separates along the axis of least penetration, works in 2D and 3D,
+    /// exact for integers except for the contact point which is rounded
+    pub fn aabb_aabb<V: Vector>(a: &Aabb<V>, b: &Aabb<V>) -> Option<Hit<V>> where V::Scalar: Signed {
+        let two        = V::Scalar::ONE + V::Scalar::ONE;
+        let mut result = None;
+
+        for index in 0usize..V::DIMENSION {
+            let low     = if a.min.component(index) > b.min.component(index) { a.min.component(index) } else { b.min.component(index) };
+            let high    = if a.max.component(index) < b.max.component(index) { a.max.component(index) } else { b.max.component(index) };
+            let overlap = high - low;
+            if overlap < V::Scalar::ZERO { return None }
+
+            if result.is_none_or(|(depth, _): (V::Scalar, usize)| overlap < depth) { result = Some((overlap, index)) }
+        } // for ..
+
+        let (distance, index) = result?;
+        let side              = if b.min.component(index) + b.max.component(index) >= a.min.component(index) + a.max.component(index) { V::Scalar::ONE } else { -V::Scalar::ONE };
+        let point             = (V::max(a.min, b.min) + V::min(a.max, b.max)) / two;
+        Some(Hit { distance, point, normal: axis(index, side) })
+    } // fn ..
+
+
+    /// Works with circles and spheres, the normal goes from the sphere to the box
+    pub fn sphere_aabb<V: Vector<Scalar = f32>>(sphere: &Ball<V>, aabb: &Aabb<V>) -> Option<Hit<V>> {
+        let closest = aabb.closest_point(sphere.center);
+        let offset  = closest - sphere.center;
+        if V::dot(offset, offset) > sphere.radius * sphere.radius { return None }
+
+        if !aabb.contains(sphere.center) {
+            let distance = offset.magnitude();
+            return Some(Hit { distance: sphere.radius - distance, point: closest, normal: offset / distance })
+        } // if ..
+
+        let (mut depth, mut face, mut normal) = (f32::INFINITY, sphere.center, V::ZERO);
+        for index in 0usize..V::DIMENSION {
+            let center = sphere.center.component(index);
+            for (gap, bound, side) in [(center - aabb.min.component(index), aabb.min.component(index), 1f32), (aabb.max.component(index) - center, aabb.max.component(index), -1f32)] {
+                if gap < depth { depth = gap; face = sphere.center; *face.component_mut(index) = bound; normal = axis(index, side); }
+            } // for ..
+        } // for ..
+
+        Some(Hit { distance: sphere.radius + depth, point: face, normal })
+    } // fn ..
+
+
+    /// Works with circles and spheres, the contact point lies in the middle of the overlap
+    pub fn circle_circle<V: Vector<Scalar = f32>>(a: &Ball<V>, b: &Ball<V>) -> Option<Hit<V>> {
+        let offset = b.center - a.center;
+        let reach  = a.radius + b.radius;
+        if V::dot(offset, offset) > reach * reach { return None }
+
+        let distance = offset.magnitude();
+        let normal   = if distance > 0f32 { offset / distance } else { axis(0usize, 1f32) };
+        let depth    = reach - distance;
+        Some(Hit { distance: depth, point: a.center + normal * (a.radius - depth * 0.5f32), normal })
+    } // fn ..
+
+
+    //###########
+    // E X A C T
+    //###########
+
+    /// Integer slab test, exact as long as the differences between coordinates fit in an `i32`
+    pub fn ray_aabb_exact(ray: &Ray<Vec2<i32>>, aabb: &Aabb2<i32>) -> Option<ExactHit> {
+        let (mut enter, mut enter_denominator) = (0i128, 1i128);
+        let mut exit: Option<(i128, i128)>     = None;
+        let mut normal                         = Vec2(0i128, 0i128);
+
+        for index in 0usize..2usize {
+            let (origin, direction) = (ray.origin.component(index) as i128, ray.direction.component(index) as i128);
+            let (min, max)          = (aabb.min.component(index) as i128, aabb.max.component(index) as i128);
+
+            if direction == 0i128 {
+                if origin < min || origin > max { return None }
+                continue;
+            } // if ..
+
+            let (near, far, side) = if direction > 0i128 { (min - origin, max - origin, -1i128) }
+                                    else                 { (origin - max, origin - min,  1i128) };
+            let denominator       = direction.abs();
+
+            if near * enter_denominator > enter * denominator { (enter, enter_denominator, normal) = (near, denominator, axis(index, side)) }
+            if exit.is_none_or(|(time, time_denominator)| far * time_denominator < time * denominator) { exit = Some((far, denominator)) }
+            if exit.is_some_and(|(time, time_denominator)| enter * time_denominator > time * enter_denominator) { return None }
+        } // for ..
+
+        Some(ExactHit::new(ray.origin, ray.direction, enter, enter_denominator, normal))
+    } // fn ..
+
+
+    /// Integer ray–circle test, exact as long as the differences between coordinates
+    /// fit in an `i32`, the hit lies on an irrational time which is rounded towards the origin
+    pub fn ray_circle_exact(ray: &Ray<Vec2<i32>>, circle: &Circle<i32>) -> Option<ExactHit> {
+        let (direction, offset) = (widen(ray.direction), widen(ray.origin) - widen(circle.center));
+        let a                   = Vec2::dot(direction, direction);
+        let b                   = Vec2::dot(offset, direction);
+        let c                   = Vec2::dot(offset, offset) - circle.radius as i128 * circle.radius as i128;
+
+        if c <= 0i128 { return Some(ExactHit::new(ray.origin, ray.direction, 0i128, 1i128, Vec2::ZERO)) }
+        if b > 0i128 || a == 0i128 { return None }
+
+        let discriminant = b * b - a * c;
+        if discriminant < 0i128 { return None }
+
+        let time = (-b - ceil_sqrt(discriminant)).max(0i128);
+        let hit  = ExactHit::new(ray.origin, ray.direction, time, a, Vec2::ZERO);
+        Some(ExactHit { normal: hit.point - widen(circle.center) * a, ..hit })
+    } // fn ..
+
+
+    /// Integer counterpart of `ray_plane`, exact as long as the differences between
+    /// coordinates fit in an `i32`
+    pub fn ray_line_exact(ray: &Ray<Vec2<i32>>, line: &Line<Vec2<i32>>) -> Option<ExactHit> {
+        let (origin, direction) = (widen(ray.origin), widen(ray.direction));
+        let (point, along)      = (widen(line.point), widen(line.direction));
+
+        match line_parameters(origin, direction, point, along) {
+            Some((time, _, denominator)) => (time >= 0i128).then(|| ExactHit::new(ray.origin, ray.direction, time, denominator, facing(along, direction))),
+            None                         => (Vec2::cross(along, origin - point) == 0i128).then(|| ExactHit::new(ray.origin, ray.direction, 0i128, 1i128, Vec2::ZERO)),
+        } // match ..
+    } // fn ..
+
+
+    /// Integer counterpart of `ray_triangle` in the plane, exact as long as the differences
+    /// between coordinates fit in an `i32`
+    pub fn ray_triangle_exact(ray: &Ray<Vec2<i32>>, triangle: &Triangle<Vec2<i32>>) -> Option<ExactHit> {
+        if triangle.contains(ray.origin) { return Some(ExactHit::new(ray.origin, ray.direction, 0i128, 1i128, Vec2::ZERO)) }
+
+        let Triangle(a, b, c)      = *triangle;
+        let (origin, direction)    = (widen(ray.origin), widen(ray.direction));
+        let mut best: Option<(i128, i128, Vec2<i128>)> = None;
+
+        for (start, end) in [(a, b), (b, c), (c, a)] {
+            let edge = widen(end) - widen(start);
+            let Some((time, u, denominator)) = line_parameters(origin, direction, widen(start), edge) else { continue };
+            if time < 0i128 || u < 0i128 || u > denominator { continue }
+            if best.is_none_or(|(best_time, best_denominator, _)| time * best_denominator < best_time * denominator) {
+                best = Some((time, denominator, facing(edge, direction)))
+            } // if ..
+        } // for ..
+
+        best.map(|(time, denominator, normal)| ExactHit::new(ray.origin, ray.direction, time, denominator, normal))
+    } // fn ..
+
+
+    /// Integer counterpart of `segment_segment`, the time is the parameter along `a` from 0 to 1,
+    /// exact as long as the differences between coordinates fit in an `i32`
+    pub fn segment_segment_exact(a: &Segment<Vec2<i32>>, b: &Segment<Vec2<i32>>) -> Option<ExactHit> {
+        let (p, r) = (widen(a.start), widen(a.end) - widen(a.start));
+        let (q, s) = (widen(b.start), widen(b.end) - widen(b.start));
+
+        if let Some((time, u, denominator)) = line_parameters(p, r, q, s) {
+            if time < 0i128 || time > denominator || u < 0i128 || u > denominator { return None }
+            return Some(ExactHit::new(a.start, a.end - a.start, time, denominator, facing(s, r)))
+        } // if ..
+
+        if Vec2::cross(q - p, r) != 0i128 { return None }
+        let length = Vec2::dot(r, r);
+        if length == 0i128 {
+            let along = Vec2::dot(p - q, s);
+            let on_b  = Vec2::cross(s, p - q) == 0i128 && along >= 0i128 && along <= Vec2::dot(s, s);
+            return on_b.then(|| ExactHit::new(a.start, a.end - a.start, 0i128, 1i128, Vec2::ZERO))
+        } // if ..
+
+        let (start, end) = (Vec2::dot(q - p, r), Vec2::dot(q + s - p, r));
+        let (lo, hi)     = if start < end { (start, end) } else { (end, start) };
+        if hi < 0i128 || lo > length { return None }
+        Some(ExactHit::new(a.start, a.end - a.start, lo.max(0i128), length, Vec2::ZERO))
+    } // fn ..
+
+
+    /// Integer counterpart of `sphere_aabb`, exact as long as the differences between coordinates
+    /// fit in an `i32`, the depth is rounded down and the normal is not normalized
+    pub fn circle_aabb_exact(circle: &Circle<i32>, aabb: &Aabb2<i32>) -> Option<Hit<Vec2<i32>>> {
+        let closest  = aabb.closest_point(circle.center);
+        let offset   = widen(closest) - widen(circle.center);
+        let distance = Vec2::dot(offset, offset);
+        let radius   = circle.radius as i128;
+        if distance > radius * radius { return None }
+
+        if !aabb.contains(circle.center) {
+            let depth = (radius - ceil_sqrt(distance)) as i32;
+            return Some(Hit { distance: depth, point: closest, normal: Vec2(offset.0 as i32, offset.1 as i32) })
+        } // if ..
+
+        let (mut depth, mut face, mut normal) = (i128::MAX, circle.center, Vec2::ZERO);
+        for index in 0usize..2usize {
+            let center = circle.center.component(index);
+            for (gap, bound, side) in [(center as i128 - aabb.min.component(index) as i128, aabb.min.component(index), 1i32), (aabb.max.component(index) as i128 - center as i128, aabb.max.component(index), -1i32)] {
+                if gap < depth { depth = gap; face = circle.center; *face.component_mut(index) = bound; normal = axis(index, side); }
+            } // for ..
+        } // for ..
+
+        Some(Hit { distance: (radius + depth) as i32, point: face, normal })
+    } // fn ..
+
+
+    /// Integer counterpart of `circle_circle`, exact as long as the differences between coordinates
+    /// fit in an `i32`, the depth and point are rounded and the normal is not normalized
+    pub fn circle_circle_exact(a: &Circle<i32>, b: &Circle<i32>) -> Option<Hit<Vec2<i32>>> {
+        let offset = widen(b.center) - widen(a.center);
+        let reach  = a.radius as i128 + b.radius as i128;
+        if Vec2::dot(offset, offset) > reach * reach { return None }
+
+        let distance      = ceil_sqrt(Vec2::dot(offset, offset));
+        let depth         = reach - distance;
+        let (normal, run) = if distance > 0i128 { (offset, distance) } else { (Vec2(1i128, 0i128), 1i128) };
+        let point         = widen(a.center) + normal * (2i128 * a.radius as i128 - depth) / (2i128 * run);
+        Some(Hit { distance: depth as i32, point: Vec2(point.0 as i32, point.1 as i32), normal: Vec2(normal.0 as i32, normal.1 as i32) })
+    } // fn ..
+
+
+//###############################
+// I M P L E M E N T A T I O N S
+//###############################
+
+    impl ExactHit {
+        fn new(origin: Vec2<i32>, direction: Vec2<i32>, time: i128, denominator: i128, normal: Vec2<i128>) -> Self {
+            let point = widen(origin) * denominator + widen(direction) * time;
+            ExactHit { time, point, denominator, normal }
+        } // fn ..
+
+
+        /// Ray parameter of the hit, rounded
+        pub fn distance(&self) -> f32 { (self.time as f64 / self.denominator as f64) as f32 }
+
+
+        /// Hit point, rounded
+        pub fn point(&self) -> Vec2<f32> {
+            Vec2((self.point.0 as f64 / self.denominator as f64) as f32, (self.point.1 as f64 / self.denominator as f64) as f32)
+        } // fn ..
+    } // impl ..
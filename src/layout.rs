@@ -0,0 +1,279 @@
+//#########################
+// D E P E N D E N C I E S
+//#########################
+
+    use std::fmt;
+    use std::error::Error;
+
+    use crate::traits::Number;
+    use crate::vectors::{Vec2, Vec3, Vec4};
+    use crate::matrices::{Mat3, Mat4};
+
+
+//#############
+// M A C R O S
+//#############
+
+    macro_rules! impl_gpu_scalar(
+        ($($T: ty),*$(,)*) => {$(
+
+            impl GpuScalar for $T {
+                fn to_block_bytes(self) -> [u8; 4usize] { self.to_ne_bytes() }
+            } // impl GpuScalar ..
+
+
+            impl GpuLayout for $T {
+                fn alignment(_layout: BlockLayout) -> usize { 4usize }
+                fn size(_layout: BlockLayout)      -> usize { 4usize }
+                fn write(&self, _layout: BlockLayout, bytes: &mut [u8]) { bytes[..4usize].copy_from_slice(&self.to_block_bytes()) }
+            } // impl GpuLayout ..
+
+        )*}
+    ); // impl_gpu_scalar()
+
+
+//#######################
+// D E F I N I T I O N S
+//#######################
+
+    /// Memory layout rules of GLSL interface blocks
+    #[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+    pub enum BlockLayout {
+        /// Uniform buffer rules, arrays and structures are aligned to 16 bytes
+        Std140,
+        /// Storage buffer rules, arrays and structures keep their element alignment
+        Std430,
+    } // enum ..
+
+
+    /// 32-bit scalar that can be stored in an interface block
+    pub trait GpuScalar: Number {
+        fn to_block_bytes(self) -> [u8; 4usize];
+    } // trait ..
+
+
+    /// Value that can be written into an interface block
+    pub trait GpuLayout {
+        fn alignment(layout: BlockLayout) -> usize;
+        fn size(layout: BlockLayout) -> usize;
+        /// Writes the value at the start of `bytes`, padding included
+        fn write(&self, layout: BlockLayout, bytes: &mut [u8]);
+
+        /// Distance between two consecutive elements of an array of this type
+        fn array_stride(layout: BlockLayout) -> usize {
+            let stride = round_up(Self::size(layout), Self::alignment(layout));
+            match layout {
+                BlockLayout::Std140 => round_up(stride, 16usize),
+                BlockLayout::Std430 => stride,
+            } // match ..
+        } // fn ..
+    } // trait ..
+
+
+    /// Builds the content of an interface block one member at a time,
+    /// following the declaration order of its GLSL counterpart
+    #[derive(Clone, PartialEq, Eq, Hash, Debug)]
+    pub struct Block {
+        layout:    BlockLayout,
+        bytes:     Vec<u8>,
+        alignment: usize,
+    } // struct ..
+
+
+    /// Returned when a member does not land at the offset it was declared with
+    #[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+    pub struct OffsetMismatch {
+        pub expected: usize,
+        pub found:    usize,
+    } // struct ..
+
+
+    /// Returned when a nested block follows other rules than the block it is pushed into,
+    /// its array and structure strides would not match the ones the shader expects
+    #[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+    pub struct LayoutMismatch {
+        pub expected: BlockLayout,
+        pub found:    BlockLayout,
+    } // struct ..
+
+
+    const fn round_up(value: usize, alignment: usize) -> usize {
+        value.div_ceil(alignment) * alignment
+    } // fn ..
+
+
+    const fn array_alignment(layout: BlockLayout, alignment: usize) -> usize {
+        match layout {
+            BlockLayout::Std140 => round_up(alignment, 16usize),
+            BlockLayout::Std430 => alignment,
+        } // match ..
+    } // fn ..
+
+
+//###############################
+// I M P L E M E N T A T I O N S
+//###############################
+
+    impl_gpu_scalar!(u32, i32, f32);
+
+
+    impl<S: GpuScalar> GpuLayout for Vec2<S> {
+        fn alignment(_layout: BlockLayout) -> usize { 8usize }
+        fn size(_layout: BlockLayout)      -> usize { 8usize }
+        fn write(&self, _layout: BlockLayout, bytes: &mut [u8]) {
+            bytes[0usize..4usize].copy_from_slice(&self.0.to_block_bytes());
+            bytes[4usize..8usize].copy_from_slice(&self.1.to_block_bytes());
+        } // fn ..
+    } // impl ..
+
+
+    impl<S: GpuScalar> GpuLayout for Vec3<S> {
+        fn alignment(_layout: BlockLayout) -> usize { 16usize }
+        fn size(_layout: BlockLayout)      -> usize { 12usize }
+        fn write(&self, _layout: BlockLayout, bytes: &mut [u8]) {
+            bytes[0usize..4usize].copy_from_slice(&self.0.to_block_bytes());
+            bytes[4usize..8usize].copy_from_slice(&self.1.to_block_bytes());
+            bytes[8usize..12usize].copy_from_slice(&self.2.to_block_bytes());
+        } // fn ..
+    } // impl ..
+
+
+    impl<S: GpuScalar> GpuLayout for Vec4<S> {
+        fn alignment(_layout: BlockLayout) -> usize { 16usize }
+        fn size(_layout: BlockLayout)      -> usize { 16usize }
+        fn write(&self, _layout: BlockLayout, bytes: &mut [u8]) {
+            bytes[0usize..4usize].copy_from_slice(&self.0.to_block_bytes());
+            bytes[4usize..8usize].copy_from_slice(&self.1.to_block_bytes());
+            bytes[8usize..12usize].copy_from_slice(&self.2.to_block_bytes());
+            bytes[12usize..16usize].copy_from_slice(&self.3.to_block_bytes());
+        } // fn ..
+    } // impl ..
+
+
+    impl<S: GpuScalar> GpuLayout for Mat3<S> {
+        /// Stored as an array of three `Vec3` columns, each padded to 16 bytes
+        fn alignment(_layout: BlockLayout) -> usize { 16usize }
+        fn size(_layout: BlockLayout)      -> usize { 48usize }
+        fn write(&self, layout: BlockLayout, bytes: &mut [u8]) {
+            bytes[..48usize].fill(0u8);
+            self.0.write(layout, &mut bytes[0usize..]);
+            self.1.write(layout, &mut bytes[16usize..]);
+            self.2.write(layout, &mut bytes[32usize..]);
+        } // fn ..
+    } // impl ..
+
+
+    impl<S: GpuScalar> GpuLayout for Mat4<S> {
+        fn alignment(_layout: BlockLayout) -> usize { 16usize }
+        fn size(_layout: BlockLayout)      -> usize { 64usize }
+        fn write(&self, layout: BlockLayout, bytes: &mut [u8]) {
+            self.0.write(layout, &mut bytes[0usize..]);
+            self.1.write(layout, &mut bytes[16usize..]);
+            self.2.write(layout, &mut bytes[32usize..]);
+            self.3.write(layout, &mut bytes[48usize..]);
+        } // fn ..
+    } // impl ..
+
+
+    impl<T: GpuLayout, const N: usize> GpuLayout for [T; N] {
+        fn alignment(layout: BlockLayout) -> usize { array_alignment(layout, T::alignment(layout)) }
+        fn size(layout: BlockLayout)      -> usize { T::array_stride(layout) * N }
+        fn write(&self, layout: BlockLayout, bytes: &mut [u8]) {
+            let stride = T::array_stride(layout);
+            bytes[..stride * N].fill(0u8);
+            for (index, element) in self.iter().enumerate() { element.write(layout, &mut bytes[index * stride..]) }
+        } // fn ..
+    } // impl ..
+
+
+    impl Block {
+        pub fn new(layout: BlockLayout) -> Self {
+            Block { layout, bytes: Vec::new(), alignment: 4usize }
+        } // fn ..
+
+
+        pub fn layout(&self) -> BlockLayout { self.layout }
+
+        /// Alignment of the block when nested as a structure member
+        pub fn alignment(&self) -> usize { array_alignment(self.layout, self.alignment) }
+
+        /// Size of the block, padded to its alignment
+        pub fn size(&self) -> usize { round_up(self.bytes.len(), self.alignment()) }
+
+
+        /// Offset the next member of type `T` would be written at
+        pub fn offset_of<T: GpuLayout>(&self) -> usize {
+            round_up(self.bytes.len(), T::alignment(self.layout))
+        } // fn ..
+
+
+        /// Appends a member and returns its offset
+        pub fn push<T: GpuLayout>(&mut self, value: &T) -> usize {
+            let alignment = T::alignment(self.layout);
+            let offset    = self.reserve(alignment, T::size(self.layout));
+            value.write(self.layout, &mut self.bytes[offset..]);
+            offset
+        } // fn ..
+
+
+        /// Appends a member after checking it lands at the `expected` offset,
+        /// such as the one reported by shader reflection
+        pub fn push_at<T: GpuLayout>(&mut self, expected: usize, value: &T) -> Result<usize, OffsetMismatch> {
+            let found = self.offset_of::<T>();
+            if found != expected { return Err(OffsetMismatch { expected, found }) }
+            Ok(self.push(value))
+        } // fn ..
+
+
+        /// Appends an unsized array member and returns its offset
+        pub fn push_array<T: GpuLayout>(&mut self, values: &[T]) -> usize {
+            let stride = T::array_stride(self.layout);
+            let offset = self.reserve(array_alignment(self.layout, T::alignment(self.layout)), stride * values.len());
+            for (index, value) in values.iter().enumerate() { value.write(self.layout, &mut self.bytes[offset + index * stride..]) }
+            offset
+        } // fn ..
+
+
+        /// Appends a nested structure laid out with the same rules and returns its offset
+        pub fn push_block(&mut self, block: &Block) -> Result<usize, LayoutMismatch> {
+            if block.layout != self.layout { return Err(LayoutMismatch { expected: self.layout, found: block.layout }) }
+            let offset = self.reserve(block.alignment(), block.size());
+            self.bytes[offset..offset + block.bytes.len()].copy_from_slice(&block.bytes);
+            Ok(offset)
+        } // fn ..
+
+
+        /// Returns the content of the block padded to its size
+        pub fn into_bytes(mut self) -> Vec<u8> {
+            self.bytes.resize(self.size(), 0u8);
+            self.bytes
+        } // fn ..
+
+
+        fn reserve(&mut self, alignment: usize, size: usize) -> usize {
+            let offset = round_up(self.bytes.len(), alignment);
+            self.bytes.resize(offset + size, 0u8);
+            self.alignment = self.alignment.max(alignment);
+            offset
+        } // fn ..
+    } // impl ..
+
+
+    impl fmt::Display for OffsetMismatch {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(f, "member expected at offset {} but laid out at offset {}", self.expected, self.found)
+        } // fn ..
+    } // impl ..
+
+
+    impl Error for OffsetMismatch {}
+
+
+    impl fmt::Display for LayoutMismatch {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(f, "nested block laid out with {:?} inside a {:?} block", self.found, self.expected)
+        } // fn ..
+    } // impl ..
+
+
+    impl Error for LayoutMismatch {}
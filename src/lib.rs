@@ -6,5 +6,30 @@
         mod angle;
     pub mod vectors;
     pub mod matrices;
+    pub mod layout;
+    pub mod display;
+    pub mod interpolation;
+    pub mod easing;
+    pub mod curves;
+    pub mod geometry;
+    pub mod intersection;
+    pub mod predicates;
+    pub mod polygon;
+    pub mod gjk;
+    pub mod bvh;
+    pub mod spatial;
+    pub mod raster;
+    pub mod clipping;
+    pub mod frustum;
+    pub mod dither;
+    pub mod colour;
+    pub mod resample;
+
+    #[cfg(feature = "serde")]
+        mod serialization;
+    #[cfg(feature = "bytemuck")]
+        mod pod;
+    #[cfg(any(feature = "mint", feature = "glam", feature = "nalgebra"))]
+        mod interop;
 
     pub use angle::Angle;
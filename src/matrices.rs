@@ -12,14 +12,43 @@
 // D E F I N I T I O N S
 //#######################
 
+    #[repr(C)]
     #[derive(Clone, Copy, PartialEq, Eq, Hash, Default, Debug)]
     pub struct Mat3<T: Number>(pub Vec3<T>, pub Vec3<T>, pub Vec3<T>);
 
 
+    #[repr(C)]
     #[derive(Clone, Copy, PartialEq, Eq, Hash, Default, Debug)]
     pub struct Mat4<T: Number>(pub Vec4<T>, pub Vec4<T>, pub Vec4<T>, pub Vec4<T>);
 
 
+    /// 2D affine transformation as the first two rows of a homogeneous `Mat3`, its columns are
+    /// the images of the x and y axes followed by the translation
+    #[repr(C)]
+    #[derive(Clone, Copy, PartialEq, Eq, Hash, Default, Debug)]
+    pub struct Affine2<T: Number>(pub Vec2<T>, pub Vec2<T>, pub Vec2<T>);
+
+
+    /// Range of the depth of visible points in clip space once divided by `w`,
+    /// `new_orthogonal_projection_mat` maps to `NegativeOneToOne`
+    #[derive(Clone, Copy, PartialEq, Eq, Hash, Default, Debug)]
+    pub enum DepthRange {
+        /// OpenGL convention
+        #[default]
+        NegativeOneToOne,
+        /// Vulkan, Direct3D and Metal convention
+        ZeroToOne,
+    } // enum ..
+
+
+    /// Applies an affine homogeneous matrix to points, which are translated,
+    /// and to directions, which are not, the last row is ignored
+    pub trait Transform<V> {
+        fn transform_point(&self, point: V) -> V;
+        fn transform_vector(&self, vector: V) -> V;
+    } // trait ..
+
+
 //###############################
 // I M P L E M E N T A T I O N S
 //###############################
@@ -112,6 +141,12 @@
     } // impl ..
 
 
+    impl<T: Number> Transform<Vec2<T>> for Mat3<T> {
+        fn transform_point(&self, point: Vec2<T>) -> Vec2<T> { (*self * point).xy() }
+        fn transform_vector(&self, vector: Vec2<T>) -> Vec2<T> { self.0.xy() * vector.0 + self.1.xy() * vector.1 }
+    } // impl ..
+
+
     impl<T: Number + Into<f32>> From<Mat3<T>> for [f32; 9usize] {
         fn from(mat4: Mat3<T>) -> Self {[
             mat4.0.0.into(), mat4.0.1.into(), mat4.0.2.into(),
@@ -206,6 +241,12 @@
     } // impl ..
 
 
+    impl<T: Number> Transform<Vec3<T>> for Mat4<T> {
+        fn transform_point(&self, point: Vec3<T>) -> Vec3<T> { (*self * point).xyz() }
+        fn transform_vector(&self, vector: Vec3<T>) -> Vec3<T> { self.0.xyz() * vector.0 + self.1.xyz() * vector.1 + self.2.xyz() * vector.2 }
+    } // impl ..
+
+
     impl<T: Number + Into<f32>> From<Mat4<T>> for [f32; 16usize] {
         fn from(mat4: Mat4<T>) -> Self {[
             mat4.0.0.into(), mat4.0.1.into(), mat4.0.2.into(), mat4.0.3.into(),
@@ -214,3 +255,60 @@
             mat4.3.0.into(), mat4.3.1.into(), mat4.3.2.into(), mat4.3.3.into(),
         ]} // fn ..
     } // impl ..
+
+
+    //#################
+    // A F F I N E 2
+    //#################
+
+    impl<T: Number> Affine2<T> {
+        pub const IDENTITY: Self = Affine2(Vec2(T::ONE, T::ZERO), Vec2(T::ZERO, T::ONE), Vec2(T::ZERO, T::ZERO));
+
+
+        /// `None` when the transformation collapses the plane onto a line or a point
+        pub fn inverse(&self) -> Option<Self> where T: Signed {
+            let Affine2(x, y, translation) = *self;
+            let determinant = x.0 * y.1 - x.1 * y.0;
+            if determinant == T::ZERO { return None }
+
+            let (x, y) = (Vec2(y.1, -x.1) / determinant, Vec2(-y.0, x.0) / determinant);
+            Some(Affine2(x, y, -(x * translation.0 + y * translation.1)))
+        } // fn ..
+    } // impl ..
+
+
+    impl<T: Number> Mul for Affine2<T> {
+        type Output = Self;
+        fn mul(self, other: Self) -> Self::Output {
+            Affine2(
+                self.0 * other.0.0 + self.1 * other.0.1,
+                self.0 * other.1.0 + self.1 * other.1.1,
+                self.0 * other.2.0 + self.1 * other.2.1 + self.2,
+            ) // Affine2()
+        } // fn ..
+    } // impl ..
+
+
+    impl<T: Number> Mul<Vec2<T>> for Affine2<T> {
+        type Output = Vec2<T>;
+        fn mul(self, other: Vec2<T>) -> Self::Output { self.0 * other.0 + self.1 * other.1 + self.2 }
+    } // impl ..
+
+
+    impl<T: Number> Transform<Vec2<T>> for Affine2<T> {
+        fn transform_point(&self, point: Vec2<T>) -> Vec2<T> { *self * point }
+        fn transform_vector(&self, vector: Vec2<T>) -> Vec2<T> { self.0 * vector.0 + self.1 * vector.1 }
+    } // impl ..
+
+
+    /// Drops the last row, which is ignored by `Transform` anyway, a projective matrix loses its perspective
+    impl<T: Number> From<Mat3<T>> for Affine2<T> {
+        fn from(mat3: Mat3<T>) -> Self { Affine2(mat3.0.xy(), mat3.1.xy(), mat3.2.xy()) }
+    } // impl ..
+
+
+    impl<T: Number> From<Affine2<T>> for Mat3<T> {
+        fn from(affine2: Affine2<T>) -> Self {
+            Mat3(Vec3(affine2.0.0, affine2.0.1, T::ZERO), Vec3(affine2.1.0, affine2.1.1, T::ZERO), Vec3(affine2.2.0, affine2.2.1, T::ONE))
+        } // fn ..
+    } // impl ..
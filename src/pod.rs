@@ -0,0 +1,39 @@
+//#########################
+// D E P E N D E N C I E S
+//#########################
+
+    use bytemuck::{Pod, Zeroable};
+
+    use crate::traits::Number;
+    use crate::vectors::{Vec2, Vec3, Vec4};
+    use crate::matrices::{Mat3, Mat4, Affine2};
+    use crate::Angle;
+
+
+//#############
+// M A C R O S
+//#############
+
+    /// # Safety
+    /// Every listed type is `#[repr(C)]` and only made of fields of a single
+    /// `Pod` type, so it can neither contain padding nor invalid bit patterns
+    macro_rules! impl_pod(
+        ($($S: ident),*$(,)*) => {$(
+
+            unsafe impl<T: Number + Zeroable> Zeroable for $S<T> {}
+            unsafe impl<T: Number + Pod> Pod for $S<T> {}
+
+        )*}
+    ); // impl_pod()
+
+
+//###############################
+// I M P L E M E N T A T I O N S
+//###############################
+
+    impl_pod!(Vec2, Vec3, Vec4, Mat3, Mat4, Affine2);
+
+
+    // `Angle` is `#[repr(transparent)]` over a `u8`
+    unsafe impl Zeroable for Angle {}
+    unsafe impl Pod for Angle {}
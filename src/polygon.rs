@@ -0,0 +1,353 @@
+//#########################
+// D E P E N D E N C I E S
+//#########################
+
+    use std::cmp::Ordering;
+
+    use crate::traits::{Number, Zero, Signed, AsF64};
+    use crate::vectors::Vec2;
+    use crate::predicates::{Predicates, Widen, Orientation, orient2d};
+
+
+//#######################
+// D E F I N I T I O N S
+//#######################
+
+    /// Which points count as inside a polygon whose edges cross or wind several times
+    #[derive(Clone, Copy, PartialEq, Eq, Hash, Default, Debug)]
+    pub enum FillRule {
+        #[default]
+        NonZero,
+        EvenOdd,
+    } // enum ..
+
+
+    //#############
+    // C O N V E X
+    //#############
+
+    // Convex polygons are slices of vertices in counter-clockwise order, as returned by
+    // `convex_hull`. Decisions go through the exact predicates, and new vertices created
+    // by clipping are computed in `f64` then rounded back to the scalar type, moving back
+    // along their edge when the rounding would leave them outside of the window.
+
+    /// Smallest convex polygon holding every point, counter-clockwise from the leftmost
+    /// point, without collinear vertices (Andrew's monotone chain)
+    pub fn convex_hull<T: Predicates>(points: &[Vec2<T>]) -> Vec<Vec2<T>> {
+        let mut sorted = points.to_vec();
+        sorted.sort_by(|a, b| (a.0, a.1).partial_cmp(&(b.0, b.1)).unwrap_or(Ordering::Equal));
+        sorted.dedup();
+        if sorted.len() < 3usize { return sorted }
+
+        let mut lower = half_hull(sorted.iter().copied());
+        let mut upper = half_hull(sorted.iter().rev().copied());
+        lower.pop();
+        upper.pop();
+        lower.append(&mut upper);
+        lower
+    } // fn ..
+
+
+    /// Whether two convex polygons share at least one point, touching included,
+    /// by looking for an edge separating them
+    pub fn convex_overlap<T: Predicates>(a: &[Vec2<T>], b: &[Vec2<T>]) -> bool {
+        !separated_by_edge(a, b) && !separated_by_edge(b, a)
+    } // fn ..
+
+
+    /// Shortest translation moving `b` out of `a`, `None` when they do not overlap
+    pub fn minimum_translation<T: Predicates + AsF64>(a: &[Vec2<T>], b: &[Vec2<T>]) -> Option<Vec2<f32>> {
+        if !convex_overlap(a, b) { return None }
+
+        let mut best: Option<(f64, (f64, f64))> = None;
+        for polygon in [a, b] {
+            for (start, end) in edges(polygon) {
+                let edge   = (end.0.as_f64() - start.0.as_f64(), end.1.as_f64() - start.1.as_f64());
+                let length = edge.0.hypot(edge.1);
+                if length == 0f64 { continue }
+
+                let axis               = (edge.1 / length, -edge.0 / length);
+                let (a_min, a_max)     = project(a, axis);
+                let (b_min, b_max)     = project(b, axis);
+                let (forward, back)    = (a_max - b_min, b_max - a_min);
+                let (depth, direction) = if forward < back { (forward, axis) } else { (back, (-axis.0, -axis.1)) };
+                if best.is_none_or(|(best_depth, _)| depth < best_depth) { best = Some((depth, direction)) }
+            } // for ..
+        } // for ..
+
+        Some(best.map_or(Vec2(0f32, 0f32), |(depth, direction)| Vec2((direction.0 * depth) as f32, (direction.1 * depth) as f32)))
+    } // fn ..
+
+
+    /// Sutherland–Hodgman clipping of any polygon against a convex window,
+    /// empty when nothing is left. Integer crossings are rounded to a point inside the
+    /// window, which may lie up to a unit away from the window edge
+    pub fn clip_polygon<T: Predicates + AsF64>(subject: &[Vec2<T>], window: &[Vec2<T>]) -> Vec<Vec2<T>> {
+        let mut output = subject.to_vec();
+
+        for (index, (a, b)) in edges(window).enumerate() {
+            let input = std::mem::take(&mut output);
+            let Some(&last) = input.last() else { break };
+
+            let inside    = |point: Vec2<T>| orient2d(a, b, point) != Orientation::Clockwise;
+            let clipped   = |point: Vec2<T>| edges(window).take(index + 1usize).all(|(a, b)| orient2d(a, b, point) != Orientation::Clockwise);
+            let mut start = last;
+            for &end in &input {
+                match (inside(start), inside(end)) {
+                    (true, true)   => output.push(end),
+                    (true, false)  => output.push(line_crossing(start, end, a, b, clipped)),
+                    (false, true)  => { output.push(line_crossing(end, start, a, b, clipped)); output.push(end); }
+                    (false, false) => (),
+                } // match ..
+                start = end;
+            } // for ..
+            output.dedup();
+        } // for ..
+
+        output
+    } // fn ..
+
+
+    /// Minkowski sum of two convex polygons, counter-clockwise from the lowest leftmost vertex
+    pub fn minkowski_sum<T: Signed + Predicates>(a: &[Vec2<T>], b: &[Vec2<T>]) -> Vec<Vec2<T>> {
+        if a.is_empty() || b.is_empty() { return Vec::new() }
+
+        let (a, b)         = (from_lowest(a), from_lowest(b));
+        let (n, m)         = (a.len(), b.len());
+        let (mut i, mut j) = (0usize, 0usize);
+        let mut sum        = Vec::with_capacity(n + m);
+
+        while i < n || j < m {
+            sum.push(a[i % n] + b[j % m]);
+            let turn     = orient2d(Vec2::ZERO, a[(i + 1usize) % n] - a[i % n], b[(j + 1usize) % m] - b[j % m]);
+            let (ai, bj) = (i, j);
+            if bj == m || (ai < n && turn != Orientation::Clockwise)        { i += 1usize }
+            if ai == n || (bj < m && turn != Orientation::CounterClockwise) { j += 1usize }
+        } // while ..
+
+        sum
+    } // fn ..
+
+
+    /// Keeps the points turning counter-clockwise, one half of the monotone chain
+    fn half_hull<T: Predicates>(points: impl Iterator<Item = Vec2<T>>) -> Vec<Vec2<T>> {
+        let mut hull: Vec<Vec2<T>> = Vec::new();
+        for point in points {
+            while hull.len() >= 2usize && orient2d(hull[hull.len() - 2usize], hull[hull.len() - 1usize], point) != Orientation::CounterClockwise { hull.pop(); }
+            hull.push(point);
+        } // for ..
+        hull
+    } // fn ..
+
+
+    fn edges<T: Number>(polygon: &[Vec2<T>]) -> impl Iterator<Item = (Vec2<T>, Vec2<T>)> + '_ {
+        polygon.iter().zip(polygon.iter().cycle().skip(1usize)).map(|(&start, &end)| (start, end))
+    } // fn ..
+
+
+    /// Whether every vertex of `other` lies strictly outside one of the edges of `polygon`
+    fn separated_by_edge<T: Predicates>(polygon: &[Vec2<T>], other: &[Vec2<T>]) -> bool {
+        edges(polygon).any(|(start, end)| other.iter().all(|&point| orient2d(start, end, point) == Orientation::Clockwise))
+    } // fn ..
+
+
+    fn project<T: AsF64>(polygon: &[Vec2<T>], axis: (f64, f64)) -> (f64, f64) {
+        polygon.iter().fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), point| {
+            let projection = point.0.as_f64() * axis.0 + point.1.as_f64() * axis.1;
+            (min.min(projection), max.max(projection))
+        }) // fold()
+    } // fn ..
+
+
+    /// Point where the segment from `inside` to `outside` crosses the line through `a` and `b`.
+    /// When rounding leaves it outside of the window `clipped` so far, bisects back towards
+    /// `inside`, which always lies in it
+    fn line_crossing<T: AsF64>(inside: Vec2<T>, outside: Vec2<T>, a: Vec2<T>, b: Vec2<T>, clipped: impl Fn(Vec2<T>) -> bool) -> Vec2<T> {
+        let (sx, sy) = (inside.0.as_f64(), inside.1.as_f64());
+        let (dx, dy) = (outside.0.as_f64() - sx, outside.1.as_f64() - sy);
+        let (lx, ly) = (b.0.as_f64() - a.0.as_f64(), b.1.as_f64() - a.1.as_f64());
+        let (ax, ay) = (a.0.as_f64() - sx, a.1.as_f64() - sy);
+        let at       = |t: f64| Vec2(T::from_f64(sx + dx * t), T::from_f64(sy + dy * t));
+
+        let (mut low, mut high) = (0f64, (lx * ay - ly * ax) / (lx * dy - ly * dx));
+        if clipped(at(high)) { return at(high) }
+        for _ in 0usize..64usize {
+            let middle = (low + high) / 2f64;
+            if clipped(at(middle)) { low = middle } else { high = middle }
+        } // for ..
+        at(low)
+    } // fn ..
+
+
+    /// Rotates the vertices so that the lowest leftmost one comes first
+    fn from_lowest<T: Predicates>(polygon: &[Vec2<T>]) -> Vec<Vec2<T>> {
+        let lowest = (0usize..polygon.len()).fold(0usize, |lowest, index| {
+            if (polygon[index].1, polygon[index].0) < (polygon[lowest].1, polygon[lowest].0) { index } else { lowest }
+        }); // let ..
+        polygon[lowest..].iter().chain(&polygon[..lowest]).copied().collect()
+    } // fn ..
+
+
+    //#############
+    // S I M P L E
+    //#############
+
+    // Simple polygons may wind either way and be concave, but their edges never cross.
+
+    /// Twice the signed area, positive for counter-clockwise polygons, in the wide type so
+    /// that it stays exact
+    pub fn twice_signed_area<T: Widen>(polygon: &[Vec2<T>]) -> T::Wide {
+        edges(polygon).fold(T::Wide::ZERO, |area, (a, b)| area + a.0.widen() * b.1.widen() - b.0.widen() * a.1.widen())
+    } // fn ..
+
+
+    /// Winding order of a polygon, `Collinear` when its area is zero
+    pub fn winding<T: Widen>(polygon: &[Vec2<T>]) -> Orientation {
+        let area = twice_signed_area(polygon);
+        if area > T::Wide::ZERO      { Orientation::CounterClockwise }
+        else if area < T::Wide::ZERO { Orientation::Clockwise }
+        else                         { Orientation::Collinear }
+    } // fn ..
+
+
+    /// Reverses the vertices when the polygon winds the other way, degenerate polygons are left as is
+    pub fn set_winding<T: Widen>(polygon: &mut [Vec2<T>], orientation: Orientation) {
+        if winding(polygon) == orientation.reversed() && orientation != Orientation::Collinear { polygon.reverse() }
+    } // fn ..
+
+
+    /// Centre of mass of the polygon area, `None` when the area is zero
+    pub fn centroid<T: AsF64>(polygon: &[Vec2<T>]) -> Option<Vec2<f32>> {
+        let origin = polygon.first()?;
+        let (ox, oy) = (origin.0.as_f64(), origin.1.as_f64());
+
+        let (area, x, y) = edges(polygon).fold((0f64, 0f64, 0f64), |(area, x, y), (a, b)| {
+            let (ax, ay) = (a.0.as_f64() - ox, a.1.as_f64() - oy);
+            let (bx, by) = (b.0.as_f64() - ox, b.1.as_f64() - oy);
+            let cross    = ax * by - bx * ay;
+            (area + cross, x + (ax + bx) * cross, y + (ay + by) * cross)
+        }); // let ..
+
+        if area == 0f64 { return None }
+        Some(Vec2((ox + x / (3f64 * area)) as f32, (oy + y / (3f64 * area)) as f32))
+    } // fn ..
+
+
+    /// Number of counter-clockwise turns the polygon makes around the point,
+    /// negative for clockwise ones
+    pub fn winding_number<T: Predicates>(polygon: &[Vec2<T>], point: Vec2<T>) -> i32 {
+        edges(polygon).fold(0i32, |winding, (a, b)| {
+            if a.1 <= point.1 && b.1 > point.1 && orient2d(a, b, point) == Orientation::CounterClockwise { winding + 1i32 }
+            else if a.1 > point.1 && b.1 <= point.1 && orient2d(a, b, point) == Orientation::Clockwise { winding - 1i32 }
+            else { winding }
+        }) // fold()
+    } // fn ..
+
+
+    /// Whether the point lies inside the polygon under the given fill rule, points on an edge included
+    pub fn contains_point<T: Predicates>(polygon: &[Vec2<T>], point: Vec2<T>, rule: FillRule) -> bool {
+        if edges(polygon).any(|(a, b)| on_segment(a, b, point)) { return true }
+
+        let winding = winding_number(polygon, point);
+        match rule {
+            FillRule::NonZero => winding != 0i32,
+            FillRule::EvenOdd => winding % 2i32 != 0i32,
+        } // match ..
+    } // fn ..
+
+
+    /// Ear clipping triangulation of a simple polygon, as counter-clockwise triples of indices into it,
+    /// `None` when the triangles cannot cover the polygon because its edges cross
+    pub fn triangulate<T: Widen>(polygon: &[Vec2<T>]) -> Option<Vec<[usize; 3]>> {
+        let mut remaining: Vec<usize> = (0usize..polygon.len()).collect();
+        if winding(polygon) == Orientation::Clockwise { remaining.reverse() }
+
+        let mut triangles = Vec::with_capacity(polygon.len().saturating_sub(2usize));
+        while remaining.len() > 3usize {
+            let n      = remaining.len();
+            let corner = |i: usize| (remaining[(i + n - 1usize) % n], remaining[i], remaining[(i + 1usize) % n]);
+
+            let ear = (0usize..n).find(|&i| {
+                let (a, b, c) = corner(i);
+                orient2d(polygon[a], polygon[b], polygon[c]) == Orientation::CounterClockwise
+                    && remaining.iter().all(|&j| j == a || j == b || j == c || !in_triangle(polygon[a], polygon[b], polygon[c], polygon[j]))
+            }); // let ..
+
+            // Without an ear the polygon is degenerate, flat corners are dropped without a triangle
+            let i = ear.or_else(|| (0usize..n).find(|&i| {
+                let (a, b, c) = corner(i);
+                orient2d(polygon[a], polygon[b], polygon[c]) == Orientation::Collinear
+            }))?;
+
+            let (a, b, c) = corner(i);
+            if orient2d(polygon[a], polygon[b], polygon[c]) == Orientation::CounterClockwise { triangles.push([a, b, c]) }
+            remaining.remove(i);
+        } // while ..
+
+        if let [a, b, c] = remaining[..] {
+            match orient2d(polygon[a], polygon[b], polygon[c]) {
+                Orientation::CounterClockwise => triangles.push([a, b, c]),
+                Orientation::Clockwise        => return None,
+                Orientation::Collinear        => (),
+            } // match ..
+        } // if ..
+
+        // Crossing edges cancel parts of the signed area that the triangles still cover
+        let covered = triangles.iter().fold(T::Wide::ZERO, |area, &[a, b, c]| area + twice_signed_area(&[polygon[a], polygon[b], polygon[c]]));
+        let area    = twice_signed_area(polygon);
+        (covered == area || covered == -area).then_some(triangles)
+    } // fn ..
+
+
+    /// Ramer–Douglas–Peucker simplification, keeps the endpoints and every point
+    /// further than `epsilon` from the simplified polyline
+    pub fn simplify<T: AsF64>(polyline: &[Vec2<T>], epsilon: f32) -> Vec<Vec2<T>> {
+        if polyline.len() < 3usize { return polyline.to_vec() }
+
+        let mut keep  = vec![false; polyline.len()];
+        let mut stack = vec![(0usize, polyline.len() - 1usize)];
+        keep[0usize]  = true;
+        keep[polyline.len() - 1usize] = true;
+
+        while let Some((first, last)) = stack.pop() {
+            let farthest = (first + 1usize..last)
+                .map(|i| (i, squared_distance_to_segment(polyline[first], polyline[last], polyline[i])))
+                .max_by(|a, b| a.1.total_cmp(&b.1));
+
+            if let Some((i, squared_distance)) = farthest {
+                if squared_distance > (epsilon as f64) * (epsilon as f64) {
+                    keep[i] = true;
+                    stack.push((first, i));
+                    stack.push((i, last));
+                } // if ..
+            } // if ..
+        } // while ..
+
+        polyline.iter().zip(keep).filter_map(|(&point, keep)| keep.then_some(point)).collect()
+    } // fn ..
+
+
+    fn on_segment<T: Predicates>(a: Vec2<T>, b: Vec2<T>, point: Vec2<T>) -> bool {
+        orient2d(a, b, point) == Orientation::Collinear
+            && (a.0 <= point.0 || b.0 <= point.0) && (a.0 >= point.0 || b.0 >= point.0)
+            && (a.1 <= point.1 || b.1 <= point.1) && (a.1 >= point.1 || b.1 >= point.1)
+    } // fn ..
+
+
+    /// Whether the point lies in the counter-clockwise triangle, edges included
+    fn in_triangle<T: Predicates>(a: Vec2<T>, b: Vec2<T>, c: Vec2<T>, point: Vec2<T>) -> bool {
+        orient2d(a, b, point) != Orientation::Clockwise
+            && orient2d(b, c, point) != Orientation::Clockwise
+            && orient2d(c, a, point) != Orientation::Clockwise
+    } // fn ..
+
+
+    fn squared_distance_to_segment<T: AsF64>(a: Vec2<T>, b: Vec2<T>, point: Vec2<T>) -> f64 {
+        let (ax, ay)  = (a.0.as_f64(), a.1.as_f64());
+        let (dx, dy)  = (b.0.as_f64() - ax, b.1.as_f64() - ay);
+        let (px, py)  = (point.0.as_f64() - ax, point.1.as_f64() - ay);
+        let length    = dx * dx + dy * dy;
+        let t         = if length == 0f64 { 0f64 } else { ((px * dx + py * dy) / length).clamp(0f64, 1f64) };
+        let (ex, ey)  = (px - dx * t, py - dy * t);
+        ex * ex + ey * ey
+    } // fn ..
@@ -0,0 +1,421 @@
+//#########################
+// D E P E N D E N C I E S
+//#########################
+
+    use std::cmp::Ordering;
+
+    use crate::traits::{Number, Zero, One, Signed, Integer, Vector};
+    use crate::vectors::{Vec2, Vec3};
+    use crate::geometry::Segment;
+
+
+//#############
+// M A C R O S
+//#############
+
+    macro_rules! impl_integer_predicates(
+        ($($T: ty => $W: ty),*$(,)*) => {$(
+
+            impl Widen for $T {
+                type Wide = $W;
+                fn widen(self) -> $W { self as $W }
+            } // impl Widen ..
+
+
+            impl Predicates for $T {
+                fn orient2d(a: Vec2<Self>, b: Vec2<Self>, c: Vec2<Self>) -> Orientation {
+                    let (a, b, c) = (widen2(a), widen2(b), widen2(c));
+                    Orientation::from_sign(sign(Vec2::cross(b - a, c - a)))
+                } // fn orient2d()
+
+                fn orient3d(a: Vec3<Self>, b: Vec3<Self>, c: Vec3<Self>, d: Vec3<Self>) -> Orientation {
+                    let (a, b, c, d) = (widen3(a), widen3(b), widen3(c), widen3(d));
+                    Orientation::from_sign(sign(Vec3::dot(Vec3::cross(b - a, c - a), d - a)))
+                } // fn orient3d()
+
+                fn incircle(a: Vec2<Self>, b: Vec2<Self>, c: Vec2<Self>, d: Vec2<Self>) -> CirclePosition {
+                    let offset       = |p: Vec2<Self>| (p.0 as i128 - d.0 as i128, p.1 as i128 - d.1 as i128);
+                    let (ax, ay)     = offset(a);
+                    let (bx, by)     = offset(b);
+                    let (cx, cy)     = offset(c);
+                    let determinant  = Int256::product(ax * ax + ay * ay, bx * cy - cx * by)
+                        .add(Int256::product(bx * bx + by * by, cx * ay - ax * cy))
+                        .add(Int256::product(cx * cx + cy * cy, ax * by - bx * ay));
+                    CirclePosition::from_sign(determinant.sign(), Self::orient2d(a, b, c))
+                } // fn incircle()
+            } // impl Predicates ..
+
+        )*}
+    ); // impl_integer_predicates()
+
+
+//#######################
+// D E F I N I T I O N S
+//#######################
+
+    #[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+    pub enum Orientation {
+        Clockwise,
+        Collinear,
+        CounterClockwise,
+    } // enum ..
+
+
+    /// Position of a point relative to the circle through three others
+    #[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+    pub enum CirclePosition {
+        Inside,
+        Cocircular,
+        Outside,
+    } // enum ..
+
+
+    /// Scalar whose geometric predicates never misclassify: integers are widened
+    /// so that no product overflows, and floats fall back to exact arithmetic
+    /// whenever the rounding error could flip the sign of the result
+    pub trait Predicates: Number {
+        fn orient2d(a: Vec2<Self>, b: Vec2<Self>, c: Vec2<Self>) -> Orientation;
+        fn orient3d(a: Vec3<Self>, b: Vec3<Self>, c: Vec3<Self>, d: Vec3<Self>) -> Orientation;
+        fn incircle(a: Vec2<Self>, b: Vec2<Self>, c: Vec2<Self>, d: Vec2<Self>) -> CirclePosition;
+    } // trait ..
+
+
+    /// Integer that fits in a type wide enough to hold products of coordinate differences
+    pub trait Widen: Integer + Predicates {
+        type Wide: Signed + Integer;
+        fn widen(self) -> Self::Wide;
+    } // trait ..
+
+
+    /// Shared part of two intersecting segments
+    #[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+    pub enum SegmentIntersection<T: Widen> {
+        /// Point at `numerator / denominator`, the denominator is positive
+        Point { numerator: Vec2<T::Wide>, denominator: T::Wide },
+        /// Collinear segments overlapping along a segment of non-zero length
+        Overlap(Segment<Vec2<T>>),
+    } // enum ..
+
+
+    /// Signed 256-bit integer, two's complement on little-endian limbs
+    #[derive(Clone, Copy)]
+    struct Int256([u64; 4usize]);
+
+
+    /// Whether `c` lies on the left of the line going from `a` to `b`
+    pub fn orient2d<T: Predicates>(a: Vec2<T>, b: Vec2<T>, c: Vec2<T>) -> Orientation { T::orient2d(a, b, c) }
+
+
+    /// Whether `a`, `b` and `c` turn counter-clockwise when seen from `d`,
+    /// which is the case when `d` lies on the side `cross(b - a, c - a)` points to
+    pub fn orient3d<T: Predicates>(a: Vec3<T>, b: Vec3<T>, c: Vec3<T>, d: Vec3<T>) -> Orientation { T::orient3d(a, b, c, d) }
+
+
+    /// Position of `d` relative to the circle through `a`, `b` and `c` whatever their winding,
+    /// when they are collinear only points of their line count as cocircular
+    pub fn incircle<T: Predicates>(a: Vec2<T>, b: Vec2<T>, c: Vec2<T>, d: Vec2<T>) -> CirclePosition { T::incircle(a, b, c, d) }
+
+
+    /// Whether two segments share at least one point, endpoints included
+    pub fn segments_intersect<T: Predicates>(a: &Segment<Vec2<T>>, b: &Segment<Vec2<T>>) -> bool {
+        let (a0, a1) = (orient2d(a.start, a.end, b.start), orient2d(a.start, a.end, b.end));
+        let (b0, b1) = (orient2d(b.start, b.end, a.start), orient2d(b.start, b.end, a.end));
+
+        if a0 != a1 && b0 != b1 { return true }
+
+        (a0 == Orientation::Collinear && within(a, b.start))
+            || (a1 == Orientation::Collinear && within(a, b.end))
+            || (b0 == Orientation::Collinear && within(b, a.start))
+            || (b1 == Orientation::Collinear && within(b, a.end))
+    } // fn ..
+
+
+    /// Exact intersection of two integer segments, `None` when they are disjoint
+    pub fn segment_intersection<T: Widen>(a: &Segment<Vec2<T>>, b: &Segment<Vec2<T>>) -> Option<SegmentIntersection<T>> {
+        if !segments_intersect(a, b) { return None }
+
+        let (p, r)      = (widen2(a.start), widen2(a.end) - widen2(a.start));
+        let (q, s)      = (widen2(b.start), widen2(b.end) - widen2(b.start));
+        let denominator = Vec2::cross(r, s);
+
+        if denominator != T::Wide::ZERO {
+            let time  = Vec2::cross(q - p, s);
+            let point = p * denominator + r * time;
+            return Some(if denominator < T::Wide::ZERO { SegmentIntersection::Point { numerator: -point, denominator: -denominator } }
+                        else                           { SegmentIntersection::Point { numerator: point, denominator } })
+        } // if ..
+
+        let ordered = |segment: &Segment<Vec2<T>>| if lexicographic(segment.end, segment.start) { (segment.end, segment.start) } else { (segment.start, segment.end) };
+        let (a0, a1) = ordered(a);
+        let (b0, b1) = ordered(b);
+        let start    = if lexicographic(a0, b0) { b0 } else { a0 };
+        let end      = if lexicographic(a1, b1) { a1 } else { b1 };
+
+        Some(if start == end { SegmentIntersection::Point { numerator: widen2(start), denominator: T::Wide::ONE } }
+             else            { SegmentIntersection::Overlap(Segment { start, end }) })
+    } // fn ..
+
+
+    fn sign<T: Number>(value: T) -> Ordering { value.partial_cmp(&T::ZERO).unwrap_or(Ordering::Equal) }
+    fn widen2<T: Widen>(vector: Vec2<T>) -> Vec2<T::Wide> { Vec2(vector.0.widen(), vector.1.widen()) }
+    fn widen3<T: Widen>(vector: Vec3<T>) -> Vec3<T::Wide> { Vec3(vector.0.widen(), vector.1.widen(), vector.2.widen()) }
+    fn lexicographic<T: Number>(a: Vec2<T>, b: Vec2<T>) -> bool { a.0 < b.0 || (a.0 == b.0 && a.1 < b.1) }
+
+
+    /// Whether a point known to be collinear with the segment lies between its endpoints
+    fn within<T: Number>(segment: &Segment<Vec2<T>>, point: Vec2<T>) -> bool {
+        let between = |value: T, a: T, b: T| (a <= value && value <= b) || (b <= value && value <= a);
+        between(point.0, segment.start.0, segment.end.0) && between(point.1, segment.start.1, segment.end.1)
+    } // fn ..
+
+
+    //###############
+    // F L O A T S
+    //###############
+
+    // Floats are checked against the error bounds of Shewchuk's "Adaptive Precision
+    // Floating-Point Arithmetic and Fast Robust Geometric Predicates". Results too close
+    // to zero are recomputed exactly as expansions, sums of non-overlapping doubles sorted
+    // by increasing magnitude. `f32` inputs cannot underflow nor overflow in `f64`.
+
+    const HALF_EPSILON:   f64 = f64::EPSILON / 2f64;
+    const ORIENT2D_BOUND: f64 = (3f64 + 16f64 * HALF_EPSILON) * HALF_EPSILON;
+    const ORIENT3D_BOUND: f64 = (7f64 + 56f64 * HALF_EPSILON) * HALF_EPSILON;
+    const INCIRCLE_BOUND: f64 = (10f64 + 96f64 * HALF_EPSILON) * HALF_EPSILON;
+
+
+    fn two_sum(a: f64, b: f64) -> (f64, f64) {
+        let sum       = a + b;
+        let virtual_b = sum - a;
+        (sum, (a - (sum - virtual_b)) + (b - virtual_b))
+    } // fn ..
+
+
+    /// Exact `a - b` as an expansion
+    fn difference(a: f32, b: f32) -> Vec<f64> {
+        let (sum, error) = two_sum(a as f64, -(b as f64));
+        compress(vec![error, sum])
+    } // fn ..
+
+
+    fn compress(expansion: Vec<f64>) -> Vec<f64> {
+        expansion.into_iter().filter(|&component| component != 0f64).collect()
+    } // fn ..
+
+
+    /// Adds a double to an expansion, Shewchuk's Grow-Expansion
+    fn grow(expansion: &[f64], value: f64) -> Vec<f64> {
+        let mut sum    = value;
+        let mut result = Vec::with_capacity(expansion.len() + 1usize);
+        for &component in expansion {
+            let (total, error) = two_sum(sum, component);
+            if error != 0f64 { result.push(error) }
+            sum = total;
+        } // for ..
+        if sum != 0f64 { result.push(sum) }
+        result
+    } // fn ..
+
+
+    fn sum(a: &[f64], b: &[f64]) -> Vec<f64> {
+        b.iter().fold(a.to_vec(), |total, &component| grow(&total, component))
+    } // fn ..
+
+
+    fn product(a: &[f64], b: &[f64]) -> Vec<f64> {
+        let mut result = Vec::new();
+        for &x in a {
+            for &y in b {
+                let product = x * y;
+                result = grow(&grow(&result, x.mul_add(y, -product)), product);
+            } // for ..
+        } // for ..
+        result
+    } // fn ..
+
+
+    fn negated(a: &[f64]) -> Vec<f64> { a.iter().map(|&component| -component).collect() }
+
+    /// The largest component sits last and carries the sign of the whole expansion
+    fn expansion_sign(a: &[f64]) -> Ordering { a.last().map_or(Ordering::Equal, |&component| sign_f64(component)) }
+
+    fn sign_f64(value: f64) -> Ordering { value.partial_cmp(&0f64).unwrap_or(Ordering::Equal) }
+
+
+    /// Exact `a * b - c * d`
+    fn cross_difference(a: &[f64], b: &[f64], c: &[f64], d: &[f64]) -> Vec<f64> {
+        sum(&product(a, b), &negated(&product(c, d)))
+    } // fn ..
+
+
+    fn orient2d_f32(a: Vec2<f32>, b: Vec2<f32>, c: Vec2<f32>) -> Ordering {
+        let left        = (a.0 as f64 - c.0 as f64) * (b.1 as f64 - c.1 as f64);
+        let right       = (a.1 as f64 - c.1 as f64) * (b.0 as f64 - c.0 as f64);
+        let determinant = left - right;
+        if determinant.abs() > ORIENT2D_BOUND * (left.abs() + right.abs()) { return sign_f64(determinant) }
+
+        expansion_sign(&cross_difference(&difference(a.0, c.0), &difference(b.1, c.1), &difference(a.1, c.1), &difference(b.0, c.0)))
+    } // fn ..
+
+
+    /// Sign of the determinant of `a - d`, `b - d` and `c - d`
+    fn orient3d_f32(a: Vec3<f32>, b: Vec3<f32>, c: Vec3<f32>, d: Vec3<f32>) -> Ordering {
+        let offset = |p: Vec3<f32>| (p.0 as f64 - d.0 as f64, p.1 as f64 - d.1 as f64, p.2 as f64 - d.2 as f64);
+        let (ax, ay, az) = offset(a);
+        let (bx, by, bz) = offset(b);
+        let (cx, cy, cz) = offset(c);
+
+        let (bxcy, cxby) = (bx * cy, cx * by);
+        let (cxay, axcy) = (cx * ay, ax * cy);
+        let (axby, bxay) = (ax * by, bx * ay);
+        let determinant  = az * (bxcy - cxby) + bz * (cxay - axcy) + cz * (axby - bxay);
+        let permanent    = (bxcy.abs() + cxby.abs()) * az.abs() + (cxay.abs() + axcy.abs()) * bz.abs() + (axby.abs() + bxay.abs()) * cz.abs();
+        if determinant.abs() > ORIENT3D_BOUND * permanent { return sign_f64(determinant) }
+
+        let offset = |p: Vec3<f32>| (difference(p.0, d.0), difference(p.1, d.1), difference(p.2, d.2));
+        let (ax, ay, az) = offset(a);
+        let (bx, by, bz) = offset(b);
+        let (cx, cy, cz) = offset(c);
+        let determinant  = sum(&sum(
+            &product(&az, &cross_difference(&bx, &cy, &cx, &by)),
+            &product(&bz, &cross_difference(&cx, &ay, &ax, &cy))),
+            &product(&cz, &cross_difference(&ax, &by, &bx, &ay)),
+        ); // let ..
+        expansion_sign(&determinant)
+    } // fn ..
+
+
+    /// Positive when `d` is inside the circle through counter-clockwise `a`, `b` and `c`
+    fn incircle_f32(a: Vec2<f32>, b: Vec2<f32>, c: Vec2<f32>, d: Vec2<f32>) -> Ordering {
+        let offset   = |p: Vec2<f32>| (p.0 as f64 - d.0 as f64, p.1 as f64 - d.1 as f64);
+        let (ax, ay) = offset(a);
+        let (bx, by) = offset(b);
+        let (cx, cy) = offset(c);
+
+        let (bxcy, cxby)          = (bx * cy, cx * by);
+        let (cxay, axcy)          = (cx * ay, ax * cy);
+        let (axby, bxay)          = (ax * by, bx * ay);
+        let (alift, blift, clift) = (ax * ax + ay * ay, bx * bx + by * by, cx * cx + cy * cy);
+        let determinant           = alift * (bxcy - cxby) + blift * (cxay - axcy) + clift * (axby - bxay);
+        let permanent             = (bxcy.abs() + cxby.abs()) * alift + (cxay.abs() + axcy.abs()) * blift + (axby.abs() + bxay.abs()) * clift;
+        if determinant.abs() > INCIRCLE_BOUND * permanent { return sign_f64(determinant) }
+
+        let offset   = |p: Vec2<f32>| (difference(p.0, d.0), difference(p.1, d.1));
+        let (ax, ay) = offset(a);
+        let (bx, by) = offset(b);
+        let (cx, cy) = offset(c);
+        let lift     = |x: &[f64], y: &[f64]| sum(&product(x, x), &product(y, y));
+
+        let determinant = sum(&sum(
+            &product(&lift(&ax, &ay), &cross_difference(&bx, &cy, &cx, &by)),
+            &product(&lift(&bx, &by), &cross_difference(&cx, &ay, &ax, &cy))),
+            &product(&lift(&cx, &cy), &cross_difference(&ax, &by, &bx, &ay)),
+        ); // let ..
+        expansion_sign(&determinant)
+    } // fn ..
+
+
+//###############################
+// I M P L E M E N T A T I O N S
+//###############################
+
+    impl_integer_predicates!(u8 => i64, u16 => i64, u32 => i128, i8 => i64, i16 => i64, i32 => i128);
+
+
+    impl Predicates for f32 {
+        fn orient2d(a: Vec2<Self>, b: Vec2<Self>, c: Vec2<Self>) -> Orientation {
+            Orientation::from_sign(orient2d_f32(a, b, c))
+        } // fn ..
+
+        fn orient3d(a: Vec3<Self>, b: Vec3<Self>, c: Vec3<Self>, d: Vec3<Self>) -> Orientation {
+            Orientation::from_sign(orient3d_f32(a, b, c, d).reverse())
+        } // fn ..
+
+        fn incircle(a: Vec2<Self>, b: Vec2<Self>, c: Vec2<Self>, d: Vec2<Self>) -> CirclePosition {
+            CirclePosition::from_sign(incircle_f32(a, b, c, d), Self::orient2d(a, b, c))
+        } // fn ..
+    } // impl ..
+
+
+    impl Orientation {
+        fn from_sign(sign: Ordering) -> Self {
+            match sign {
+                Ordering::Less    => Orientation::Clockwise,
+                Ordering::Equal   => Orientation::Collinear,
+                Ordering::Greater => Orientation::CounterClockwise,
+            } // match ..
+        } // fn ..
+
+
+        pub fn reversed(self) -> Self {
+            match self {
+                Orientation::Clockwise        => Orientation::CounterClockwise,
+                Orientation::Collinear        => Orientation::Collinear,
+                Orientation::CounterClockwise => Orientation::Clockwise,
+            } // match ..
+        } // fn ..
+    } // impl ..
+
+
+    impl CirclePosition {
+        /// Interprets the sign of the in-circle determinant, which is positive inside
+        /// when the three points turn counter-clockwise
+        fn from_sign(sign: Ordering, orientation: Orientation) -> Self {
+            let sign = match orientation {
+                Orientation::CounterClockwise => sign,
+                Orientation::Clockwise        => sign.reverse(),
+                Orientation::Collinear        => if sign == Ordering::Equal { sign } else { Ordering::Less },
+            }; // let ..
+            match sign {
+                Ordering::Less    => CirclePosition::Outside,
+                Ordering::Equal   => CirclePosition::Cocircular,
+                Ordering::Greater => CirclePosition::Inside,
+            } // match ..
+        } // fn ..
+    } // impl ..
+
+
+    impl Int256 {
+        fn product(a: i128, b: i128) -> Self {
+            let (x, y)    = (a.unsigned_abs(), b.unsigned_abs());
+            let mut limbs = [0u64; 4usize];
+            Int256::add_at(&mut limbs, 0usize, (x as u64 as u128) * (y as u64 as u128));
+            Int256::add_at(&mut limbs, 1usize, (x as u64 as u128) * (y >> 64u8));
+            Int256::add_at(&mut limbs, 1usize, (x >> 64u8) * (y as u64 as u128));
+            Int256::add_at(&mut limbs, 2usize, (x >> 64u8) * (y >> 64u8));
+            if (a < 0i128) != (b < 0i128) { Int256(limbs).negated() } else { Int256(limbs) }
+        } // fn ..
+
+
+        /// Adds a 128-bit value starting at the limb `index`, carries past the last limb are dropped
+        fn add_at(limbs: &mut [u64; 4usize], index: usize, value: u128) {
+            let mut carry = value;
+            for limb in limbs.iter_mut().skip(index) {
+                let sum = *limb as u128 + (carry as u64 as u128);
+                *limb   = sum as u64;
+                carry   = (carry >> 64u8) + (sum >> 64u8);
+                if carry == 0u128 { break }
+            } // for ..
+        } // fn ..
+
+
+        fn add(self, other: Self) -> Self {
+            let mut limbs = self.0;
+            for (index, &limb) in other.0.iter().enumerate() { Int256::add_at(&mut limbs, index, limb as u128) }
+            Int256(limbs)
+        } // fn ..
+
+
+        fn negated(self) -> Self {
+            let mut limbs = self.0.map(|limb| !limb);
+            Int256::add_at(&mut limbs, 0usize, 1u128);
+            Int256(limbs)
+        } // fn ..
+
+
+        fn sign(self) -> Ordering {
+            if self.0 == [0u64; 4usize]            { Ordering::Equal }
+            else if self.0[3usize] >> 63u8 == 1u64 { Ordering::Less }
+            else                                   { Ordering::Greater }
+        } // fn ..
+    } // impl ..
@@ -0,0 +1,615 @@
+//#########################
+// D E P E N D E N C I E S
+//#########################
+
+    use crate::traits::{Signed, Integer, Vector};
+    use crate::vectors::{Vec2, Vec4};
+
+
+//#############
+// M A C R O S
+//#############
+
+    macro_rules! impl_coordinate(
+        ($($T: ty),*$(,)*) => {$(
+
+            impl Coordinate for $T {
+                const MIN: i64 = <$T>::MIN as i64;
+                const MAX: i64 = <$T>::MAX as i64;
+                fn to_i64(self) -> i64 { self as i64 }
+                fn from_i64(value: i64) -> Option<Self> { <$T>::try_from(value).ok() }
+            } // impl Coordinate ..
+
+        )*}
+    ); // impl_coordinate()
+
+
+//#######################
+// D E F I N I T I O N S
+//#######################
+
+    // Pixels are integer positions, the pixel `Vec2(x, y)` covering the unit square centered on
+    // it. Error terms are kept in wider integers so that no coordinate range overflows them, and
+    // pixels falling outside of the coordinate type are skipped. Iterators never allocate.
+
+    /// Signed integer pixel coordinate
+    pub trait Coordinate: Signed + Integer {
+        const MIN: i64;
+        const MAX: i64;
+        fn to_i64(self) -> i64;
+        fn from_i64(value: i64) -> Option<Self>;
+    } // trait ..
+
+
+    /// Inclusive horizontal run of pixels
+    #[derive(Clone, Copy, PartialEq, Eq, Hash, Default, Debug)]
+    pub struct Span<T> {
+        pub y:     T,
+        pub start: T,
+        pub end:   T,
+    } // struct ..
+
+
+    /// Pixels of a Bresenham line, both ends included
+    #[derive(Clone, Debug)]
+    pub struct LinePixels<T: Coordinate> {
+        position: Vec2<i64>,
+        end:      Vec2<i64>,
+        delta:    Vec2<i64>,
+        step:     Vec2<i64>,
+        error:    i64,
+        done:     bool,
+        marker:   std::marker::PhantomData<T>,
+    } // struct ..
+
+
+    /// Pixels of a line `width` pixels thick across its direction, drawn as runs along the
+    /// minor axis centered on a Bresenham line, so that the ends are cut along that axis
+    #[derive(Clone, Debug)]
+    pub struct ThickLinePixels<T: Coordinate> {
+        line:   LinePixels<T>,
+        across: Vec2<i64>,
+        length: i64,
+        center: Option<Vec2<T>>,
+        offset: i64,
+    } // struct ..
+
+
+    /// Pixels of a midpoint ellipse outline, circles being ellipses with equal radii
+    #[derive(Clone, Debug)]
+    pub struct EllipsePixels<T: Coordinate> {
+        center:   Vec2<i64>,
+        arc:      QuarterArc,
+        point:    Option<(i64, i64)>,
+        quadrant: u8,
+        marker:   std::marker::PhantomData<T>,
+    } // struct ..
+
+
+    /// Rows of a filled midpoint ellipse, matching its outline
+    #[derive(Clone, Debug)]
+    pub struct EllipseSpans<T: Coordinate> {
+        center:  Vec2<i64>,
+        arc:     QuarterArc,
+        row:     Option<(i64, i64)>,
+        pending: Option<Span<T>>,
+    } // struct ..
+
+
+    /// Pixels of a Xiaolin Wu antialiased line along with their coverage, both ends fully covered
+    #[derive(Clone, Debug)]
+    pub struct WuLinePixels<T: Coordinate> {
+        position: Vec2<i64>,
+        end:      Vec2<i64>,
+        /// Unit step along the major axis, then along the minor one
+        major:    Vec2<i64>,
+        minor:    Vec2<i64>,
+        steps:    i64,
+        /// Fraction of a pixel the line drifts along the minor axis per step, out of `1 << 16`
+        slope:    u32,
+        error:    u32,
+        pending:  Option<(Vec2<i64>, u8)>,
+        started:  bool,
+        marker:   std::marker::PhantomData<T>,
+    } // struct ..
+
+
+    /// Points of the quarter of an ellipse centered on the origin with `x, y >= 0`, going from
+    /// `(0, b)` to `(a, 0)` with `y` never increasing
+    #[derive(Clone, Debug)]
+    struct QuarterArc {
+        radii:    (i128, i128),
+        position: (i128, i128),
+        /// Decision variable scaled by four, and the derivatives `2 * b² * x` and `2 * a² * y`
+        decision: i128,
+        slope:    (i128, i128),
+        region:   Region,
+    } // struct ..
+
+
+    #[derive(Clone, Copy, PartialEq, Eq, Debug)]
+    enum Region { Start, Flat, Steep, Done }
+
+
+    /// Number of fractional bits in the subpixel coordinates of triangle vertices
+    pub const SUBPIXEL_BITS: u32 = 4u32;
+
+    /// Subpixel coordinates of triangle vertices stay strictly within this bound so that edge
+    /// functions and areas fit in `i64`
+    const GUARD_BAND: i64 = 1i64 << 30u32;
+
+
+    /// How attributes vary across a triangle
+    #[derive(Clone, Copy, PartialEq, Eq, Hash, Default, Debug)]
+    pub enum Interpolation {
+        /// Linear in screen space, cheaper and right for flat triangles facing the screen
+        Affine,
+        /// Linear in space before the perspective divide
+        #[default]
+        Perspective,
+    } // enum ..
+
+
+    /// Triangle ready to be rasterized, in subpixel coordinates with `SUBPIXEL_BITS` fractional
+    /// bits and `y` going down. Pixels whose center lies inside are covered, and centers on an
+    /// edge only for top and left edges so that triangles sharing an edge never cover a pixel twice
+    #[derive(Clone, Copy, PartialEq, Debug)]
+    pub struct TriangleSetup {
+        vertices:      [Vec2<i64>; 3],
+        /// Edge opposite each vertex, as the change of its edge function along `x` and `y`
+        steps:         [Vec2<i64>; 3],
+        /// Subtracted from edge functions so that only top and left edges keep their centers
+        biases:        [i64; 3],
+        /// Twice the area in squared subpixels, positive once the vertices are reordered
+        area:          i64,
+        /// Whether the last two vertices were swapped to make the area positive
+        swapped:       bool,
+        /// Inclusive pixel bounds
+        min:           Vec2<i64>,
+        max:           Vec2<i64>,
+        depths:        [f32; 3],
+        inverse_w:     [f32; 3],
+        interpolation: Interpolation,
+    } // struct ..
+
+
+    /// Pixel covered by a triangle
+    #[derive(Clone, Copy, PartialEq, Default, Debug)]
+    pub struct Fragment {
+        pub pixel:   Vec2<i32>,
+        /// Weights of the vertices in the order they were given, summing to one
+        pub weights: [f32; 3],
+        /// Normalized device depth, always interpolated in screen space
+        pub depth:   f32,
+    } // struct ..
+
+
+    /// Covered pixels of a triangle, row by row from the top
+    #[derive(Clone, Debug)]
+    pub struct TrianglePixels<'a> {
+        setup: &'a TriangleSetup,
+        pixel: Vec2<i64>,
+        /// Edge functions at the center of the current pixel and at the start of its row
+        edges: [i64; 3],
+        row:   [i64; 3],
+    } // struct ..
+
+
+//###############################
+// I M P L E M E N T A T I O N S
+//###############################
+
+    impl_coordinate!(i8, i16, i32);
+
+
+    /// Pixels of the line from `start` to `end`
+    pub fn line<T: Coordinate>(start: Vec2<T>, end: Vec2<T>) -> LinePixels<T> {
+        let (start, end) = (widen(start), widen(end));
+        let delta = Vec2((end.0 - start.0).abs(), -(end.1 - start.1).abs());
+        LinePixels {
+            position: start,
+            end,
+            delta,
+            step:     Vec2((end.0 - start.0).signum(), (end.1 - start.1).signum()),
+            error:    delta.0 + delta.1,
+            done:     false,
+            marker:   std::marker::PhantomData,
+        } // LinePixels
+    } // fn ..
+
+
+    /// Pixels of the line from `start` to `end`, `width` pixels thick
+    pub fn thick_line<T: Coordinate>(start: Vec2<T>, end: Vec2<T>, width: u32) -> ThickLinePixels<T> {
+        let line     = line(start, end);
+        let (dx, dy) = (line.delta.0 as f64, -line.delta.1 as f64);
+
+        // Runs along the minor axis get longer as the line leans, to keep the width across it
+        let (across, major) = if dx >= dy { (Vec2(0i64, 1i64), dx) } else { (Vec2(1i64, 0i64), dy) };
+        let length = if major == 0f64 { width as i64 } else { (width as f64 * dx.hypot(dy) / major).round() as i64 };
+        ThickLinePixels { line, across, length, center: None, offset: 0i64 }
+    } // fn ..
+
+
+    /// Pixels of the circle outline, each once
+    pub fn circle<T: Coordinate>(center: Vec2<T>, radius: T) -> EllipsePixels<T> { ellipse(center, radius, radius) }
+
+
+    /// Pixels of the ellipse outline with the given horizontal and vertical radii, each once
+    pub fn ellipse<T: Coordinate>(center: Vec2<T>, radius_x: T, radius_y: T) -> EllipsePixels<T> {
+        EllipsePixels {
+            center:   widen(center),
+            arc:      QuarterArc::new(radius_x.to_i64(), radius_y.to_i64()),
+            point:    None,
+            quadrant: 4u8,
+            marker:   std::marker::PhantomData,
+        } // EllipsePixels
+    } // fn ..
+
+
+    /// Rows of the filled circle, from the bottom and top edges towards the middle
+    pub fn filled_circle<T: Coordinate>(center: Vec2<T>, radius: T) -> EllipseSpans<T> { filled_ellipse(center, radius, radius) }
+
+
+    /// Rows of the filled ellipse, from the bottom and top edges towards the middle
+    pub fn filled_ellipse<T: Coordinate>(center: Vec2<T>, radius_x: T, radius_y: T) -> EllipseSpans<T> {
+        EllipseSpans { center: widen(center), arc: QuarterArc::new(radius_x.to_i64(), radius_y.to_i64()), row: None, pending: None }
+    } // fn ..
+
+
+    /// Antialiased pixels of the line from `start` to `end` with their coverage
+    pub fn wu_line<T: Coordinate>(start: Vec2<T>, end: Vec2<T>) -> WuLinePixels<T> {
+        let (start, end) = (widen(start), widen(end));
+        let (dx, dy) = (end.0 - start.0, end.1 - start.1);
+        let (major, minor, steps, drift) = match dx.abs() >= dy.abs() {
+            true  => (Vec2(dx.signum(), 0i64), Vec2(0i64, dy.signum()), dx.abs(), dy.abs()),
+            false => (Vec2(0i64, dy.signum()), Vec2(dx.signum(), 0i64), dy.abs(), dx.abs()),
+        }; // let ..
+
+        WuLinePixels {
+            position: start,
+            end,
+            major,
+            minor,
+            steps,
+            slope:    if steps == 0i64 { 0u32 } else { ((drift << 16u32) / steps) as u32 },
+            error:    0u32,
+            pending:  None,
+            started:  false,
+            marker:   std::marker::PhantomData,
+        } // WuLinePixels
+    } // fn ..
+
+
+    impl<T: Coordinate> Iterator for LinePixels<T> {
+        type Item = Vec2<T>;
+
+        fn next(&mut self) -> Option<Vec2<T>> {
+            if self.done { return None }
+
+            let pixel = self.position;
+            if pixel == self.end { self.done = true }
+            else {
+                let doubled = 2i64 * self.error;
+                if doubled >= self.delta.1 { self.error += self.delta.1; self.position.0 += self.step.0; }
+                if doubled <= self.delta.0 { self.error += self.delta.0; self.position.1 += self.step.1; }
+            } // else ..
+
+            narrow(pixel)
+        } // fn ..
+    } // impl ..
+
+
+    impl<T: Coordinate> Iterator for ThickLinePixels<T> {
+        type Item = Vec2<T>;
+
+        fn next(&mut self) -> Option<Vec2<T>> {
+            loop {
+                let center = match self.center {
+                    Some(center) if self.offset < self.length => center,
+                    _ => { self.center = Some(self.line.next()?); self.offset = 0i64; continue }
+                }; // let ..
+
+                let shift = self.offset - (self.length - 1i64) / 2i64;
+                self.offset += 1i64;
+                let pixel = Vec2(center.0.to_i64() + self.across.0 * shift, center.1.to_i64() + self.across.1 * shift);
+                if let Some(pixel) = narrow(pixel) { return Some(pixel) }
+            } // loop ..
+        } // fn ..
+    } // impl ..
+
+
+    impl<T: Coordinate> Iterator for EllipsePixels<T> {
+        type Item = Vec2<T>;
+
+        fn next(&mut self) -> Option<Vec2<T>> {
+            loop {
+                if self.quadrant == 4u8 { self.point = Some(self.arc.next()?); self.quadrant = 0u8; }
+                let (x, y) = self.point?;
+                let (mirror_x, mirror_y) = (self.quadrant & 1u8 != 0u8, self.quadrant & 2u8 != 0u8);
+                self.quadrant += 1u8;
+
+                // Points on an axis are their own mirror image
+                if (mirror_x && x == 0i64) || (mirror_y && y == 0i64) { continue }
+                let pixel = Vec2(self.center.0 + if mirror_x { -x } else { x }, self.center.1 + if mirror_y { -y } else { y });
+                if let Some(pixel) = narrow(pixel) { return Some(pixel) }
+            } // loop ..
+        } // fn ..
+    } // impl ..
+
+
+    impl<T: Coordinate> Iterator for EllipseSpans<T> {
+        type Item = Span<T>;
+
+        fn next(&mut self) -> Option<Span<T>> {
+            loop {
+                if let Some(span) = self.pending.take() { return Some(span) }
+
+                // A row is complete once the arc leaves it, its last point being the widest
+                let point = self.arc.next();
+                let (y, x) = match (self.row, point) {
+                    (Some((y, x)), Some((next_x, next_y))) if next_y != y => { self.row = Some((next_y, next_x)); (y, x) }
+                    (_, Some((next_x, next_y)))                          => { self.row = Some((next_y, next_x)); continue }
+                    (Some(row), None)                                    => { self.row = None; row }
+                    (None, None)                                         => return None,
+                }; // let ..
+
+                let start = (self.center.0 - x).clamp(T::MIN, T::MAX);
+                let end   = (self.center.0 + x).clamp(T::MIN, T::MAX);
+                let span  = |row: i64| Some(Span { y: T::from_i64(self.center.1 + row)?, start: T::from_i64(start)?, end: T::from_i64(end)? });
+                self.pending = if y == 0i64 { None } else { span(y) };
+                if let Some(span) = span(-y) { return Some(span) }
+            } // loop ..
+        } // fn ..
+    } // impl ..
+
+
+    impl<T: Coordinate> Iterator for WuLinePixels<T> {
+        type Item = (Vec2<T>, u8);
+
+        fn next(&mut self) -> Option<(Vec2<T>, u8)> {
+            loop {
+                if let Some((pixel, coverage)) = self.pending.take() {
+                    if let Some(pixel) = narrow(pixel) { return Some((pixel, coverage)) }
+                    continue
+                } // if ..
+
+                // Both ends sit on pixel centers and are fully covered
+                if !self.started {
+                    self.started = true;
+                    self.pending = Some((self.position, u8::MAX));
+                    continue
+                } // if ..
+                match self.steps {
+                    0i64 => return None,
+                    1i64 => { self.steps = 0i64; self.pending = Some((self.end, u8::MAX)); continue }
+                    _    => (),
+                } // match ..
+
+                // The line splits its coverage between the pixel it leaves and the next one across
+                self.steps -= 1i64;
+                self.error += self.slope;
+                let carry = (self.error >> 16u32) as i64;
+                self.error &= 0xFFFFu32;
+                self.position = Vec2(
+                    self.position.0 + self.major.0 + self.minor.0 * carry,
+                    self.position.1 + self.major.1 + self.minor.1 * carry,
+                ); // Vec2
+
+                let weight = (self.error >> 8u32) as u8;
+                let across = Vec2(self.position.0 + self.minor.0, self.position.1 + self.minor.1);
+                if weight > 0u8 { self.pending = Some((across, weight)) }
+                if let Some(pixel) = narrow(self.position) { return Some((pixel, u8::MAX - weight)) }
+            } // loop ..
+        } // fn ..
+    } // impl ..
+
+
+    impl QuarterArc {
+        fn new(radius_x: i64, radius_y: i64) -> Self {
+            let (a, b) = (radius_x.unsigned_abs() as i128, radius_y.unsigned_abs() as i128);
+            QuarterArc { radii: (a, b), position: (0i128, b), decision: 0i128, slope: (0i128, 0i128), region: Region::Start }
+        } // fn ..
+    } // impl ..
+
+
+    impl Iterator for QuarterArc {
+        type Item = (i64, i64);
+
+        fn next(&mut self) -> Option<(i64, i64)> {
+            let (a2, b2) = (self.radii.0 * self.radii.0, self.radii.1 * self.radii.1);
+            let (x, y)   = &mut self.position;
+
+            match self.region {
+                Region::Done  => return None,
+                Region::Start => {
+                    self.slope    = (0i128, 2i128 * a2 * *y);
+                    self.decision = 4i128 * b2 - 4i128 * a2 * self.radii.1 + a2;
+                    self.region   = Region::Flat;
+                } // Region::Start
+                Region::Flat  => {
+                    *x += 1i128;
+                    self.slope.0 += 2i128 * b2;
+                    if self.decision < 0i128 { self.decision += 4i128 * (self.slope.0 + b2) }
+                    else {
+                        *y -= 1i128;
+                        self.slope.1 -= 2i128 * a2;
+                        self.decision += 4i128 * (self.slope.0 - self.slope.1 + b2);
+                    } // else ..
+                } // Region::Flat
+                Region::Steep => {
+                    // Very flat ellipses reach the axis early and finish along it
+                    if *y == 0i128 {
+                        if *x >= self.radii.0 { self.region = Region::Done; return None }
+                        *x += 1i128;
+                    } else {
+                        *y -= 1i128;
+                        self.slope.1 -= 2i128 * a2;
+                        if self.decision > 0i128 { self.decision += 4i128 * (a2 - self.slope.1) }
+                        else {
+                            *x += 1i128;
+                            self.slope.0 += 2i128 * b2;
+                            self.decision += 4i128 * (self.slope.0 - self.slope.1 + a2);
+                        } // else ..
+                    } // else ..
+                } // Region::Steep
+            } // match ..
+
+            // Past the point where the slope reaches -1, steps go along y
+            if self.region == Region::Flat && self.slope.0 >= self.slope.1 {
+                let (x, y) = self.position;
+                self.region   = Region::Steep;
+                self.decision = b2 * (2i128 * x + 1i128) * (2i128 * x + 1i128) + 4i128 * a2 * (y - 1i128) * (y - 1i128) - 4i128 * a2 * b2;
+            } // if ..
+
+            Some((self.position.0 as i64, self.position.1 as i64))
+        } // fn ..
+    } // impl ..
+
+
+    impl TriangleSetup {
+        /// Setup of a triangle given in subpixels, of either winding, `None` when it has no area
+        /// or when a coordinate reaches `±2^30`, past which edge functions would overflow `i64`
+        pub fn new(a: Vec2<i32>, b: Vec2<i32>, c: Vec2<i32>) -> Option<Self> {
+            Self::with_depth([widen(a), widen(b), widen(c)], [0f32; 3], [1f32; 3], Interpolation::Affine, None)
+        } // fn ..
+
+
+        /// Setup of a triangle from clip positions, as given by a projection `Mat4` times a `Vec4`
+        /// point, on a viewport of `size` pixels. `None` when the triangle has no area, when a
+        /// vertex lies behind the eye or lands `2^30` subpixels away, triangles crossing the near
+        /// plane need clipping first and those reaching that far need guard band clipping
+        pub fn from_clip(clip: [Vec4<f32>; 3], size: Vec2<i32>, interpolation: Interpolation) -> Option<Self> {
+            if clip.iter().any(|vertex| vertex.3 <= 0f32) { return None }
+
+            // Checked before the conversion, which saturates and turns NaN into zero
+            let scale     = (1i64 << SUBPIXEL_BITS) as f32;
+            let subpixels = |value: f32| { let value = value.round(); (value.abs() < GUARD_BAND as f32).then_some(value as i64) };
+            let position  = |vertex: Vec4<f32>| Some(Vec2(
+                subpixels((vertex.0 / vertex.3 + 1f32) * 0.5f32 * size.0 as f32 * scale)?,
+                subpixels((1f32 - vertex.1 / vertex.3) * 0.5f32 * size.1 as f32 * scale)?,
+            )); // let ..
+
+            let viewport = (Vec2(0i64, 0i64), Vec2(size.0 as i64 - 1i64, size.1 as i64 - 1i64));
+            Self::with_depth(
+                [position(clip[0usize])?, position(clip[1usize])?, position(clip[2usize])?],
+                clip.map(|vertex| vertex.2 / vertex.3),
+                clip.map(|vertex| 1f32 / vertex.3),
+                interpolation,
+                Some(viewport),
+            ) // Self::with_depth
+        } // fn ..
+
+
+        fn with_depth(
+            mut vertices:  [Vec2<i64>; 3],
+            mut depths:    [f32; 3],
+            mut inverse_w: [f32; 3],
+            interpolation: Interpolation,
+            viewport:      Option<(Vec2<i64>, Vec2<i64>)>,
+        ) -> Option<Self> {
+            if vertices.iter().any(|vertex| vertex.0.abs() >= GUARD_BAND || vertex.1.abs() >= GUARD_BAND) { return None }
+
+            let [a, b, c] = vertices;
+            let mut area  = (b.0 - a.0) * (c.1 - a.1) - (b.1 - a.1) * (c.0 - a.0);
+            if area == 0i64 { return None }
+
+            let swapped = area < 0i64;
+            if swapped {
+                vertices.swap(1usize, 2usize);
+                depths.swap(1usize, 2usize);
+                inverse_w.swap(1usize, 2usize);
+                area = -area;
+            } // if ..
+
+            // Edge functions grow towards the inside, an edge is left when the inside lies to its
+            // right, and top when it is horizontal with the inside below it
+            let mut steps  = [Vec2(0i64, 0i64); 3];
+            let mut biases = [0i64; 3];
+            for index in 0usize..3usize {
+                let (start, end) = (vertices[(index + 1usize) % 3usize], vertices[(index + 2usize) % 3usize]);
+                let delta        = Vec2(end.0 - start.0, end.1 - start.1);
+                steps[index]     = Vec2(-delta.1, delta.0);
+                biases[index]    = if delta.1 < 0i64 || (delta.1 == 0i64 && delta.0 > 0i64) { 0i64 } else { 1i64 };
+            } // for ..
+
+            // Pixels whose center falls within the subpixel bounds
+            let half   = 1i64 << (SUBPIXEL_BITS - 1u32);
+            let bounds = |pick: fn(i64, i64) -> i64| Vec2(
+                pick(pick(vertices[0usize].0, vertices[1usize].0), vertices[2usize].0),
+                pick(pick(vertices[0usize].1, vertices[1usize].1), vertices[2usize].1),
+            ); // let ..
+            let (low, high) = (bounds(i64::min), bounds(i64::max));
+            let mut min     = Vec2((low.0 - half + (1i64 << SUBPIXEL_BITS) - 1i64) >> SUBPIXEL_BITS, (low.1 - half + (1i64 << SUBPIXEL_BITS) - 1i64) >> SUBPIXEL_BITS);
+            let mut max     = Vec2((high.0 - half) >> SUBPIXEL_BITS, (high.1 - half) >> SUBPIXEL_BITS);
+            if let Some((low, high)) = viewport {
+                min = Vec2(min.0.max(low.0), min.1.max(low.1));
+                max = Vec2(max.0.min(high.0), max.1.min(high.1));
+            } // if ..
+
+            Some(TriangleSetup { vertices, steps, biases, area, swapped, min, max, depths, inverse_w, interpolation })
+        } // fn ..
+
+
+        pub fn pixels(&self) -> TrianglePixels<'_> {
+            let half   = 1i64 << (SUBPIXEL_BITS - 1u32);
+            let center = Vec2((self.min.0 << SUBPIXEL_BITS) + half, (self.min.1 << SUBPIXEL_BITS) + half);
+            let row    = std::array::from_fn(|index| {
+                let start = self.vertices[(index + 1usize) % 3usize];
+                self.steps[index].0 * (center.0 - start.0) + self.steps[index].1 * (center.1 - start.1) - self.biases[index]
+            }); // let ..
+            TrianglePixels { setup: self, pixel: self.min, edges: row, row }
+        } // fn ..
+
+
+        /// Weights and depth at a pixel center from its edge functions
+        fn fragment(&self, pixel: Vec2<i64>, edges: [i64; 3]) -> Fragment {
+            let mut weights = std::array::from_fn(|index| (edges[index] + self.biases[index]) as f32 / self.area as f32);
+            let depth       = (0usize..3usize).map(|index| weights[index] * self.depths[index]).sum();
+
+            if self.interpolation == Interpolation::Perspective {
+                let scaled: [f32; 3] = std::array::from_fn(|index| weights[index] * self.inverse_w[index]);
+                let total = scaled.iter().sum::<f32>();
+                weights = scaled.map(|weight| weight / total);
+            } // if ..
+            if self.swapped { weights.swap(1usize, 2usize) }
+
+            Fragment { pixel: Vec2(pixel.0 as i32, pixel.1 as i32), weights, depth }
+        } // fn ..
+    } // impl ..
+
+
+    impl Fragment {
+        /// Blends one attribute per vertex, in the order the vertices were given
+        pub fn interpolate<V: Vector<Scalar = f32>>(&self, attributes: [V; 3]) -> V {
+            attributes[0usize] * self.weights[0usize] + attributes[1usize] * self.weights[1usize] + attributes[2usize] * self.weights[2usize]
+        } // fn ..
+    } // impl ..
+
+
+    impl Iterator for TrianglePixels<'_> {
+        type Item = Fragment;
+
+        fn next(&mut self) -> Option<Fragment> {
+            let setup = self.setup;
+            let pixel = 1i64 << SUBPIXEL_BITS;
+
+            while self.pixel.1 <= setup.max.1 {
+                while self.pixel.0 <= setup.max.0 {
+                    let (position, edges) = (self.pixel, self.edges);
+                    self.pixel.0 += 1i64;
+                    for index in 0usize..3usize { self.edges[index] += setup.steps[index].0 * pixel }
+                    if edges.iter().all(|&edge| edge >= 0i64) { return Some(setup.fragment(position, edges)) }
+                } // while ..
+
+                self.pixel = Vec2(setup.min.0, self.pixel.1 + 1i64);
+                for index in 0usize..3usize { self.row[index] += setup.steps[index].1 * pixel }
+                self.edges = self.row;
+            } // while ..
+
+            None
+        } // fn ..
+    } // impl ..
+
+
+    fn widen<T: Coordinate>(point: Vec2<T>) -> Vec2<i64> { Vec2(point.0.to_i64(), point.1.to_i64()) }
+    fn narrow<T: Coordinate>(point: Vec2<i64>) -> Option<Vec2<T>> { Some(Vec2(T::from_i64(point.0)?, T::from_i64(point.1)?)) }
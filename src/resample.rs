@@ -0,0 +1,160 @@
+//#########################
+// D E P E N D E N C I E S
+//#########################
+
+    use crate::vectors::Vec2;
+    use crate::matrices::Affine2;
+    use crate::interpolation::Lerp;
+
+
+//#######################
+// D E F I N I T I O N S
+//#######################
+
+    // Buffers are rows of `width` pixels, the pixel `(x, y)` covering the unit square from
+    // `(x, y)` to `(x + 1, y + 1)`. Transformations map destination positions to source ones,
+    // the inverse of how the image moves, so that every destination pixel is sampled once and
+    // none is left as a hole.
+
+    #[derive(Clone, Copy, PartialEq, Eq, Hash, Default, Debug)]
+    pub enum Filter {
+        /// Fixed-point stepping through the source, see `mode7`
+        #[default]
+        Nearest,
+        /// Blends the four source pixels around each sample, blurs pixel art
+        Bilinear,
+        /// Samples the source upscaled eight times by `scale2x`, which keeps the lines of pixel
+        /// art smooth when rotated. Upscales on every call, sprites drawn often are better
+        /// upscaled once and drawn with `Nearest` and an inverse scaled by eight
+        RotSprite,
+    } // enum ..
+
+
+    /// What destination pixels mapping outside of the source become
+    #[derive(Clone, Copy, PartialEq, Eq, Hash, Default, Debug)]
+    pub enum Edge {
+        /// Left as they are, for sprites drawn over a background
+        #[default]
+        Clip,
+        /// Tiled source, for Mode-7 floors
+        Repeat,
+    } // enum ..
+
+
+    /// Source position of the center of the first pixel of a destination row, and its change
+    /// from one pixel to the next, with `FIXED_BITS` fractional bits. 64 bits leave room for
+    /// sources far wider than the 32768 pixels 32 bits would
+    #[derive(Clone, Copy, PartialEq, Eq, Hash, Default, Debug)]
+    pub struct Scanline {
+        pub start: Vec2<i64>,
+        pub step:  Vec2<i64>,
+    } // struct ..
+
+
+    pub const FIXED_BITS: u32 = 16u32;
+
+
+//###############################
+// I M P L E M E N T A T I O N S
+//###############################
+
+    impl Scanline {
+        pub fn from_affine(inverse: &Affine2<f32>, y: usize) -> Self {
+            let fixed = |vector: Vec2<f32>| Vec2(to_fixed(vector.0), to_fixed(vector.1));
+            Scanline { start: fixed(*inverse * Vec2(0.5f32, y as f32 + 0.5f32)), step: fixed(inverse.0) }
+        } // fn ..
+    } // impl ..
+
+
+    /// Draws the source into the destination through the inverse transformation. A `Mat3`
+    /// is taken as affine, the projective row is ignored, see `mode7` for perspective floors.
+    /// RotSprite upscales the whole source eight times each way on every call, allocating
+    /// 64 times its size, sprites drawn every frame can be upscaled once with `scale2x` and
+    /// drawn with `Filter::Nearest` instead
+    pub fn resample<P: Lerp + PartialEq>(source: &[P], source_width: usize, destination: &mut [P], destination_width: usize, inverse: impl Into<Affine2<f32>>, filter: Filter, edge: Edge) {
+        let inverse = inverse.into();
+        match filter {
+            Filter::Nearest   => mode7(source, source_width, destination, destination_width, edge, |y| Scanline::from_affine(&inverse, y)),
+            Filter::Bilinear  => bilinear(source, source_width, destination, destination_width, &inverse, edge),
+            Filter::RotSprite => {
+                let (mut upscaled, mut width) = (source.to_vec(), source_width);
+                for _ in 0usize..3usize { (upscaled, width) = (scale2x(&upscaled, width), 2usize * width) }
+
+                let inverse = Affine2(Vec2(8f32, 0f32), Vec2(0f32, 8f32), Vec2(0f32, 0f32)) * inverse;
+                mode7(&upscaled, width, destination, destination_width, edge, |y| Scanline::from_affine(&inverse, y))
+            } // Filter::RotSprite
+        } // match ..
+    } // fn ..
+
+
+    /// Nearest sampling with a single fixed-point addition per pixel. `scanline` gives the
+    /// stepping of each destination row, which may change from row to row for perspective floors
+    pub fn mode7<P: Copy>(source: &[P], source_width: usize, destination: &mut [P], destination_width: usize, edge: Edge, mut scanline: impl FnMut(usize) -> Scanline) {
+        if source_width == 0usize || destination_width == 0usize || source.len() < source_width { return }
+        let (width, height) = (source_width as i64, (source.len() / source_width) as i64);
+
+        for (y, row) in destination.chunks_mut(destination_width).enumerate() {
+            let Scanline { mut start, step } = scanline(y);
+            for pixel in row {
+                let (u, v) = (start.0 >> FIXED_BITS, start.1 >> FIXED_BITS);
+                match edge {
+                    Edge::Clip   => if u >= 0i64 && u < width && v >= 0i64 && v < height { *pixel = source[(v * width + u) as usize] },
+                    Edge::Repeat => *pixel = source[(v.rem_euclid(height) * width + u.rem_euclid(width)) as usize],
+                } // match ..
+                // Saturates rather than wraps back into the source on absurd transformations
+                start = Vec2(start.0.saturating_add(step.0), start.1.saturating_add(step.1));
+            } // for ..
+        } // for ..
+    } // fn ..
+
+
+    /// Doubles the size of pixel art, rounding the corners of diagonal lines instead of making
+    /// them blocky, see Andrea Mazzoleni's Scale2x
+    pub fn scale2x<P: Copy + PartialEq>(source: &[P], width: usize) -> Vec<P> {
+        if width == 0usize { return Vec::new() }
+        let height     = source.len() / width;
+        let mut scaled = Vec::with_capacity(4usize * width * height);
+        let at         = |x: usize, y: usize| source[y * width + x];
+
+        for y in 0usize..height {
+            let (above, below) = (y.saturating_sub(1usize), (y + 1usize).min(height - 1usize));
+            for half in [above, below] {
+                for x in 0usize..width {
+                    let (left, right) = (at(x.saturating_sub(1usize), y), at((x + 1usize).min(width - 1usize), y));
+                    let (pixel, near, far) = (at(x, y), at(x, half), at(x, above + below - half));
+                    scaled.push(if left == near && left != far && near != right { near } else { pixel });
+                    scaled.push(if right == near && right != far && near != left { near } else { pixel });
+                } // for ..
+            } // for ..
+        } // for ..
+        scaled
+    } // fn ..
+
+
+    fn bilinear<P: Lerp>(source: &[P], source_width: usize, destination: &mut [P], destination_width: usize, inverse: &Affine2<f32>, edge: Edge) {
+        if source_width == 0usize || destination_width == 0usize || source.len() < source_width { return }
+        let (width, height) = (source_width as i64, (source.len() / source_width) as i64);
+        let at = |x: i64, y: i64| match edge {
+            Edge::Clip   => source[(y.clamp(0i64, height - 1i64) * width + x.clamp(0i64, width - 1i64)) as usize],
+            Edge::Repeat => source[(y.rem_euclid(height) * width + x.rem_euclid(width)) as usize],
+        }; // let ..
+
+        for (y, row) in destination.chunks_mut(destination_width).enumerate() {
+            let mut position = *inverse * Vec2(0.5f32, y as f32 + 0.5f32);
+            for pixel in row {
+                let inside = position.0 >= 0f32 && position.0 < width as f32 && position.1 >= 0f32 && position.1 < height as f32;
+                if edge == Edge::Repeat || inside {
+                    // Pixel centers sit half a pixel away from their corner
+                    let (x, y)   = (position.0 - 0.5f32, position.1 - 0.5f32);
+                    let (x0, y0) = (x.floor(), y.floor());
+                    let (tx, ty) = (x - x0, y - y0);
+                    let (x0, y0) = (x0 as i64, y0 as i64);
+                    *pixel = at(x0, y0).lerp(at(x0 + 1i64, y0), tx).lerp(at(x0, y0 + 1i64).lerp(at(x0 + 1i64, y0 + 1i64), tx), ty);
+                } // if ..
+                position += inverse.0;
+            } // for ..
+        } // for ..
+    } // fn ..
+
+
+    fn to_fixed(value: f32) -> i64 { (value * (1u32 << FIXED_BITS) as f32).round() as i64 }
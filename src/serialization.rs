@@ -0,0 +1,92 @@
+//#########################
+// D E P E N D E N C I E S
+//#########################
+
+    use std::fmt;
+    use std::marker::PhantomData;
+
+    use serde::{Serialize, Serializer, Deserialize, Deserializer};
+    use serde::ser::SerializeTuple;
+    use serde::de::{self, Visitor, SeqAccess};
+
+    use crate::traits::Number;
+    use crate::vectors::{Vec2, Vec3, Vec4};
+    use crate::matrices::{Mat3, Mat4, Affine2};
+    use crate::Angle;
+
+
+//#############
+// M A C R O S
+//#############
+
+    /// Serializes tuple structs as fixed length sequences so that vectors
+    /// stay compact and matrices end up as nested arrays
+    macro_rules! impl_serde_tuple(
+        ($($S: ident<$T: ident>($($i: tt: $E: ty),*) => $len: literal);*$(;)*) => {$(
+
+            impl<$T: Number + Serialize> Serialize for $S<$T> {
+                fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    let mut tuple = serializer.serialize_tuple($len)?;
+                    $(tuple.serialize_element(&self.$i)?;)*
+                    tuple.end()
+                } // fn serialize()
+            } // impl Serialize ..
+
+
+            impl<'de, $T: Number + Deserialize<'de>> Deserialize<'de> for $S<$T> {
+                fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+
+                    struct TupleVisitor<$T>(PhantomData<$T>);
+                    impl<'de, $T: Number + Deserialize<'de>> Visitor<'de> for TupleVisitor<$T> {
+                        type Value = $S<$T>;
+
+                        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                            write!(formatter, "a sequence of {} elements", $len)
+                        } // fn expecting()
+
+                        fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+                            Ok($S($(
+                                seq.next_element::<$E>()?.ok_or_else(|| de::Error::invalid_length($i, &self))?
+                            ),*))
+                        } // fn visit_seq()
+                    } // impl Visitor ..
+
+
+                    deserializer.deserialize_tuple($len, TupleVisitor(PhantomData))
+
+                } // fn deserialize()
+            } // impl Deserialize ..
+
+        )*}
+    ); // impl_serde_tuple()
+
+
+//###############################
+// I M P L E M E N T A T I O N S
+//###############################
+
+    impl_serde_tuple!(
+        Vec2<T>(0: T, 1: T)                                     => 2;
+        Vec3<T>(0: T, 1: T, 2: T)                               => 3;
+        Vec4<T>(0: T, 1: T, 2: T, 3: T)                         => 4;
+        Mat3<T>(0: Vec3<T>, 1: Vec3<T>, 2: Vec3<T>)             => 3;
+        Mat4<T>(0: Vec4<T>, 1: Vec4<T>, 2: Vec4<T>, 3: Vec4<T>) => 4;
+        Affine2<T>(0: Vec2<T>, 1: Vec2<T>, 2: Vec2<T>)          => 3;
+    ); // impl_serde_tuple!()
+
+
+    impl Serialize for Angle {
+        /// Emits degrees for human readable formats and the raw byte otherwise
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            if serializer.is_human_readable() { serializer.serialize_f32(self.to_degrees()) }
+            else                              { serializer.serialize_u8(self.0) }
+        } // fn ..
+    } // impl ..
+
+
+    impl<'de> Deserialize<'de> for Angle {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            if deserializer.is_human_readable() { f32::deserialize(deserializer).map(Angle::from_degrees) }
+            else                                { u8::deserialize(deserializer).map(Angle) }
+        } // fn ..
+    } // impl ..
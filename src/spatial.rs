@@ -0,0 +1,436 @@
+//#########################
+// D E P E N D E N C I E S
+//#########################
+
+    use std::cmp::Reverse;
+    use std::collections::{HashMap, BinaryHeap};
+
+    use crate::vectors::Vec2;
+    use crate::geometry::Aabb2;
+
+
+//#######################
+// D E F I N I T I O N S
+//#######################
+
+    // Both indices store items of any type along with inclusive integer bounds, a point
+    // being a box whose corners are equal. Queries report boxes touching the query shape,
+    // and distances run from the query point to the nearest point of each box.
+
+    /// Key of an item in a spatial index, valid until the item is removed
+    #[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+    pub struct Handle(usize);
+
+
+    /// Uniform grid hashing square cells, items are listed in every cell they touch
+    #[derive(Clone, Debug)]
+    pub struct SpatialHash<T> {
+        cell_size: i32,
+        cells:     HashMap<Vec2<i32>, Vec<usize>>,
+        slots:     Slots<T, ()>,
+    } // struct ..
+
+
+    /// Loose quadtree, items sit in the deepest node whose bounds doubled around its
+    /// center hold them, so that they are never split across nodes
+    #[derive(Clone, Debug)]
+    pub struct Quadtree<T> {
+        nodes:     Vec<QuadNode>,
+        max_depth: u32,
+        slots:     Slots<T, usize>,
+    } // struct ..
+
+
+    #[derive(Clone, Debug)]
+    struct QuadNode {
+        /// Tight bounds, the loose ones reach `size / 2` further on every side
+        bounds:   Aabb2<i32>,
+        depth:    u32,
+        /// Index of the first of four children ordered by quadrant, bottom left first
+        children: Option<usize>,
+        items:    Vec<usize>,
+    } // struct ..
+
+
+    /// Items with their bounds and some per-index data, and the free slots left by removals
+    #[derive(Clone, Debug)]
+    struct Slots<T, D> {
+        entries: Vec<Option<Entry<T, D>>>,
+        free:    Vec<usize>,
+    } // struct ..
+
+
+    #[derive(Clone, Debug)]
+    struct Entry<T, D> {
+        bounds: Aabb2<i32>,
+        item:   T,
+        data:   D,
+    } // struct ..
+
+
+//###############################
+// I M P L E M E N T A T I O N S
+//###############################
+
+    impl<T> SpatialHash<T> {
+        pub fn new(cell_size: i32) -> Self {
+            assert!(cell_size > 0i32, "cell size must be positive");
+            SpatialHash { cell_size, cells: HashMap::new(), slots: Slots::new() }
+        } // fn ..
+
+
+        pub fn cell_size(&self) -> i32 { self.cell_size }
+        pub fn len(&self) -> usize { self.slots.len() }
+        pub fn is_empty(&self) -> bool { self.len() == 0usize }
+        pub fn get(&self, handle: Handle) -> Option<&T> { self.slots.get(handle).map(|entry| &entry.item) }
+        pub fn get_mut(&mut self, handle: Handle) -> Option<&mut T> { self.slots.get_mut(handle).map(|entry| &mut entry.item) }
+        pub fn bounds(&self, handle: Handle) -> Option<Aabb2<i32>> { self.slots.get(handle).map(|entry| entry.bounds) }
+
+        /// Cell holding a position
+        pub fn cell(&self, position: Vec2<i32>) -> Vec2<i32> { Vec2(position.0.div_euclid(self.cell_size), position.1.div_euclid(self.cell_size)) }
+
+
+        pub fn insert(&mut self, bounds: Aabb2<i32>, item: T) -> Handle {
+            let slot = self.slots.insert(Entry { bounds, item, data: () });
+            self.link(slot, bounds);
+            Handle(slot)
+        } // fn ..
+
+
+        pub fn remove(&mut self, handle: Handle) -> Option<T> {
+            let bounds = self.slots.get(handle)?.bounds;
+            self.unlink(handle.0, bounds);
+            self.slots.remove(handle).map(|entry| entry.item)
+        } // fn ..
+
+
+        /// Moves an item to new bounds, returns `false` for a stale handle
+        pub fn move_to(&mut self, handle: Handle, bounds: Aabb2<i32>) -> bool {
+            let Some(entry) = self.slots.get_mut(handle) else { return false };
+            let previous = std::mem::replace(&mut entry.bounds, bounds);
+            if self.cells_of(previous) != self.cells_of(bounds) {
+                self.unlink(handle.0, previous);
+                self.link(handle.0, bounds);
+            } // if ..
+            true
+        } // fn ..
+
+
+        /// Calls `found` once for every item touching the rectangle
+        pub fn query_rect(&self, rect: &Aabb2<i32>, mut found: impl FnMut(Handle, &T)) {
+            self.visit(*rect, |entry| overlaps(&entry.bounds, rect), &mut found)
+        } // fn ..
+
+
+        /// Calls `found` once for every item within `radius` of the center
+        pub fn query_radius(&self, center: Vec2<i32>, radius: i32, mut found: impl FnMut(Handle, &T)) {
+            let reach = circle_bounds(center, radius);
+            self.visit(reach, |entry| squared_distance(&entry.bounds, center) <= (radius as i128) * (radius as i128), &mut found)
+        } // fn ..
+
+
+        /// Up to `k` items nearest to the point, nearest first. Rings of cells are searched
+        /// outwards until no unseen item can be nearer than the ones found, or until the rings
+        /// would hold more cells than are occupied, which are then scanned directly
+        pub fn nearest(&self, point: Vec2<i32>, k: usize) -> Vec<Handle> {
+            let mut found: Vec<(i128, usize)> = Vec::new();
+            if k == 0usize || self.is_empty() { return Vec::new() }
+
+            // Items spanning several cells are only pushed from the first of them to be searched
+            let center   = self.cell(point);
+            let distance = |cell: &Vec2<i32>| (cell.0 as i64 - center.0 as i64).abs().max((cell.1 as i64 - center.1 as i64).abs());
+            let mut seen = vec![false; self.slots.entries.len()];
+            let mut push = |found: &mut Vec<(i128, usize)>, slots: &Vec<usize>| {
+                for &slot in slots {
+                    if !std::mem::replace(&mut seen[slot], true) { found.push((squared_distance(&self.slots.entry(slot).bounds, point), slot)) }
+                } // for ..
+            }; // let ..
+
+            for ring in 0i64.. {
+                if (2i64 * ring + 1i64).pow(2u32) > self.cells.len() as i64 {
+                    for (_, slots) in self.cells.iter().filter(|(cell, _)| distance(cell) >= ring) { push(&mut found, slots) }
+                    found.sort_unstable();
+                    break
+                } // if ..
+
+                for cell in ring_cells(center, ring) {
+                    if let Some(slots) = self.cells.get(&cell) { push(&mut found, slots) }
+                } // for ..
+
+                // Unseen items lie in further rings, at least `ring` whole cells away
+                if found.len() < k { continue }
+                found.sort_unstable();
+                let reach = ring as i128 * self.cell_size as i128;
+                if found[k - 1usize].0 <= reach * reach { break }
+            } // for ..
+
+            found.into_iter().take(k).map(|(_, slot)| Handle(slot)).collect()
+        } // fn ..
+
+
+        fn cells_of(&self, bounds: Aabb2<i32>) -> (Vec2<i32>, Vec2<i32>) { (self.cell(bounds.min), self.cell(bounds.max)) }
+
+
+        fn link(&mut self, slot: usize, bounds: Aabb2<i32>) {
+            let (min, max) = self.cells_of(bounds);
+            for y in min.1..=max.1 {
+                for x in min.0..=max.0 { self.cells.entry(Vec2(x, y)).or_default().push(slot) }
+            } // for ..
+        } // fn ..
+
+
+        fn unlink(&mut self, slot: usize, bounds: Aabb2<i32>) {
+            let (min, max) = self.cells_of(bounds);
+            for y in min.1..=max.1 {
+                for x in min.0..=max.0 {
+                    let Some(cell) = self.cells.get_mut(&Vec2(x, y)) else { continue };
+                    cell.retain(|&other| other != slot);
+                    if cell.is_empty() { self.cells.remove(&Vec2(x, y)); }
+                } // for ..
+            } // for ..
+        } // fn ..
+
+
+        /// Visits the cells touching the area, reporting an item only from the first of them
+        /// it shares with the area so that items spanning several cells come once. Areas larger
+        /// than the occupied cells go through the occupied cells instead
+        fn visit(&self, area: Aabb2<i32>, test: impl Fn(&Entry<T, ()>) -> bool, found: &mut impl FnMut(Handle, &T)) {
+            let (min, max) = self.cells_of(area);
+            let mut visit_cell = |cell: Vec2<i32>, slots: &Vec<usize>| {
+                for &slot in slots {
+                    let entry = self.slots.entry(slot);
+                    let first = self.cell(entry.bounds.min);
+                    if Vec2(first.0.max(min.0), first.1.max(min.1)) == cell && test(entry) { found(Handle(slot), &entry.item) }
+                } // for ..
+            }; // let ..
+
+            let size = (max.0 as i128 - min.0 as i128 + 1i128) * (max.1 as i128 - min.1 as i128 + 1i128);
+            if size > self.cells.len() as i128 {
+                for (&cell, slots) in &self.cells {
+                    if min.0 <= cell.0 && cell.0 <= max.0 && min.1 <= cell.1 && cell.1 <= max.1 { visit_cell(cell, slots) }
+                } // for ..
+                return
+            } // if ..
+
+            for y in min.1..=max.1 {
+                for x in min.0..=max.0 {
+                    if let Some(slots) = self.cells.get(&Vec2(x, y)) { visit_cell(Vec2(x, y), slots) }
+                } // for ..
+            } // for ..
+        } // fn ..
+    } // impl ..
+
+
+    impl<T> Quadtree<T> {
+        /// Tree covering the given bounds, items outside of them all land in the root
+        pub fn new(bounds: Aabb2<i32>, max_depth: u32) -> Self {
+            Quadtree {
+                nodes: vec![QuadNode { bounds, depth: 0u32, children: None, items: Vec::new() }],
+                max_depth,
+                slots: Slots::new(),
+            } // Quadtree
+        } // fn ..
+
+
+        pub fn len(&self) -> usize { self.slots.len() }
+        pub fn is_empty(&self) -> bool { self.len() == 0usize }
+        pub fn get(&self, handle: Handle) -> Option<&T> { self.slots.get(handle).map(|entry| &entry.item) }
+        pub fn get_mut(&mut self, handle: Handle) -> Option<&mut T> { self.slots.get_mut(handle).map(|entry| &mut entry.item) }
+        pub fn bounds(&self, handle: Handle) -> Option<Aabb2<i32>> { self.slots.get(handle).map(|entry| entry.bounds) }
+
+
+        pub fn insert(&mut self, bounds: Aabb2<i32>, item: T) -> Handle {
+            let node = self.place(bounds);
+            let slot = self.slots.insert(Entry { bounds, item, data: node });
+            self.nodes[node].items.push(slot);
+            Handle(slot)
+        } // fn ..
+
+
+        pub fn remove(&mut self, handle: Handle) -> Option<T> {
+            let entry = self.slots.remove(handle)?;
+            self.nodes[entry.data].items.retain(|&slot| slot != handle.0);
+            Some(entry.item)
+        } // fn ..
+
+
+        /// Moves an item to new bounds, returns `false` for a stale handle
+        pub fn move_to(&mut self, handle: Handle, bounds: Aabb2<i32>) -> bool {
+            let Some(previous) = self.slots.get(handle).map(|entry| entry.data) else { return false };
+            let node = self.place(bounds);
+            if node != previous {
+                self.nodes[previous].items.retain(|&slot| slot != handle.0);
+                self.nodes[node].items.push(handle.0);
+            } // if ..
+
+            let entry    = self.slots.entry_mut(handle.0);
+            entry.bounds = bounds;
+            entry.data   = node;
+            true
+        } // fn ..
+
+
+        /// Calls `found` for every item touching the rectangle
+        pub fn query_rect(&self, rect: &Aabb2<i32>, mut found: impl FnMut(Handle, &T)) {
+            self.visit(|bounds| overlaps(bounds, rect), &mut found)
+        } // fn ..
+
+
+        /// Calls `found` for every item within `radius` of the center
+        pub fn query_radius(&self, center: Vec2<i32>, radius: i32, mut found: impl FnMut(Handle, &T)) {
+            let squared_radius = (radius as i128) * (radius as i128);
+            self.visit(|bounds| squared_distance(bounds, center) <= squared_radius, &mut found)
+        } // fn ..
+
+
+        /// Up to `k` items nearest to the point, nearest first, searching nodes by increasing distance
+        pub fn nearest(&self, point: Vec2<i32>, k: usize) -> Vec<Handle> {
+            let mut nearest = Vec::with_capacity(k);
+            let mut pending = BinaryHeap::from([Reverse((0i128, false, 0usize))]);
+
+            // Items and nodes share the queue, an item popped first is nearer than anything left
+            while let Some(Reverse((distance, is_item, index))) = pending.pop() {
+                if nearest.len() == k { break }
+                if is_item { nearest.push(Handle(index)); continue }
+
+                let node = &self.nodes[index];
+                for &slot in &node.items { pending.push(Reverse((squared_distance(&self.slots.entry(slot).bounds, point), true, slot))) }
+                for child in node.children.into_iter().flat_map(|first| first..first + 4usize) {
+                    let reach = squared_distance(&loose(&self.nodes[child].bounds), point);
+                    pending.push(Reverse((reach.max(distance), false, child)));
+                } // for ..
+            } // while ..
+
+            nearest
+        } // fn ..
+
+
+        /// Deepest node whose loose bounds hold the box, creating nodes on the way
+        fn place(&mut self, bounds: Aabb2<i32>) -> usize {
+            let center = Vec2(midpoint(bounds.min.0, bounds.max.0), midpoint(bounds.min.1, bounds.max.1));
+            let extent = (bounds.max.0 as i64 - bounds.min.0 as i64).max(bounds.max.1 as i64 - bounds.min.1 as i64);
+
+            let mut index = 0usize;
+            loop {
+                let node = &self.nodes[index];
+                let size = node.bounds.max.0 as i64 - node.bounds.min.0 as i64;
+                if node.depth >= self.max_depth || size < 2i64 || !contains(&node.bounds, center) || extent > size / 4i64 { return index }
+
+                let first = match node.children {
+                    Some(first) => first,
+                    None        => self.split(index),
+                }; // let ..
+                match (first..first + 4usize).find(|&child| contains(&self.nodes[child].bounds, center)) {
+                    Some(child) => index = child,
+                    None        => return index,
+                } // match ..
+            } // loop ..
+        } // fn ..
+
+
+        fn split(&mut self, index: usize) -> usize {
+            let QuadNode { bounds, depth, .. } = self.nodes[index];
+            let middle = Vec2(midpoint(bounds.min.0, bounds.max.0), midpoint(bounds.min.1, bounds.max.1));
+            let first  = self.nodes.len();
+
+            for (x, y) in [(false, false), (true, false), (false, true), (true, true)] {
+                let min = Vec2(if x { middle.0 + 1i32 } else { bounds.min.0 }, if y { middle.1 + 1i32 } else { bounds.min.1 });
+                let max = Vec2(if x { bounds.max.0 } else { middle.0 }, if y { bounds.max.1 } else { middle.1 });
+                self.nodes.push(QuadNode { bounds: Aabb2::new(min, max), depth: depth + 1u32, children: None, items: Vec::new() });
+            } // for ..
+
+            self.nodes[index].children = Some(first);
+            first
+        } // fn ..
+
+
+        fn visit(&self, test: impl Fn(&Aabb2<i32>) -> bool, found: &mut impl FnMut(Handle, &T)) {
+            let mut pending = vec![0usize];
+            while let Some(index) = pending.pop() {
+                let node = &self.nodes[index];
+                if index != 0usize && !test(&loose(&node.bounds)) { continue }
+
+                for &slot in &node.items {
+                    let entry = self.slots.entry(slot);
+                    if test(&entry.bounds) { found(Handle(slot), &entry.item) }
+                } // for ..
+                pending.extend(node.children.into_iter().flat_map(|first| first..first + 4usize));
+            } // while ..
+        } // fn ..
+    } // impl ..
+
+
+    impl<T, D> Slots<T, D> {
+        fn new() -> Self { Slots { entries: Vec::new(), free: Vec::new() } }
+        fn len(&self) -> usize { self.entries.len() - self.free.len() }
+        fn get(&self, handle: Handle) -> Option<&Entry<T, D>> { self.entries.get(handle.0)?.as_ref() }
+        fn get_mut(&mut self, handle: Handle) -> Option<&mut Entry<T, D>> { self.entries.get_mut(handle.0)?.as_mut() }
+
+        /// Entry of a slot known to be occupied
+        fn entry(&self, slot: usize) -> &Entry<T, D> { self.entries[slot].as_ref().expect("occupied slot") }
+        fn entry_mut(&mut self, slot: usize) -> &mut Entry<T, D> { self.entries[slot].as_mut().expect("occupied slot") }
+
+
+        fn insert(&mut self, entry: Entry<T, D>) -> usize {
+            match self.free.pop() {
+                Some(slot) => { self.entries[slot] = Some(entry); slot }
+                None       => { self.entries.push(Some(entry)); self.entries.len() - 1usize }
+            } // match ..
+        } // fn ..
+
+
+        fn remove(&mut self, handle: Handle) -> Option<Entry<T, D>> {
+            let entry = self.entries.get_mut(handle.0)?.take()?;
+            self.free.push(handle.0);
+            Some(entry)
+        } // fn ..
+    } // impl ..
+
+
+    fn overlaps(a: &Aabb2<i32>, b: &Aabb2<i32>) -> bool {
+        a.min.0 <= b.max.0 && b.min.0 <= a.max.0 && a.min.1 <= b.max.1 && b.min.1 <= a.max.1
+    } // fn ..
+
+
+    fn contains(bounds: &Aabb2<i32>, point: Vec2<i32>) -> bool {
+        bounds.min.0 <= point.0 && point.0 <= bounds.max.0 && bounds.min.1 <= point.1 && point.1 <= bounds.max.1
+    } // fn ..
+
+
+    /// Squared distance from the point to the nearest point of the box, in `i128` so that it never overflows
+    fn squared_distance(bounds: &Aabb2<i32>, point: Vec2<i32>) -> i128 {
+        let gap = |min: i32, max: i32, value: i32| (min as i128 - value as i128).max(value as i128 - max as i128).max(0i128);
+        let (x, y) = (gap(bounds.min.0, bounds.max.0, point.0), gap(bounds.min.1, bounds.max.1, point.1));
+        x * x + y * y
+    } // fn ..
+
+
+    fn circle_bounds(center: Vec2<i32>, radius: i32) -> Aabb2<i32> {
+        Aabb2::new(
+            Vec2(center.0.saturating_sub(radius), center.1.saturating_sub(radius)),
+            Vec2(center.0.saturating_add(radius), center.1.saturating_add(radius)),
+        ) // Aabb2
+    } // fn ..
+
+
+    /// Tight bounds grown by half their size on every side
+    fn loose(bounds: &Aabb2<i32>) -> Aabb2<i32> {
+        let (x, y) = ((bounds.max.0 - bounds.min.0) / 2i32 + 1i32, (bounds.max.1 - bounds.min.1) / 2i32 + 1i32);
+        Aabb2::new(
+            Vec2(bounds.min.0.saturating_sub(x), bounds.min.1.saturating_sub(y)),
+            Vec2(bounds.max.0.saturating_add(x), bounds.max.1.saturating_add(y)),
+        ) // Aabb2
+    } // fn ..
+
+
+    fn midpoint(a: i32, b: i32) -> i32 { ((a as i64 + b as i64).div_euclid(2i64)) as i32 }
+
+
+    /// Cells at a Chebyshev distance of `ring` from the center, walking the rows above and
+    /// below then the columns on either side
+    fn ring_cells(center: Vec2<i32>, ring: i64) -> impl Iterator<Item = Vec2<i32>> {
+        let (cx, cy) = (center.0 as i64, center.1 as i64);
+        let rows     = [-ring, ring].into_iter().take(if ring == 0i64 { 1usize } else { 2usize }).flat_map(move |y| (-ring..=ring).map(move |x| (x, y)));
+        let columns  = [-ring, ring].into_iter().take(if ring == 0i64 { 0usize } else { 2usize }).flat_map(move |x| (1i64 - ring..ring).map(move |y| (x, y)));
+        rows.chain(columns).filter_map(move |(x, y)| Some(Vec2(i32::try_from(cx + x).ok()?, i32::try_from(cy + y).ok()?)))
+    } // fn ..
@@ -11,6 +11,151 @@
     }; // use ..
 
     use crate::Angle;
+    use crate::vectors::{Vec2, Vec3, Vec4};
+    use crate::matrices::{Mat3, Mat4};
+
+
+//#############
+// M A C R O S
+//#############
+
+    /// Asserts that two values are approximately equal, see [`ApproxEq`] for
+    /// the meaning of each tolerance
+    ///
+    /// ```
+    /// # use lofi_maths::assert_approx_eq;
+    /// assert_approx_eq!(0.1f32 + 0.2f32, 0.3f32);
+    /// assert_approx_eq!(1f32, 1.05f32, epsilon = 0.1f32);
+    /// assert_approx_eq!(100f32, 101f32, epsilon = 0f32, max_relative = 0.01f32);
+    /// assert_approx_eq!(1f32, 1f32 + f32::EPSILON, max_ulps = 1u32);
+    /// ```
+    #[macro_export]
+    macro_rules! assert_approx_eq(
+        ($left: expr, $right: expr $(,)?) => {
+            $crate::__assert_approx!(true, $left, $right, |l, r| $crate::traits::ApproxEq::approx_eq(l, r))
+        };
+        ($left: expr, $right: expr, epsilon = $epsilon: expr $(,)?) => {
+            $crate::__assert_approx!(true, $left, $right, |l, r| $crate::traits::ApproxEq::abs_diff_eq(l, r, $epsilon))
+        };
+        ($left: expr, $right: expr, epsilon = $epsilon: expr, max_relative = $max_relative: expr $(,)?) => {
+            $crate::__assert_approx!(true, $left, $right, |l, r| $crate::traits::ApproxEq::relative_eq(l, r, $epsilon, $max_relative))
+        };
+        ($left: expr, $right: expr, max_ulps = $max_ulps: expr $(,)?) => {
+            $crate::__assert_approx!(true, $left, $right, |l, r| $crate::traits::ApproxEq::ulps_eq(l, r, $crate::traits::ApproxEq::epsilon_of(l), $max_ulps))
+        };
+    ); // assert_approx_eq!()
+
+
+    /// Asserts that two values are not approximately equal, accepts the same
+    /// tolerances as [`assert_approx_eq!`]
+    #[macro_export]
+    macro_rules! assert_approx_ne(
+        ($left: expr, $right: expr $(,)?) => {
+            $crate::__assert_approx!(false, $left, $right, |l, r| $crate::traits::ApproxEq::approx_eq(l, r))
+        };
+        ($left: expr, $right: expr, epsilon = $epsilon: expr $(,)?) => {
+            $crate::__assert_approx!(false, $left, $right, |l, r| $crate::traits::ApproxEq::abs_diff_eq(l, r, $epsilon))
+        };
+        ($left: expr, $right: expr, epsilon = $epsilon: expr, max_relative = $max_relative: expr $(,)?) => {
+            $crate::__assert_approx!(false, $left, $right, |l, r| $crate::traits::ApproxEq::relative_eq(l, r, $epsilon, $max_relative))
+        };
+        ($left: expr, $right: expr, max_ulps = $max_ulps: expr $(,)?) => {
+            $crate::__assert_approx!(false, $left, $right, |l, r| $crate::traits::ApproxEq::ulps_eq(l, r, $crate::traits::ApproxEq::epsilon_of(l), $max_ulps))
+        };
+    ); // assert_approx_ne!()
+
+
+    #[doc(hidden)]
+    #[macro_export]
+    macro_rules! __assert_approx(
+        ($expected: expr, $left: expr, $right: expr, $compare: expr) => {
+            match (&$left, &$right) {
+                (left, right) => if $crate::traits::__approx_compare(left, right, $compare) != $expected {
+                    panic!(
+                        "assertion failed: `left {} right`\n  left: `{:?}`\n right: `{:?}`",
+                        if $expected { "≈" } else { "≉" }, left, right,
+                    ) // panic!()
+                } // if ..
+            } // match ..
+        };
+    ); // __assert_approx!()
+
+
+    macro_rules! impl_integer_approx_eq(
+        ($($T: ty => $E: ty),*$(,)*) => {$(
+
+            impl ApproxEq for $T {
+                type Epsilon = $E;
+                const EPSILON:      Self::Epsilon = 0;
+                const MAX_RELATIVE: Self::Epsilon = 0;
+                const MAX_ULPS:     u32           = 0u32;
+
+                fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+                    self.abs_diff(*other) <= epsilon
+                } // fn abs_diff_eq()
+
+                fn relative_eq(&self, other: &Self, epsilon: Self::Epsilon, _max_relative: Self::Epsilon) -> bool {
+                    self.abs_diff_eq(other, epsilon)
+                } // fn relative_eq()
+
+                fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
+                    self.abs_diff_eq(other, epsilon) || (self.abs_diff(*other) as u32) <= max_ulps
+                } // fn ulps_eq()
+            } // impl ApproxEq ..
+
+        )*}
+    ); // impl_integer_approx_eq()
+
+
+    macro_rules! impl_integer_as_f64(
+        ($($T: ty),*$(,)*) => {$(
+
+            impl AsF64 for $T {
+                fn as_f64(self) -> f64 { self as f64 }
+                fn from_f64(value: f64) -> Self { value.round() as $T }
+            } // impl AsF64 ..
+
+        )*}
+    ); // impl_integer_as_f64()
+
+
+    macro_rules! impl_integer_widening(
+        ($($T: ty),*$(,)*) => {$(
+
+            impl Widening for $T {
+                type Wide = i128;
+                fn to_wide(self) -> i128 { self as i128 }
+                fn from_wide(value: i128) -> Self { value as $T }
+            } // impl Widening ..
+
+        )*}
+    ); // impl_integer_widening()
+
+
+    macro_rules! impl_componentwise_approx_eq(
+        ($($S: ident($($i: tt),*)),*$(,)*) => {$(
+
+            impl<T: Number + ApproxEq> ApproxEq for $S<T> {
+                type Epsilon = T::Epsilon;
+                const EPSILON:      Self::Epsilon = T::EPSILON;
+                const MAX_RELATIVE: Self::Epsilon = T::MAX_RELATIVE;
+                const MAX_ULPS:     u32           = T::MAX_ULPS;
+
+                fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+                    $(self.$i.abs_diff_eq(&other.$i, epsilon))&&*
+                } // fn abs_diff_eq()
+
+                fn relative_eq(&self, other: &Self, epsilon: Self::Epsilon, max_relative: Self::Epsilon) -> bool {
+                    $(self.$i.relative_eq(&other.$i, epsilon, max_relative))&&*
+                } // fn relative_eq()
+
+                fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
+                    $(self.$i.ulps_eq(&other.$i, epsilon, max_ulps))&&*
+                } // fn ulps_eq()
+            } // impl ApproxEq ..
+
+        )*}
+    ); // impl_componentwise_approx_eq()
 
 
 //#######################
@@ -40,6 +185,92 @@
 
     pub trait Sqrt { fn sqrt(self) -> Self; }
 
+    /// Conversion to `f64` for intermediate results that would overflow the scalar,
+    /// integers round back to the nearest value and saturate
+    pub trait AsF64: Number { fn as_f64(self) -> f64; fn from_f64(value: f64) -> Self; }
+
+    /// Signed type holding products of several scalars, `i128` for integers and
+    /// the scalar itself for floats, `from_wide` truncates values out of range
+    pub trait Widening: Signed {
+        type Wide: Signed;
+        fn to_wide(self) -> Self::Wide;
+        fn from_wide(value: Self::Wide) -> Self;
+    } // trait ..
+
+
+    /// Operations shared by `Vec2`, `Vec3` and `Vec4` to write code generic over the dimension
+    pub trait Vector:
+        Copy
+        + PartialEq
+        + Add<Output=Self> + AddAssign
+        + Sub<Output=Self> + SubAssign
+        + Mul<Self::Scalar, Output=Self> + MulAssign<Self::Scalar>
+        + Div<Self::Scalar, Output=Self> + DivAssign<Self::Scalar>
+        + Zero
+    {
+        type Scalar: Number;
+        const DIMENSION: usize;
+
+        fn splat(value: Self::Scalar) -> Self;
+        fn component(&self, index: usize) -> Self::Scalar;
+        fn component_mut(&mut self, index: usize) -> &mut Self::Scalar;
+        fn dot(a: Self, b: Self) -> Self::Scalar;
+
+        fn squared_magnitude(&self) -> Self::Scalar { Self::dot(*self, *self) }
+        fn magnitude(&self) -> Self::Scalar where Self::Scalar: Sqrt { self.squared_magnitude().sqrt() }
+
+        /// Returns the vector scaled to a unit magnitude, or the zero vector
+        fn normalized(self) -> Self where Self::Scalar: Sqrt {
+            let magnitude = self.magnitude();
+            if magnitude == Self::Scalar::ZERO { Self::ZERO } else { self / magnitude }
+        } // fn ..
+
+        /// Component-wise minimum
+        fn min(a: Self, b: Self) -> Self {
+            let mut min = a;
+            for index in 0usize..Self::DIMENSION { if b.component(index) < a.component(index) { *min.component_mut(index) = b.component(index) }}
+            min
+        } // fn ..
+
+        /// Component-wise maximum
+        fn max(a: Self, b: Self) -> Self {
+            let mut max = a;
+            for index in 0usize..Self::DIMENSION { if b.component(index) > a.component(index) { *max.component_mut(index) = b.component(index) }}
+            max
+        } // fn ..
+    } // trait ..
+
+
+    /// Approximate equality for results that went through rounding.
+    ///
+    /// - `abs_diff_eq` accepts values at most `epsilon` apart
+    /// - `relative_eq` also accepts values whose difference is at most `max_relative` times the largest of both
+    /// - `ulps_eq` also accepts floats at most `max_ulps` representable values apart
+    ///
+    /// Integers are exact units, so their relative tolerance is ignored and one ULP is one unit.
+    /// Angles compare along the shortest way around the circle, in bytes.
+    pub trait ApproxEq: Sized {
+        type Epsilon: Copy;
+        const EPSILON:      Self::Epsilon;
+        const MAX_RELATIVE: Self::Epsilon;
+        const MAX_ULPS:     u32;
+
+        fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool;
+        fn relative_eq(&self, other: &Self, epsilon: Self::Epsilon, max_relative: Self::Epsilon) -> bool;
+        fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool;
+
+        /// Relative comparison with the default tolerances
+        fn approx_eq(&self, other: &Self) -> bool { self.relative_eq(other, Self::EPSILON, Self::MAX_RELATIVE) }
+        #[doc(hidden)]
+        fn epsilon_of(&self) -> Self::Epsilon { Self::EPSILON }
+    } // trait ..
+
+
+    #[doc(hidden)]
+    pub fn __approx_compare<T: ApproxEq>(left: &T, right: &T, compare: impl Fn(&T, &T) -> bool) -> bool {
+        compare(left, right)
+    } // fn ..
+
 
     const fn isqrt8(x: u8) -> u8 {
 
@@ -145,43 +376,59 @@
         + Div<Rhs, Output=Output> + DivAssign
         + Zero + One {}
 
-    impl Signed for i8  { type Unsigned = u8; }
-    impl Signed for i16 { type Unsigned = u16; }
-    impl Signed for i32 { type Unsigned = u32; }
-    impl Signed for f32 { type Unsigned = f32; }
-    impl Unsigned for u8  { type Signed = i8; }
-    impl Unsigned for u16 { type Signed = i16; }
-    impl Unsigned for u32 { type Signed = i32; }
-
-    impl Integer for u8  {}
-    impl Integer for u16 {}
-    impl Integer for u32 {}
-    impl Integer for i8  {}
-    impl Integer for i16 {}
-    impl Integer for i32 {}
+    impl Signed for i8   { type Unsigned = u8; }
+    impl Signed for i16  { type Unsigned = u16; }
+    impl Signed for i32  { type Unsigned = u32; }
+    impl Signed for i64  { type Unsigned = u64; }
+    impl Signed for i128 { type Unsigned = u128; }
+    impl Signed for f32  { type Unsigned = f32; }
+    impl Unsigned for u8   { type Signed = i8; }
+    impl Unsigned for u16  { type Signed = i16; }
+    impl Unsigned for u32  { type Signed = i32; }
+    impl Unsigned for u64  { type Signed = i64; }
+    impl Unsigned for u128 { type Signed = i128; }
+
+    impl Integer for u8   {}
+    impl Integer for u16  {}
+    impl Integer for u32  {}
+    impl Integer for i8   {}
+    impl Integer for i16  {}
+    impl Integer for i32  {}
+    impl Integer for u64  {}
+    impl Integer for u128 {}
+    impl Integer for i64  {}
+    impl Integer for i128 {}
 
     impl Float for f32 {}
 
 
-    impl Zero for u8  { const ZERO: Self = 0u8; }
-    impl Zero for u16 { const ZERO: Self = 0u16; }
-    impl Zero for u32 { const ZERO: Self = 0u32; }
-    impl Zero for i8  { const ZERO: Self = 0i8; }
-    impl Zero for i16 { const ZERO: Self = 0i16; }
-    impl Zero for i32 { const ZERO: Self = 0i32; }
+    impl Zero for u8   { const ZERO: Self = 0u8; }
+    impl Zero for u16  { const ZERO: Self = 0u16; }
+    impl Zero for u32  { const ZERO: Self = 0u32; }
+    impl Zero for i8   { const ZERO: Self = 0i8; }
+    impl Zero for i16  { const ZERO: Self = 0i16; }
+    impl Zero for i32  { const ZERO: Self = 0i32; }
+    impl Zero for u64  { const ZERO: Self = 0u64; }
+    impl Zero for u128 { const ZERO: Self = 0u128; }
+    impl Zero for i64  { const ZERO: Self = 0i64; }
+    impl Zero for i128 { const ZERO: Self = 0i128; }
 
-    impl Zero for f32 { const ZERO: Self = 0f32; }
+    impl Zero for f32  { const ZERO: Self = 0f32; }
 
     impl Zero for Angle    { const ZERO: Self = Angle(0u8); }
 
-    impl One for u8  { const ONE: Self = 1u8; }
-    impl One for u16 { const ONE: Self = 1u16; }
-    impl One for u32 { const ONE: Self = 1u32; }
-    impl One for i8  { const ONE: Self = 1i8; }
-    impl One for i16 { const ONE: Self = 1i16; }
-    impl One for i32 { const ONE: Self = 1i32; }
+    impl One for u8   { const ONE: Self = 1u8; }
+    impl One for u16  { const ONE: Self = 1u16; }
+    impl One for u32  { const ONE: Self = 1u32; }
+    impl One for i8   { const ONE: Self = 1i8; }
+    impl One for i16  { const ONE: Self = 1i16; }
+    impl One for i32  { const ONE: Self = 1i32; }
+    impl One for u64  { const ONE: Self = 1u64; }
+    impl One for u128 { const ONE: Self = 1u128; }
+    impl One for i64  { const ONE: Self = 1i64; }
+    impl One for i128 { const ONE: Self = 1i128; }
 
-    impl One for f32 { const ONE: Self = 1f32; }
+    impl One for f32  { const ONE: Self = 1f32; }
 
     impl One for Angle    { const ONE: Self = Angle(128u8); }
 
@@ -194,3 +441,63 @@
 
     impl Trigonometry for f32   { fn sin(self) -> f32 { f32::sin(self) } fn cos(self) -> f32 { f32::cos(self) }}
     impl Trigonometry for Angle { fn sin(self) -> f32 { self.sinf() }    fn cos(self) -> f32 { self.cosf() }}
+
+
+    impl_integer_as_f64!(u8, u16, u32, i8, i16, i32);
+
+    impl AsF64 for f32 { fn as_f64(self) -> f64 { self as f64 } fn from_f64(value: f64) -> Self { value as f32 }}
+
+
+    impl_integer_widening!(i8, i16, i32, i64);
+
+    impl Widening for f32 { type Wide = f32; fn to_wide(self) -> f32 { self } fn from_wide(value: f32) -> Self { value }}
+
+
+    impl_integer_approx_eq!(u8 => u8, u16 => u16, u32 => u32, i8 => u8, i16 => u16, i32 => u32);
+    impl_componentwise_approx_eq!(Vec2(0, 1), Vec3(0, 1, 2), Vec4(0, 1, 2, 3), Mat3(0, 1, 2), Mat4(0, 1, 2, 3));
+
+
+    impl ApproxEq for f32 {
+        type Epsilon = f32;
+        const EPSILON:      Self::Epsilon = f32::EPSILON;
+        const MAX_RELATIVE: Self::Epsilon = f32::EPSILON;
+        const MAX_ULPS:     u32           = 4u32;
+
+        fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+            (self - other).abs() <= epsilon
+        } // fn ..
+
+        fn relative_eq(&self, other: &Self, epsilon: Self::Epsilon, max_relative: Self::Epsilon) -> bool {
+            if self == other                            { return true }
+            if self.is_infinite() || other.is_infinite() { return false }
+            let difference = (self - other).abs();
+            difference <= epsilon || difference <= self.abs().max(other.abs()) * max_relative
+        } // fn ..
+
+        fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
+            if self.abs_diff_eq(other, epsilon)                    { return true }
+            if self.is_nan() || other.is_nan()                     { return false }
+            if self.is_sign_negative() != other.is_sign_negative() { return false }
+            self.to_bits().abs_diff(other.to_bits()) <= max_ulps
+        } // fn ..
+    } // impl ..
+
+
+    impl ApproxEq for Angle {
+        type Epsilon = u8;
+        const EPSILON:      Self::Epsilon = 0u8;
+        const MAX_RELATIVE: Self::Epsilon = 0u8;
+        const MAX_ULPS:     u32           = 0u32;
+
+        fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+            self.0.wrapping_sub(other.0).min(other.0.wrapping_sub(self.0)) <= epsilon
+        } // fn ..
+
+        fn relative_eq(&self, other: &Self, epsilon: Self::Epsilon, _max_relative: Self::Epsilon) -> bool {
+            self.abs_diff_eq(other, epsilon)
+        } // fn ..
+
+        fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
+            self.abs_diff_eq(other, epsilon) || self.abs_diff_eq(other, max_ulps.min(u8::MAX as u32) as u8)
+        } // fn ..
+    } // impl ..
@@ -10,7 +10,7 @@
         Div, DivAssign,
     }; // use ..
 
-    use crate::traits::{Number, Signed, Unsigned, Trigonometry, Sqrt};
+    use crate::traits::{Number, Zero, Signed, Unsigned, Trigonometry, Sqrt, Vector};
     use crate::Angle;
 
 
@@ -75,18 +75,60 @@
     ); // impl_into_fvec()
 
 
+    macro_rules! impl_vector(
+        ($($V: ident($($i: tt),*) => $len: literal),*$(,)*) => {$(
+
+            impl<T: Number> Zero for $V<T> {
+                const ZERO: Self = $V($({ let _ = $i; T::ZERO }),*);
+            } // impl Zero ..
+
+
+            impl<T: Number> Vector for $V<T> {
+                type Scalar = T;
+                const DIMENSION: usize = $len;
+
+                fn splat(value: T) -> Self { $V($({ let _ = $i; value }),*) }
+
+                fn component(&self, index: usize) -> T {
+                    match index {
+                        $($i => self.$i,)*
+                        _    => panic!("component index {} out of range for {}", index, stringify!($V)),
+                    } // match ..
+                } // fn component()
+
+                fn component_mut(&mut self, index: usize) -> &mut T {
+                    match index {
+                        $($i => &mut self.$i,)*
+                        _    => panic!("component index {} out of range for {}", index, stringify!($V)),
+                    } // match ..
+                } // fn component_mut()
+
+                fn dot(a: Self, b: Self) -> T {
+                    let mut dot = T::ZERO;
+                    $(dot += a.$i * b.$i;)*
+                    dot
+                } // fn dot()
+            } // impl Vector ..
+
+        )*}
+    ); // impl_vector()
+
+
 //#######################
 // D E F I N I T I O N S
 //#######################
 
+    #[repr(C)]
     #[derive(Clone, Copy, PartialEq, Eq, Hash, Default, Debug)]
     pub struct Vec2<T: Number>(pub T, pub T);
 
 
+    #[repr(C)]
     #[derive(Clone, Copy, PartialEq, Eq, Hash, Default, Debug)]
     pub struct Vec3<T: Number>(pub T, pub T, pub T);
 
 
+    #[repr(C)]
     #[derive(Clone, Copy, PartialEq, Eq, Hash, Default, Debug)]
     pub struct Vec4<T: Number>(pub T, pub T, pub T, pub T);
 
@@ -97,6 +139,7 @@
 
     impl_vec_left_mul!(u8, u16, u32, i8, i16, i32, f32);
     impl_into_fvec!(u8, u16, u32, i8, i16, i32);
+    impl_vector!(Vec2(0, 1) => 2, Vec3(0, 1, 2) => 3, Vec4(0, 1, 2, 3) => 4);
 
 
     //#########
@@ -122,6 +165,8 @@
     impl<T: Signed> Vec2<T> {
         pub fn ivec2_magnitude(&self)           -> T::Unsigned where T: Into<T::Unsigned>, T::Unsigned: Sqrt { ((self.0 * self.0 + self.1 * self.1).into()).sqrt() }
         pub fn ivec2_distance(a: Self, b: Self) -> T::Unsigned where T: Into<T::Unsigned>, T::Unsigned: Sqrt { (b - a).ivec2_magnitude() }
+        /// Z component of the 3D cross product, positive when `b` is counter-clockwise from `a`
+        pub fn cross(a: Self, b: Self) -> T { a.0 * b.1 - a.1 * b.0 }
     } // impl ..
 
 
@@ -138,6 +183,16 @@
     } // impl ..
 
 
+    impl<T: Number> From<[T; 2usize]> for Vec2<T> {
+        fn from(array: [T; 2usize]) -> Self { Vec2(array[0usize], array[1usize]) }
+    } // impl ..
+
+
+    impl<T: Number> From<Vec2<T>> for [T; 2usize] {
+        fn from(vec2: Vec2<T>) -> Self { [vec2.0, vec2.1] }
+    } // impl ..
+
+
     impl From<Angle> for Vec2<f32> {
         /// Creates a `Vec2` from polar coordinates
         fn from(angle: Angle) -> Self {
@@ -213,6 +268,23 @@
     } // impl ..
 
 
+    impl<T: Signed> Vec3<T> {
+        pub fn cross(a: Self, b: Self) -> Self {
+            Vec3(a.1 * b.2 - a.2 * b.1, a.2 * b.0 - a.0 * b.2, a.0 * b.1 - a.1 * b.0)
+        } // fn ..
+    } // impl ..
+
+
+    impl<T: Number> From<[T; 3usize]> for Vec3<T> {
+        fn from(array: [T; 3usize]) -> Self { Vec3(array[0usize], array[1usize], array[2usize]) }
+    } // impl ..
+
+
+    impl<T: Number> From<Vec3<T>> for [T; 3usize] {
+        fn from(vec3: Vec3<T>) -> Self { [vec3.0, vec3.1, vec3.2] }
+    } // impl ..
+
+
     impl<T: Signed> Neg for Vec3<T>  {
         type Output = Self;
         fn neg(self) -> Self::Output { Vec3(-self.0, -self.1, -self.2) }
@@ -289,6 +361,16 @@
     } // impl ..
 
 
+    impl<T: Number> From<[T; 4usize]> for Vec4<T> {
+        fn from(array: [T; 4usize]) -> Self { Vec4(array[0usize], array[1usize], array[2usize], array[3usize]) }
+    } // impl ..
+
+
+    impl<T: Number> From<Vec4<T>> for [T; 4usize] {
+        fn from(vec4: Vec4<T>) -> Self { [vec4.0, vec4.1, vec4.2, vec4.3] }
+    } // impl ..
+
+
     impl<T: Signed> Neg for Vec4<T>  {
         type Output = Self;
         fn neg(self) -> Self::Output { Vec4(-self.0, -self.1, -self.2, -self.3) }
@@ -0,0 +1,131 @@
+use lofi_maths::{assert_approx_eq, assert_approx_ne};
+use lofi_maths::traits::ApproxEq;
+use lofi_maths::vectors::{Vec3, Vec4};
+use lofi_maths::matrices::Mat3;
+use lofi_maths::Angle;
+
+
+#[test]
+fn epsilon_across_the_sign_boundary() {
+    assert!(0f32.abs_diff_eq(&-0f32, 0f32));
+    assert!(1e-6f32.abs_diff_eq(&-1e-6f32, 2e-6f32));
+    assert!(!1e-6f32.abs_diff_eq(&-1e-6f32, 1e-6f32));
+    assert!((-1f32).abs_diff_eq(&-1.05f32, 0.1f32));
+    assert_approx_eq!(0.05f32, -0.04f32, epsilon = 0.1f32);
+    assert_approx_ne!(0.05f32, -0.06f32, epsilon = 0.1f32);
+}
+
+
+#[test]
+fn relative_across_the_sign_boundary() {
+    assert!(0f32.relative_eq(&-0f32, 0f32, 0f32));
+    assert!(100f32.relative_eq(&101f32, 0f32, 0.01f32));
+    assert!(!100f32.relative_eq(&102f32, 0f32, 0.01f32));
+    assert!((-100f32).relative_eq(&-101f32, 0f32, 0.01f32));
+
+    // Opposite signs are twice the magnitude apart, only the epsilon can bring them together
+    assert!(!1e-3f32.relative_eq(&-1e-3f32, 0f32, 0.5f32));
+    assert!(1e-3f32.relative_eq(&-1e-3f32, 2e-3f32, 0f32));
+    assert!(1e-3f32.relative_eq(&-1e-3f32, 0f32, 2f32));
+}
+
+
+#[test]
+fn ulps_across_the_sign_boundary() {
+    let next = |value: f32, ulps: u32| f32::from_bits(value.to_bits() + ulps);
+    assert!(1f32.ulps_eq(&next(1f32, 4u32), 0f32, 4u32));
+    assert!(!1f32.ulps_eq(&next(1f32, 5u32), 0f32, 4u32));
+    assert!((-1f32).ulps_eq(&next(-1f32, 4u32), 0f32, 4u32));
+    assert_approx_eq!(1f32, 1f32 + f32::EPSILON, max_ulps = 1u32);
+
+    // The smallest subnormals of opposite signs are far apart in bits
+    let tiny = f32::from_bits(1u32);
+    assert!(0f32.ulps_eq(&-0f32, 0f32, 0u32));
+    assert!(!tiny.ulps_eq(&-tiny, 0f32, u32::MAX));
+    assert!(tiny.ulps_eq(&-tiny, f32::EPSILON, 0u32));
+    assert!(tiny.ulps_eq(&0f32, 0f32, 1u32));
+    assert!(!tiny.ulps_eq(&-0f32, 0f32, 1u32));
+}
+
+
+#[test]
+fn nan_is_never_equal() {
+    for nan in [f32::NAN, -f32::NAN] {
+        for other in [f32::NAN, 0f32, 1f32, f32::INFINITY] {
+            assert!(!nan.abs_diff_eq(&other, f32::MAX));
+            assert!(!nan.relative_eq(&other, f32::MAX, f32::MAX));
+            assert!(!nan.approx_eq(&other));
+            assert!(!other.relative_eq(&nan, f32::MAX, f32::MAX));
+        } // for ..
+        assert!(!nan.ulps_eq(&nan, f32::MAX, 0u32));
+        assert!(!nan.ulps_eq(&f32::INFINITY, 0f32, u32::MAX));
+    } // for ..
+}
+
+
+#[test]
+fn infinities() {
+    assert!(f32::INFINITY.approx_eq(&f32::INFINITY));
+    assert!(f32::NEG_INFINITY.approx_eq(&f32::NEG_INFINITY));
+    assert!(!f32::INFINITY.approx_eq(&f32::NEG_INFINITY));
+    assert!(!f32::INFINITY.relative_eq(&f32::MAX, f32::MAX, 1f32));
+    assert!(!f32::MAX.relative_eq(&f32::INFINITY, f32::MAX, 1f32));
+    assert!(!f32::INFINITY.abs_diff_eq(&f32::MAX, f32::MAX));
+    assert!(f32::MAX.relative_eq(&f32::MAX, 0f32, 0f32));
+}
+
+
+#[test]
+fn integers_count_units() {
+    assert!(i32::MIN.abs_diff_eq(&i32::MAX, u32::MAX));
+    assert!(!i32::MIN.abs_diff_eq(&i32::MAX, u32::MAX - 1u32));
+    assert!((-2i8).abs_diff_eq(&2i8, 4u8));
+    assert!(!(-2i8).relative_eq(&2i8, 3u8, u8::MAX));
+    assert!(250u8.ulps_eq(&255u8, 0u8, 5u32));
+    assert_approx_eq!(7u16, 7u16);
+    assert_approx_ne!(7u16, 8u16);
+}
+
+
+#[test]
+fn angles_wrap() {
+    assert!(Angle(255u8).abs_diff_eq(&Angle(0u8), 1u8));
+    assert!(Angle(0u8).abs_diff_eq(&Angle(255u8), 1u8));
+    assert!(Angle(250u8).abs_diff_eq(&Angle(4u8), 10u8));
+    assert!(!Angle(250u8).abs_diff_eq(&Angle(4u8), 9u8));
+    assert!(Angle(0u8).abs_diff_eq(&Angle(128u8), 128u8));
+    assert!(!Angle(0u8).abs_diff_eq(&Angle(128u8), 127u8));
+    assert!(Angle(1u8).ulps_eq(&Angle(254u8), 0u8, 3u32));
+    assert_approx_ne!(Angle(0u8), Angle(1u8));
+}
+
+
+#[test]
+fn vectors_compare_each_component() {
+    let a = Vec3(1f32, -0f32, 100f32);
+    assert_approx_eq!(a, Vec3(1f32 + f32::EPSILON, 0f32, 100f32 + 1e-5f32));
+    assert_approx_ne!(a, Vec3(1f32, 0f32, 101f32));
+    assert_approx_eq!(a, Vec3(1.05f32, 0.05f32, 99.95f32), epsilon = 0.1f32);
+    assert_approx_ne!(a, Vec3(1.05f32, 0.05f32, 99.8f32), epsilon = 0.1f32);
+    assert_approx_eq!(Vec4(100f32, -100f32, 0f32, 1f32), Vec4(101f32, -101f32, 0f32, 1f32), epsilon = 0f32, max_relative = 0.01f32);
+
+    assert!(!Vec3(f32::NAN, 0f32, 0f32).approx_eq(&Vec3(f32::NAN, 0f32, 0f32)));
+    assert!(!Vec3(0f32, 0f32, f32::INFINITY).approx_eq(&Vec3(0f32, 0f32, f32::MAX)));
+    assert!(Vec3(1i32, -1i32, 0i32).abs_diff_eq(&Vec3(2i32, -2i32, 1i32), 1u32));
+}
+
+
+#[test]
+fn matrices_compare_each_component() {
+    let identity = Mat3(Vec3(1f32, 0f32, 0f32), Vec3(0f32, 1f32, 0f32), Vec3(0f32, 0f32, 1f32));
+    let mut near = identity;
+    near.1.1 += f32::EPSILON;
+    assert_approx_eq!(identity, near);
+    assert_approx_eq!(identity, near, max_ulps = 1u32);
+
+    let mut far = identity;
+    far.2.0 = 1e-3f32;
+    assert_approx_ne!(identity, far);
+    assert_approx_eq!(identity, far, epsilon = 1e-3f32);
+    assert_approx_ne!(identity, far, max_ulps = 4u32);
+}
@@ -0,0 +1,119 @@
+use lofi_maths::vectors::{Vec2, Vec3, Vec4};
+use lofi_maths::matrices::{Mat3, Mat4};
+use lofi_maths::layout::{Block, BlockLayout, GpuLayout, OffsetMismatch, LayoutMismatch};
+
+
+fn float_at(bytes: &[u8], offset: usize) -> f32 {
+    f32::from_ne_bytes(bytes[offset..offset + 4usize].try_into().unwrap())
+}
+
+
+#[test]
+fn scalar_packs_after_vec3() {
+    for layout in [BlockLayout::Std140, BlockLayout::Std430] {
+        let mut block = Block::new(layout);
+        assert_eq!(block.push(&Vec3(1f32, 2f32, 3f32)), 0usize);
+        assert_eq!(block.push(&4f32), 12usize);
+        assert_eq!(block.push(&Vec2(5f32, 6f32)), 16usize);
+        assert_eq!(block.push(&Vec4(7f32, 8f32, 9f32, 10f32)), 32usize);
+        assert_eq!(block.size(), 48usize);
+
+        let bytes = block.into_bytes();
+        assert_eq!(float_at(&bytes, 12usize), 4f32);
+        assert_eq!(float_at(&bytes, 20usize), 6f32);
+    } // for ..
+}
+
+
+#[test]
+fn mat3_columns_are_padded_to_16_bytes() {
+    let mat3 = Mat3(Vec3(1f32, 2f32, 3f32), Vec3(4f32, 5f32, 6f32), Vec3(7f32, 8f32, 9f32));
+    for layout in [BlockLayout::Std140, BlockLayout::Std430] {
+        let mut block = Block::new(layout);
+        block.push(&1u32);
+        assert_eq!(block.push(&mat3), 16usize);
+        assert_eq!(block.push(&2u32), 64usize);
+
+        let bytes = block.into_bytes();
+        assert_eq!([float_at(&bytes, 16usize), float_at(&bytes, 32usize), float_at(&bytes, 48usize)], [1f32, 4f32, 7f32]);
+        assert_eq!([float_at(&bytes, 40usize), float_at(&bytes, 56usize)], [6f32, 9f32]);
+        assert_eq!((&bytes[28usize..32usize], &bytes[60usize..64usize]), (&[0u8; 4usize][..], &[0u8; 4usize][..]));
+    } // for ..
+    assert_eq!(Mat4::<f32>::size(BlockLayout::Std140), 64usize);
+}
+
+
+#[test]
+fn array_stride_depends_on_layout() {
+    assert_eq!(f32::array_stride(BlockLayout::Std140), 16usize);
+    assert_eq!(f32::array_stride(BlockLayout::Std430), 4usize);
+    assert_eq!(Vec2::<f32>::array_stride(BlockLayout::Std140), 16usize);
+    assert_eq!(Vec2::<f32>::array_stride(BlockLayout::Std430), 8usize);
+    assert_eq!(Vec3::<f32>::array_stride(BlockLayout::Std430), 16usize);
+
+    let mut std140 = Block::new(BlockLayout::Std140);
+    std140.push(&1f32);
+    assert_eq!(std140.push(&[2f32, 3f32, 4f32]), 16usize);
+    assert_eq!(std140.push(&5f32), 64usize);
+    let bytes = std140.into_bytes();
+    assert_eq!([float_at(&bytes, 16usize), float_at(&bytes, 32usize), float_at(&bytes, 48usize)], [2f32, 3f32, 4f32]);
+
+    let mut std430 = Block::new(BlockLayout::Std430);
+    std430.push(&1f32);
+    assert_eq!(std430.push(&[2f32, 3f32, 4f32]), 4usize);
+    assert_eq!(std430.push(&5f32), 16usize);
+    assert_eq!(std430.push_array(&[Vec2(6f32, 7f32), Vec2(8f32, 9f32)]), 24usize);
+    let bytes = std430.into_bytes();
+    assert_eq!([float_at(&bytes, 8usize), float_at(&bytes, 32usize)], [3f32, 8f32]);
+}
+
+
+#[test]
+fn nested_blocks_are_aligned() {
+    let mut inner = Block::new(BlockLayout::Std140);
+    inner.push(&1f32);
+    assert_eq!((inner.alignment(), inner.size()), (16usize, 16usize));
+
+    let mut outer = Block::new(BlockLayout::Std140);
+    outer.push(&2f32);
+    assert_eq!(outer.push_block(&inner), Ok(16usize));
+    assert_eq!(outer.push(&3f32), 32usize);
+
+    let mut inner = Block::new(BlockLayout::Std430);
+    inner.push(&1f32);
+    assert_eq!((inner.alignment(), inner.size()), (4usize, 4usize));
+
+    let mut outer = Block::new(BlockLayout::Std430);
+    outer.push(&2f32);
+    assert_eq!(outer.push_block(&inner), Ok(4usize));
+
+    let mut inner = Block::new(BlockLayout::Std430);
+    inner.push(&Vec3(1f32, 2f32, 3f32));
+    assert_eq!(outer.push_block(&inner), Ok(16usize));
+    assert_eq!(outer.size(), 32usize);
+}
+
+
+#[test]
+fn nested_blocks_keep_their_layout() {
+    let mut std140 = Block::new(BlockLayout::Std140);
+    let mut std430 = Block::new(BlockLayout::Std430);
+    std140.push(&[1f32, 2f32]);
+    std430.push(&[1f32, 2f32]);
+
+    // The arrays inside have strides of 16 and 4 bytes, neither fits the other block
+    assert_eq!(std140.push_block(&std430), Err(LayoutMismatch { expected: BlockLayout::Std140, found: BlockLayout::Std430 }));
+    assert_eq!(std430.push_block(&std140), Err(LayoutMismatch { expected: BlockLayout::Std430, found: BlockLayout::Std140 }));
+    assert_eq!((std140.size(), std430.size()), (32usize, 8usize));
+    assert_eq!(LayoutMismatch { expected: BlockLayout::Std140, found: BlockLayout::Std430 }.to_string(), "nested block laid out with Std430 inside a Std140 block");
+}
+
+
+#[test]
+fn push_at_reports_offset_mismatches() {
+    let mut block = Block::new(BlockLayout::Std140);
+    assert_eq!(block.push_at(0usize, &Vec2(1f32, 2f32)), Ok(0usize));
+    assert_eq!(block.push_at(8usize, &Vec3(1f32, 2f32, 3f32)), Err(OffsetMismatch { expected: 8usize, found: 16usize }));
+    assert_eq!(block.offset_of::<Vec3<f32>>(), 16usize);
+    assert_eq!(block.push_at(16usize, &Vec3(1f32, 2f32, 3f32)), Ok(16usize));
+}
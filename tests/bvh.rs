@@ -0,0 +1,114 @@
+use lofi_maths::vectors::{Vec2, Vec3};
+use lofi_maths::geometry::{Ray, Ball, Aabb};
+use lofi_maths::intersection::ray_sphere;
+use lofi_maths::bvh::{Bvh, Split};
+
+
+/// Linear congruential generator, returns values in `0..1`
+fn random(state: &mut u32) -> f32 {
+    *state = state.wrapping_mul(1664525u32).wrapping_add(1013904223u32);
+    (*state >> 8u32) as f32 / (1u32 << 24u32) as f32
+}
+
+
+fn random_point(state: &mut u32, size: f32) -> Vec3<f32> {
+    Vec3(random(state) * size, random(state) * size, random(state) * size)
+}
+
+
+fn random_boxes(state: &mut u32, count: usize) -> Vec<Aabb<Vec3<f32>>> {
+    (0usize..count).map(|_| {
+        let min = random_point(state, 60f32);
+        Aabb::new(min, min + random_point(state, 6f32) + Vec3(2f32, 2f32, 2f32))
+    }).collect()
+}
+
+
+/// Ball inside each box, so that the tree only skips items the ray cannot hit
+fn inner_ball(aabb: &Aabb<Vec3<f32>>) -> Ball<Vec3<f32>> {
+    let extent = aabb.max - aabb.min;
+    Ball::new((aabb.min + aabb.max) / 2f32, extent.0.min(extent.1).min(extent.2) / 2f32)
+}
+
+
+fn collect(query: impl FnOnce(&mut dyn FnMut(usize))) -> Vec<usize> {
+    let mut found = Vec::new();
+    query(&mut |item| found.push(item));
+    found.sort_unstable();
+    found
+}
+
+
+/// Checks every query against a linear scan of the boxes, returns how many rays hit
+fn assert_matches_scan(bvh: &Bvh<Vec3<f32>>, boxes: &[Aabb<Vec3<f32>>], state: &mut u32) -> usize {
+    let mut hits = 0usize;
+    let overlaps = |a: &Aabb<Vec3<f32>>, b: &Aabb<Vec3<f32>>| a.min.0 <= b.max.0 && b.min.0 <= a.max.0 && a.min.1 <= b.max.1 && b.min.1 <= a.max.1 && a.min.2 <= b.max.2 && b.min.2 <= a.max.2;
+
+    for _ in 0usize..200usize {
+        let min    = random_point(state, 70f32) - Vec3(5f32, 5f32, 5f32);
+        let aabb   = Aabb::new(min, min + random_point(state, 20f32));
+        let scan   = (0usize..boxes.len()).filter(|&item| overlaps(&boxes[item], &aabb)).collect::<Vec<_>>();
+        assert_eq!(collect(|found| bvh.query_aabb(&aabb, found)), scan);
+
+        let sphere = Ball::new(random_point(state, 70f32), random(state) * 15f32);
+        let scan   = (0usize..boxes.len()).filter(|&item| boxes[item].squared_distance(sphere.center) <= sphere.radius * sphere.radius).collect::<Vec<_>>();
+        assert_eq!(collect(|found| bvh.query_sphere(&sphere, found)), scan);
+
+        let ray    = Ray::new(random_point(state, 60f32), random_point(state, 2f32) - Vec3(1f32, 1f32, 1f32));
+        let hit    = |item: usize| ray_sphere(&ray, &inner_ball(&boxes[item])).map(|hit| hit.distance);
+        let scan   = (0usize..boxes.len()).filter_map(|item| hit(item).map(|time| (item, time))).reduce(|a, b| if b.1 < a.1 { b } else { a });
+        assert_eq!(bvh.ray_cast(&ray, hit), scan);
+        hits += scan.is_some() as usize;
+    } // for ..
+    hits
+}
+
+
+#[test]
+fn queries_match_a_linear_scan() {
+    for split in [Split::Median, Split::SurfaceArea] {
+        let mut state = 7u32;
+        for count in [0usize, 1usize, 3usize, 4usize, 5usize, 17usize, 300usize] {
+            let boxes = random_boxes(&mut state, count);
+            let bvh   = Bvh::build(&boxes, split);
+            assert_eq!(bvh.bounds().is_some(), count > 0usize, "{split:?}");
+            let hits = assert_matches_scan(&bvh, &boxes, &mut state);
+            assert!(count < 300usize || hits > 40usize, "{split:?}");
+        } // for ..
+    } // for ..
+}
+
+
+#[test]
+fn queries_match_a_linear_scan_after_refit() {
+    for split in [Split::Median, Split::SurfaceArea] {
+        let mut state = 11u32;
+        let mut boxes = random_boxes(&mut state, 300usize);
+        let mut bvh   = Bvh::build(&boxes, split);
+
+        for _ in 0usize..3usize {
+            for aabb in &mut boxes {
+                let offset = random_point(&mut state, 40f32) - Vec3(20f32, 20f32, 20f32);
+                *aabb = Aabb::new(aabb.min + offset, aabb.max + offset);
+            } // for ..
+            bvh.refit(&boxes);
+
+            let bounds = bvh.bounds().unwrap();
+            assert!(boxes.iter().all(|aabb| bounds.contains(aabb.min) && bounds.contains(aabb.max)), "{split:?}");
+            assert!(assert_matches_scan(&bvh, &boxes, &mut state) > 40usize, "{split:?}");
+        } // for ..
+    } // for ..
+}
+
+
+#[test]
+fn identical_boxes_stay_in_one_leaf() {
+    let boxes = vec![Aabb::new(Vec2(0i32, 0i32), Vec2(2i32, 2i32)); 20usize];
+    for split in [Split::Median, Split::SurfaceArea] {
+        let bvh = Bvh::build(&boxes, split);
+        assert_eq!(bvh.bounds(), Some(boxes[0usize]));
+        assert_eq!(collect(|found| bvh.query_aabb(&Aabb::new(Vec2(2i32, 2i32), Vec2(3i32, 3i32)), found)), (0usize..20usize).collect::<Vec<_>>());
+        assert_eq!(collect(|found| bvh.query_aabb(&Aabb::new(Vec2(3i32, 0i32), Vec2(4i32, 2i32)), found)), Vec::<usize>::new());
+        assert_eq!(collect(|found| bvh.query_sphere(&Ball::new(Vec2(4i32, 1i32), 2i32), found)).len(), 20usize);
+    } // for ..
+}
@@ -0,0 +1,121 @@
+use lofi_maths::vectors::{Vec2, Vec4};
+use lofi_maths::matrices::DepthRange;
+use lofi_maths::geometry::Aabb2;
+use lofi_maths::clipping::{ClipVolume, Outcode, clip_segment};
+
+
+/// Clip depth of a point on the near plane
+fn near(depth: DepthRange, w: f32) -> f32 {
+    match depth {
+        DepthRange::NegativeOneToOne => -w,
+        DepthRange::ZeroToOne        => 0f32,
+    } // match ..
+}
+
+
+#[test]
+fn triangles_crossing_the_near_plane() {
+    for depth in [DepthRange::NegativeOneToOne, DepthRange::ZeroToOne] {
+        let volume   = ClipVolume::new(depth, None);
+        let triangle = [Vec4(-0.5f32, -0.5f32, 0.5f32, 1f32), Vec4(0.5f32, -0.5f32, 0.5f32, 1f32), Vec4(0f32, 0.5f32, near(depth, 1f32) - 1f32, 1f32)];
+        assert_eq!(volume.outcode(triangle[2usize]), Outcode::NEAR);
+
+        // The vertex behind the plane gives way to two on it
+        let clipped = volume.clip_polygon(&triangle);
+        assert_eq!(clipped.len(), 4usize, "{depth:?}");
+        assert_eq!(clipped.iter().filter(|vertex| triangle.contains(vertex)).count(), 2usize);
+        for vertex in &clipped {
+            assert!(volume.distances(*vertex).iter().all(|&distance| distance >= -1e-6f32), "{depth:?} {vertex:?}");
+            if !triangle.contains(vertex) { assert!((vertex.2 - near(depth, vertex.3)).abs() < 1e-6f32, "{depth:?} {vertex:?}") }
+        } // for ..
+
+        // The new vertices sit where the edges cross the plane
+        let t = (0.5f32 - near(depth, 1f32)) / (0.5f32 - (near(depth, 1f32) - 1f32));
+        assert!(clipped.iter().any(|vertex| (vertex.0 - (-0.5f32 + 0.5f32 * t)).abs() < 1e-5f32 && (vertex.1 - (-0.5f32 + t)).abs() < 1e-5f32), "{depth:?}");
+
+        // Lines too
+        let (start, end) = volume.clip_line(triangle[0usize], triangle[2usize]).unwrap();
+        assert_eq!(start, triangle[0usize]);
+        assert!((end.2 - near(depth, end.3)).abs() < 1e-6f32);
+        assert_eq!(volume.clip_line(triangle[2usize], triangle[2usize] + Vec4(1f32, 0f32, 0f32, 0f32)), None);
+
+        // Behind the eye entirely
+        let behind = triangle.map(|vertex| Vec4(vertex.0, vertex.1, -2f32, 1f32));
+        assert!(volume.clip_polygon(&behind).is_empty());
+    } // for ..
+}
+
+
+#[test]
+fn guard_band_lets_triangles_through() {
+    // Crossing the right edge of the screen but not of a band twice as wide
+    let triangle = [Vec4(0f32, 0f32, 0.5f32, 1f32), Vec4(1.5f32, 0f32, 0.5f32, 1f32), Vec4(0f32, 1.5f32, 0.5f32, 1f32)];
+    for depth in [DepthRange::NegativeOneToOne, DepthRange::ZeroToOne] {
+        let banded = ClipVolume::new(depth, Some(2f32));
+        assert_eq!(banded.outcode(triangle[1usize]), Outcode::INSIDE);
+        assert_eq!(banded.clip_polygon(&triangle), triangle.to_vec());
+
+        let tight   = ClipVolume::new(depth, None);
+        let clipped = tight.clip_polygon(&triangle);
+        assert_eq!(tight.outcode(triangle[1usize]), Outcode::RIGHT);
+        assert_eq!(clipped.len(), 5usize);
+        assert!(clipped.iter().all(|vertex| vertex.0 <= 1f32 + 1e-6f32 && vertex.1 <= 1f32 + 1e-6f32));
+
+        // Past the band, it clips on the band
+        let wide    = triangle.map(|vertex| Vec4(vertex.0 * 4f32, vertex.1, vertex.2, vertex.3));
+        let clipped = banded.clip_polygon(&wide);
+        assert_eq!(clipped.len(), 4usize);
+        assert!(clipped.iter().all(|vertex| vertex.0 <= 2f32 + 1e-6f32));
+        assert!(clipped.iter().any(|vertex| (vertex.0 - 2f32).abs() < 1e-6f32));
+    } // for ..
+
+    let volume = ClipVolume::new(DepthRange::ZeroToOne, Some(2f32));
+    assert_eq!(volume.outcode(Vec4(-3f32, 0f32, 0.5f32, 1f32)), Outcode::LEFT);
+    assert_eq!(volume.outcode(Vec4(0f32, 5f32, 1.5f32, 2f32)), Outcode::TOP);
+    assert_eq!(volume.outcode(Vec4(0f32, 0f32, 3f32, 2f32)), Outcode::FAR);
+}
+
+
+#[test]
+fn integer_segments() {
+    let rect = Aabb2::new(Vec2(0i32, 0i32), Vec2(10i32, 10i32));
+    assert_eq!(clip_segment(Vec2(1i32, 2i32), Vec2(9i32, 3i32), &rect), Some((Vec2(1i32, 2i32), Vec2(9i32, 3i32))));
+    assert_eq!(clip_segment(Vec2(-5i32, 5i32), Vec2(15i32, 5i32), &rect), Some((Vec2(0i32, 5i32), Vec2(10i32, 5i32))));
+    assert_eq!(clip_segment(Vec2(-5i32, -5i32), Vec2(15i32, 15i32), &rect), Some((Vec2(0i32, 0i32), Vec2(10i32, 10i32))));
+    assert_eq!(clip_segment(Vec2(-5i32, 11i32), Vec2(15i32, 11i32), &rect), None);
+    assert_eq!(clip_segment(Vec2(-5i32, 20i32), Vec2(20i32, -5i32), &rect), Some((Vec2(5i32, 10i32), Vec2(10i32, 5i32))));
+    assert_eq!(clip_segment(Vec2(-10i32, 5i32), Vec2(5i32, 20i32), &rect), None);
+    assert_eq!(clip_segment(Vec2(-5i32, 15i32), Vec2(5i32, 5i32), &rect), Some((Vec2(0i32, 10i32), Vec2(5i32, 5i32))));
+
+    // The crossing with the left side lies 0.6 above the rectangle, rounding it up would bend
+    // the line through it and move the crossing with the top from 1 to 1.5
+    let (start, end) = (Vec2(-4i32, 13i32), Vec2(6i32, 7i32));
+    assert_eq!(clip_segment(start, end, &rect), Some((Vec2(1i32, 10i32), end)));
+    assert_eq!(clip_segment(end, start, &rect), Some((end, Vec2(1i32, 10i32))));
+}
+
+
+#[test]
+fn rounded_crossings_stay_inside() {
+    let mut state = 9u32;
+    let mut random = |range: i32| { state = state.wrapping_mul(1664525u32).wrapping_add(1013904223u32); ((state >> 8u32) % (2u32 * range as u32)) as i32 - range };
+
+    for _ in 0usize..20000usize {
+        let min  = Vec2(random(20i32), random(20i32));
+        let rect = Aabb2::new(min, min + Vec2(random(20i32).abs(), random(20i32).abs()));
+        let (start, end) = (Vec2(random(60i32), random(60i32)), Vec2(random(60i32), random(60i32)));
+
+        let exact = clip_segment(Vec2(start.0 as f32, start.1 as f32), Vec2(end.0 as f32, end.1 as f32), &Aabb2::new(Vec2(rect.min.0 as f32, rect.min.1 as f32), Vec2(rect.max.0 as f32, rect.max.1 as f32)));
+        let Some((first, last)) = clip_segment(start, end, &rect) else {
+            assert_eq!(exact, None, "{start:?} {end:?} {rect:?}");
+            continue
+        }; // let ..
+
+        // Both ends lie inside, within half a pixel along each axis of the float crossings
+        let Some((exact_first, exact_last)) = exact else { panic!("{start:?} {end:?} {rect:?} should miss") };
+        for (point, exact) in [(first, exact_first), (last, exact_last)] {
+            assert!(Outcode::of_point(point, &rect).is_inside(), "{point:?} left {rect:?} for {start:?} {end:?}");
+            assert!((point.0 as f32 - exact.0).abs() <= 0.5f32 && (point.1 as f32 - exact.1).abs() <= 0.5f32, "{point:?} {exact:?}");
+        } // for ..
+    } // for ..
+}
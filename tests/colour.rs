@@ -0,0 +1,205 @@
+use lofi_maths::vectors::{Vec3, Vec4};
+use lofi_maths::matrices::{Mat3, Mat4, Transform};
+use lofi_maths::colour::{Rgb, Rgba, Hsv, Hsl, BlendMode, FixedColourMat};
+
+
+fn assert_close(actual: Rgb, expected: Rgb, tolerance: u8) {
+    for (actual, expected) in [(actual.0, expected.0), (actual.1, expected.1), (actual.2, expected.2)] {
+        assert!(actual.abs_diff(expected) <= tolerance, "{actual:?} is not {expected:?}");
+    } // for ..
+}
+
+
+/// Linear congruential generator, returns a random colour
+fn random_colour(state: &mut u32) -> Rgb {
+    *state = state.wrapping_mul(1664525u32).wrapping_add(1013904223u32);
+    let [_, r, g, b] = state.to_be_bytes();
+    Vec3(r, g, b)
+}
+
+
+/// Applies a float colour matrix to a byte colour, rounding and clamping like `FixedColourMat`
+fn apply_float(matrix: &Mat4<f32>, colour: Rgb) -> Rgb {
+    let channel = |value: f32| (value * 255f32).round().clamp(0f32, 255f32) as u8;
+    let result  = matrix.transform_point(Vec3(colour.0 as f32, colour.1 as f32, colour.2 as f32) / 255f32);
+    Vec3(channel(result.0), channel(result.1), channel(result.2))
+}
+
+
+#[test]
+fn linear_round_trip() {
+    for value in 0u8..=255u8 {
+        let colour = Vec3(value, value / 2u8, 255u8 - value);
+        assert_eq!(Rgb::from_linear(colour.to_linear()), colour);
+        assert_eq!(Rgba::from_linear(Vec4(colour.0, colour.1, colour.2, value).to_linear()), Vec4(colour.0, colour.1, colour.2, value));
+    } // for ..
+
+    assert_eq!(Rgb::from_linear(Vec3(-1f32, 2f32, 0.5f32)), Vec3(0u8, 255u8, 188u8));
+    assert_eq!(Vec3(0u8, 255u8, 0u8).to_linear(), Vec3(0f32, 1f32, 0f32));
+}
+
+
+#[test]
+fn packed_formats() {
+    let (red, white) = (Vec3(255u8, 0u8, 0u8), Vec3(255u8, 255u8, 255u8));
+    assert_eq!(red.to_u32(), 0x00FF0000u32);
+    assert_eq!(Rgb::from_u32(0xAB123456u32), Vec3(0x12u8, 0x34u8, 0x56u8));
+    assert_eq!(Rgba::from_u32(0x12345678u32).to_u32(), 0x12345678u32);
+
+    assert_eq!(Vec3(0u8, 0u8, 0u8).to_rgb565(), 0x0000u16);
+    assert_eq!(white.to_rgb565(), 0xFFFFu16);
+    assert_eq!(red.to_rgb565(), 0xF800u16);
+    assert_eq!(Rgb::from_rgb565(0xFFFFu16), white);
+    assert_eq!(Rgb::from_rgb565(0x07E0u16), Vec3(0u8, 255u8, 0u8));
+    assert_eq!(Rgb::from_rgb565(0x001Fu16), Vec3(0u8, 0u8, 255u8));
+
+    assert_eq!(Vec4(0u8, 0u8, 0u8, 0u8).to_rgba4444(), 0x0000u16);
+    assert_eq!(Vec4(255u8, 255u8, 255u8, 255u8).to_rgba4444(), 0xFFFFu16);
+    assert_eq!(Vec4(255u8, 0u8, 0u8, 255u8).to_rgba4444(), 0xF00Fu16);
+    assert_eq!(Rgba::from_rgba4444(0xFFFFu16), Vec4(255u8, 255u8, 255u8, 255u8));
+    assert_eq!(Rgba::from_rgba4444(0x0F00u16), Vec4(0u8, 255u8, 0u8, 0u8));
+
+    // Every packed value comes back, and bytes move by less than a step
+    for packed in 0u16..=u16::MAX {
+        assert_eq!(Rgb::from_rgb565(packed).to_rgb565(), packed);
+        assert_eq!(Rgba::from_rgba4444(packed).to_rgba4444(), packed);
+    } // for ..
+    for value in 0u8..=255u8 {
+        assert_close(Rgb::from_rgb565(Vec3(value, value, value).to_rgb565()), Vec3(value, value, value), 4u8);
+        assert!(Rgba::from_rgba4444(Vec4(value, value, value, value).to_rgba4444()).3.abs_diff(value) <= 8u8);
+    } // for ..
+}
+
+
+#[test]
+fn premultiplied_alpha() {
+    assert_eq!(Vec4(200u8, 100u8, 50u8, 255u8).premultiply(), Vec4(200u8, 100u8, 50u8, 255u8));
+    assert_eq!(Vec4(200u8, 100u8, 50u8, 0u8).premultiply(), Vec4(0u8, 0u8, 0u8, 0u8));
+    assert_eq!(Vec4(255u8, 128u8, 0u8, 128u8).premultiply(), Vec4(128u8, 64u8, 0u8, 128u8));
+    assert_eq!(Vec4(9u8, 9u8, 9u8, 0u8).unpremultiply(), Vec4(0u8, 0u8, 0u8, 0u8));
+
+    for alpha in 1u8..=255u8 {
+        // Premultiplied channels never exceed alpha and come back exactly
+        for value in 0u8..=alpha {
+            let premultiplied = Vec4(value, alpha - value, value / 2u8, alpha);
+            assert_eq!(premultiplied.unpremultiply().premultiply(), premultiplied);
+        } // for ..
+
+        // The other way loses what the rounding dropped, at most half a step of alpha
+        for value in 0u8..=255u8 {
+            let straight = Vec4(value, 255u8 - value, 0u8, alpha).premultiply().unpremultiply();
+            let slack    = (128u32 / alpha as u32 + 1u32) as u8;
+            assert!(straight.0.abs_diff(value) <= slack && straight.1.abs_diff(255u8 - value) <= slack, "{value} {alpha} {straight:?}");
+        } // for ..
+    } // for ..
+}
+
+
+#[test]
+fn blend_modes() {
+    let modes       = [BlendMode::Normal, BlendMode::Add, BlendMode::Multiply, BlendMode::Screen];
+    let clear       = Vec4(0u8, 0u8, 0u8, 0u8);
+    let source      = Vec4(200u8, 100u8, 0u8, 255u8);
+    let destination = Vec4(100u8, 100u8, 255u8, 255u8);
+    let half        = Vec4(200u8, 100u8, 0u8, 128u8).premultiply();
+
+    // Nothing over a colour, or a colour over nothing, leaves the colour in every mode
+    for mode in modes {
+        assert_eq!(clear.blend(destination, mode), destination, "{mode:?}");
+        assert_eq!(source.blend(clear, mode), source, "{mode:?}");
+        assert_eq!(half.blend(clear, mode), half, "{mode:?}");
+        assert_eq!(clear.blend(clear, mode), clear, "{mode:?}");
+        assert_eq!(half.blend(destination, mode).3, 255u8, "{mode:?}");
+        assert_eq!(half.blend(half, mode).3, 192u8, "{mode:?}");
+    } // for ..
+
+    assert_eq!(source.blend(destination, BlendMode::Normal), source);
+    assert_eq!(source.blend(destination, BlendMode::Add), Vec4(255u8, 200u8, 255u8, 255u8));
+    assert_eq!(source.blend(destination, BlendMode::Multiply), Vec4(78u8, 39u8, 0u8, 255u8));
+    assert_eq!(source.blend(destination, BlendMode::Screen), Vec4(222u8, 161u8, 255u8, 255u8));
+
+    // Half covered, the destination shows through for half its colour
+    assert_eq!(half.blend(destination, BlendMode::Normal), Vec4(150u8, 100u8, 127u8, 255u8));
+    assert_eq!(half.blend(destination, BlendMode::Add), Vec4(200u8, 150u8, 255u8, 255u8));
+    assert_eq!(half.blend(destination, BlendMode::Multiply), Vec4(89u8, 70u8, 127u8, 255u8));
+    assert_eq!(half.blend(destination, BlendMode::Screen), Vec4(161u8, 130u8, 255u8, 255u8));
+}
+
+
+#[test]
+fn hsv_and_hsl_round_trips() {
+    let primaries = [
+        Vec3(255u8, 0u8, 0u8), Vec3(0u8, 255u8, 0u8), Vec3(0u8, 0u8, 255u8),
+        Vec3(0u8, 255u8, 255u8), Vec3(255u8, 0u8, 255u8), Vec3(255u8, 255u8, 0u8),
+        Vec3(0u8, 0u8, 0u8), Vec3(128u8, 128u8, 128u8), Vec3(255u8, 255u8, 255u8),
+    ]; // let ..
+    for rgb in primaries {
+        let (hsv, hsl) = (Hsv::from(rgb), Hsl::from(rgb));
+        assert_eq!(Rgb::from(hsv), rgb, "{hsv:?}");
+        assert_eq!(Rgb::from(hsl), rgb, "{hsl:?}");
+    } // for ..
+
+    let red = Hsv::from(Vec3(255u8, 0u8, 0u8));
+    assert_eq!((red.hue.0, red.saturation, red.value), (0u8, 1f32, 1f32));
+    let grey = Hsl::from(Vec3(128u8, 128u8, 128u8));
+    assert_eq!((grey.hue.0, grey.saturation), (0u8, 0f32));
+
+    // Off the primaries the byte hues move channels by a few steps at most
+    for rgb in [Vec3(255u8, 128u8, 0u8), Vec3(30u8, 60u8, 90u8), Vec3(200u8, 10u8, 120u8)] {
+        assert_close(Rgb::from(Hsv::from(rgb)), rgb, 3u8);
+        assert_close(Rgb::from(Hsl::from(rgb)), rgb, 3u8);
+    } // for ..
+}
+
+
+#[test]
+fn fixed_matrices_match_the_float_ones() {
+    let matrices = [
+        Mat4::new_rgb_to_ycbcr_mat(),
+        Mat4::new_ycbcr_to_rgb_mat(),
+        Mat4::new_colour_mat(Mat3::new_sepia_mat(), Vec3(0f32, 0f32, 0f32)),
+        Mat4::new_brightness_contrast_mat(0.1f32, 1.5f32),
+    ]; // let ..
+    let corners = (0u32..8u32).map(|bits| Vec3(((bits & 1u32) * 255u32) as u8, ((bits >> 1u32 & 1u32) * 255u32) as u8, ((bits >> 2u32) * 255u32) as u8));
+
+    let mut state = 21u32;
+    let colours = corners.chain((0usize..2000usize).map(|_| random_colour(&mut state))).collect::<Vec<_>>();
+    for matrix in &matrices {
+        let fixed = FixedColourMat::from(*matrix);
+        for &colour in &colours {
+            let (expected, actual) = (apply_float(matrix, colour), fixed.apply(colour));
+            assert_close(actual, expected, 1u8);
+        } // for ..
+    } // for ..
+
+    assert_eq!(FixedColourMat::from(Mat3::new_sepia_mat()), FixedColourMat::from(matrices[2usize]));
+    assert_eq!(FixedColourMat::from(Mat3::new_sepia_mat()).apply(Vec3(255u8, 255u8, 255u8)), Vec3(255u8, 255u8, 239u8));
+}
+
+
+#[test]
+fn ycbcr_round_trip() {
+    let (forward, backward) = (FixedColourMat::from(Mat4::new_rgb_to_ycbcr_mat()), FixedColourMat::from(Mat4::new_ycbcr_to_rgb_mat()));
+    assert_eq!(forward.apply(Vec3(255u8, 255u8, 255u8)), Vec3(255u8, 128u8, 128u8));
+    assert_eq!(forward.apply(Vec3(0u8, 0u8, 0u8)), Vec3(0u8, 128u8, 128u8));
+
+    let mut state = 8u32;
+    for colour in (0u8..=255u8).map(|value| Vec3(value, value, value)).chain((0usize..5000usize).map(|_| random_colour(&mut state))) {
+        assert_close(backward.apply(forward.apply(colour)), colour, 1u8);
+    } // for ..
+}
+
+
+#[test]
+fn fixed_coefficients_saturate() {
+    let huge  = Mat4::new_colour_mat(Mat3::new_channel_mixing_mat(Vec3(1e9f32, 1e9f32, 1e9f32), Vec3(-1e9f32, -1e9f32, -1e9f32), Vec3(200f32, 0f32, -200f32)), Vec3(1e9f32, -1e9f32, 300f32));
+    let fixed = FixedColourMat::from(huge);
+    let limit = 128i32 << 14u32;
+    assert_eq!(fixed.rows[0usize], Vec3(limit, limit, limit));
+    assert_eq!(fixed.rows[1usize], Vec3(-limit, -limit, -limit));
+    assert_eq!(fixed.offset, Vec3(limit * 255i32, -limit * 255i32, limit * 255i32));
+
+    // The largest sums stay within `i32`
+    assert_eq!(fixed.apply(Vec3(255u8, 255u8, 255u8)), Vec3(255u8, 0u8, 255u8));
+    assert_eq!(fixed.apply(Vec3(0u8, 0u8, 255u8)), Vec3(255u8, 0u8, 0u8));
+}
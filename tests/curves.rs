@@ -0,0 +1,118 @@
+use lofi_maths::assert_approx_eq;
+use lofi_maths::vectors::Vec2;
+use lofi_maths::curves::{Curve, QuadraticBezier, CubicBezier, CatmullRom, BSpline};
+
+
+fn finite_difference<C: Curve<Vec2<f32>>>(curve: &C, t: f32) -> Vec2<f32> {
+    let h = 1e-3f32;
+    (curve.eval(t + h) - curve.eval(t - h)) / (2f32 * h)
+}
+
+
+#[test]
+fn bezier_endpoints_and_derivatives() {
+    let quadratic = QuadraticBezier(Vec2(0f32, 0f32), Vec2(2f32, 4f32), Vec2(4f32, 0f32));
+    assert_eq!((quadratic.eval(0f32), quadratic.eval(1f32)), (quadratic.0, quadratic.2));
+    assert_eq!(quadratic.derivative(0f32), Vec2(4f32, 8f32));
+    assert_eq!(quadratic.derivative(1f32), Vec2(4f32, -8f32));
+    assert_eq!(quadratic.eval(0.5f32), Vec2(2f32, 2f32));
+
+    let cubic = CubicBezier(Vec2(0f32, 0f32), Vec2(1f32, 3f32), Vec2(4f32, 3f32), Vec2(5f32, -1f32));
+    assert_eq!((cubic.eval(0f32), cubic.eval(1f32)), (cubic.0, cubic.3));
+    assert_eq!(cubic.derivative(0f32), Vec2(3f32, 9f32));
+    assert_eq!(cubic.derivative(1f32), Vec2(3f32, -12f32));
+
+    for t in [0.1f32, 0.25f32, 0.5f32, 0.8f32] {
+        assert_approx_eq!(quadratic.derivative(t), finite_difference(&quadratic, t), epsilon = 1e-2f32);
+        assert_approx_eq!(cubic.derivative(t), finite_difference(&cubic, t), epsilon = 1e-2f32);
+        assert_approx_eq!(quadratic.to_cubic().eval(t), quadratic.eval(t), epsilon = 1e-5f32);
+    } // for ..
+}
+
+
+#[test]
+fn split_halves_meet() {
+    let cubic = CubicBezier(Vec2(0f32, 0f32), Vec2(1f32, 3f32), Vec2(4f32, 3f32), Vec2(5f32, -1f32));
+    let (left, right) = cubic.split(0.3f32);
+    assert_eq!((left.0, right.3), (cubic.0, cubic.3));
+    assert_eq!(left.3, right.0);
+    assert_approx_eq!(left.eval(1f32), cubic.eval(0.3f32), epsilon = 1e-5f32);
+    assert_approx_eq!(right.eval(0.5f32), cubic.eval(0.65f32), epsilon = 1e-5f32);
+
+    let (min, max) = cubic.bounding_box();
+    for step in 0u32..=100u32 {
+        let point = cubic.eval(step as f32 / 100f32);
+        assert!(point.0 >= min.0 - 1e-5f32 && point.0 <= max.0 + 1e-5f32 && point.1 >= min.1 - 1e-5f32 && point.1 <= max.1 + 1e-5f32);
+    } // for ..
+    assert!(max.1 > 2f32 && max.1 < 3f32);
+}
+
+
+#[test]
+fn catmull_rom_goes_through_its_points() {
+    let points = vec![Vec2(-1f32, 0f32), Vec2(0f32, 0f32), Vec2(1f32, 2f32), Vec2(3f32, 1f32), Vec2(4f32, 4f32), Vec2(6f32, 0f32)];
+    let spline = CatmullRom(points.clone());
+    assert_eq!(spline.segment_count(), 3usize);
+
+    for (index, &point) in points[1usize..points.len() - 1usize].iter().enumerate() {
+        assert_approx_eq!(spline.eval(index as f32 / 3f32), point, epsilon = 1e-5f32);
+    } // for ..
+
+    // Tangents at the inner points are half the difference of their neighbours, per segment
+    assert_approx_eq!(spline.derivative(1f32 / 3f32), (points[3usize] - points[1usize]) / 2f32 * 3f32, epsilon = 1e-4f32);
+    assert_approx_eq!(spline.segment(0usize).3, spline.segment(1usize).0);
+}
+
+
+#[test]
+fn b_spline_segments() {
+    for count in 4usize..10usize {
+        let points = (0usize..count).map(|index| Vec2(index as f32, (index % 2usize) as f32)).collect::<Vec<_>>();
+        let spline = BSpline(points);
+        assert_eq!(spline.segment_count(), count - 3usize);
+        for index in 1usize..spline.segment_count() {
+            assert_approx_eq!(spline.segment(index - 1usize).3, spline.segment(index).0);
+            assert_approx_eq!(spline.segment(index - 1usize).derivative(1f32), spline.segment(index).derivative(0f32), epsilon = 1e-5f32);
+        } // for ..
+    } // for ..
+
+    // Collinear evenly spaced points give a straight line at constant speed
+    let line = BSpline(vec![Vec2(0f32, 0f32), Vec2(1f32, 1f32), Vec2(2f32, 2f32), Vec2(3f32, 3f32), Vec2(4f32, 4f32)]);
+    assert_approx_eq!(line.eval(0f32), Vec2(1f32, 1f32), epsilon = 1e-5f32);
+    assert_approx_eq!(line.eval(1f32), Vec2(3f32, 3f32), epsilon = 1e-5f32);
+    assert_approx_eq!(line.eval(0.25f32), Vec2(1.5f32, 1.5f32), epsilon = 1e-5f32);
+}
+
+
+#[test]
+#[should_panic]
+fn splines_need_four_points() {
+    BSpline(vec![Vec2(0f32, 0f32), Vec2(1f32, 1f32), Vec2(2f32, 2f32)]).segment_count();
+}
+
+
+#[test]
+fn arc_length_table() {
+    let line  = CubicBezier(Vec2(0f32, 0f32), Vec2(0f32, 0f32), Vec2(3f32, 4f32), Vec2(3f32, 4f32));
+    let table = line.arc_length_table(64usize);
+    assert_approx_eq!(table.length(), 5f32, epsilon = 1e-4f32);
+    assert_eq!((table.parameter_at(-1f32), table.parameter_at(10f32)), (0f32, 1f32));
+
+    // The control points bunch up at the ends, constant speed still moves in equal steps
+    let mut previous = 0f32;
+    for step in 1u32..=10u32 {
+        let parameter = table.parameter_at_fraction(step as f32 / 10f32);
+        assert!(parameter >= previous);
+        assert_approx_eq!(line.eval(parameter), Vec2(0.3f32, 0.4f32) * step as f32, epsilon = 1e-2f32);
+        previous = parameter;
+    } // for ..
+
+    let curve = CubicBezier(Vec2(0f32, 0f32), Vec2(1f32, 3f32), Vec2(4f32, 3f32), Vec2(5f32, -1f32));
+    let table = curve.arc_length_table(100usize);
+    let mut previous = 0f32;
+    for step in 0u32..=1000u32 {
+        let parameter = table.parameter_at(step as f32 * table.length() / 1000f32);
+        assert!(parameter >= previous);
+        previous = parameter;
+    } // for ..
+}
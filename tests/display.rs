@@ -0,0 +1,79 @@
+use lofi_maths::Angle;
+use lofi_maths::vectors::{Vec2, Vec3, Vec4};
+use lofi_maths::matrices::{Mat3, Mat4};
+use lofi_maths::display::ParseError;
+
+
+#[test]
+fn vectors_round_trip() {
+    let vec2 = Vec2(12i32, -7i32);
+    let vec3 = Vec3(0.25f32, -1.5f32, 8f32);
+    let vec4 = Vec4(1u8, 2u8, 3u8, 255u8);
+
+    assert_eq!(vec2.to_string(), "(12, -7)");
+    assert_eq!(vec2.to_string().parse::<Vec2<i32>>(), Ok(vec2));
+    assert_eq!(vec3.to_string().parse::<Vec3<f32>>(), Ok(vec3));
+    assert_eq!(vec4.to_string().parse::<Vec4<u8>>(), Ok(vec4));
+    assert_eq!(" [ 12 ,-7 ] ".parse::<Vec2<i32>>(), Ok(vec2));
+}
+
+
+#[test]
+fn matrices_round_trip() {
+    let mat3 = Mat3(Vec3(1i32, 2i32, 3i32), Vec3(4i32, 5i32, 6i32), Vec3(7i32, 8i32, 9i32));
+    let mat4 = Mat4::<f32>::new_orthogonal_projection_mat((-4f32, 4f32), (-3f32, 3f32), (0.5f32, 100f32));
+
+    assert_eq!(mat3.to_string(), "[[1, 4, 7],\n [2, 5, 8],\n [3, 6, 9]]");
+    assert_eq!(mat3.to_string().parse::<Mat3<i32>>(), Ok(mat3));
+    assert_eq!(mat4.to_string().parse::<Mat4<f32>>(), Ok(mat4));
+    assert_eq!("((1, 4, 7), (2, 5, 8), (3, 6, 9))".parse::<Mat3<i32>>(), Ok(mat3));
+    assert_eq!("[(1, 4, 7), [2, 5, 8], (3, 6, 9)]".parse::<Mat3<i32>>(), Ok(mat3));
+}
+
+
+#[test]
+fn angles_round_trip() {
+    for byte in 0u8..=255u8 {
+        let angle = Angle(byte);
+        assert_eq!(angle.to_string().parse::<Angle>(), Ok(angle));
+        assert_eq!(format!("{:#}", angle).parse::<Angle>(), Ok(angle));
+    } // for ..
+    assert_eq!(format!("{:#}", Angle(64u8)), "64b");
+    assert_eq!("90°".parse::<Angle>(), Ok(Angle(64u8)));
+}
+
+
+#[test]
+fn delimiter_errors() {
+    assert_eq!("1, 2".parse::<Vec2<i32>>(), Err(ParseError::Delimiter));
+    assert_eq!("(1, 2]".parse::<Vec2<i32>>(), Err(ParseError::Delimiter));
+    assert_eq!("[(1, 4, 7], (2, 5, 8), (3, 6, 9)]".parse::<Mat3<i32>>(), Err(ParseError::Delimiter));
+    assert_eq!("[1, 4, 7, 2, 5, 8, 3, 6, 9]".parse::<Mat3<i32>>(), Err(ParseError::Delimiter));
+    assert_eq!("[[1, 4, 7] [2, 5, 8], [3, 6, 9]]".parse::<Mat3<i32>>(), Err(ParseError::Delimiter));
+}
+
+
+#[test]
+fn length_errors() {
+    assert_eq!("(1, 2, 3)".parse::<Vec2<i32>>(), Err(ParseError::Length { expected: 2usize, found: 3usize }));
+    assert_eq!("(1, 2, 3)".parse::<Vec4<i32>>(), Err(ParseError::Length { expected: 4usize, found: 3usize }));
+    assert_eq!("[[1, 4, 7], [2, 5, 8]]".parse::<Mat3<i32>>(), Err(ParseError::Length { expected: 3usize, found: 2usize }));
+    assert_eq!("[[1, 4, 7], [2, 5], [3, 6, 9]]".parse::<Mat3<i32>>(), Err(ParseError::Length { expected: 3usize, found: 2usize }));
+}
+
+
+#[test]
+fn component_errors() {
+    assert_eq!("(1, x)".parse::<Vec2<i32>>(), Err(ParseError::Component { index: 1usize }));
+    assert_eq!("(1, 256)".parse::<Vec2<u8>>(), Err(ParseError::Component { index: 1usize }));
+    assert_eq!("[[1, 4, 7], [2, 5, 8], [3, 6.5, 9]]".parse::<Mat3<i32>>(), Err(ParseError::Component { index: 7usize }));
+    assert_eq!("ninetydeg".parse::<Angle>(), Err(ParseError::Component { index: 0usize }));
+    assert_eq!("300b".parse::<Angle>(), Err(ParseError::Component { index: 0usize }));
+}
+
+
+#[test]
+fn unit_errors() {
+    assert_eq!("90".parse::<Angle>(), Err(ParseError::Unit));
+    assert_eq!("90rad".parse::<Angle>(), Err(ParseError::Unit));
+}
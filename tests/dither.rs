@@ -0,0 +1,126 @@
+use lofi_maths::vectors::{Vec2, Vec3};
+use lofi_maths::dither::{
+    BAYER_2X2, BAYER_4X4, BAYER_8X8, BLUE_NOISE_16X16,
+    bayer_threshold, blue_noise_threshold, quantize, nearest_colour, floyd_steinberg, atkinson,
+};
+
+
+/// Checks that a table ranks every pixel once
+fn assert_permutation<const N: usize>(matrix: &[[u8; N]; N]) {
+    let mut ranks = matrix.iter().flatten().map(|&rank| rank as usize).collect::<Vec<_>>();
+    ranks.sort_unstable();
+    assert_eq!(ranks, (0usize..N * N).collect::<Vec<_>>());
+}
+
+
+/// Doubles a Bayer table, each block is four times the smaller table plus the rank of its quadrant
+fn double<const N: usize, const M: usize>(matrix: &[[u8; N]; N]) -> [[u8; M]; M] {
+    let mut doubled = [[0u8; M]; M];
+    for (y, row) in doubled.iter_mut().enumerate() {
+        for (x, rank) in row.iter_mut().enumerate() {
+            *rank = 4u8 * matrix[y % N][x % N] + BAYER_2X2[y / N][x / N];
+        } // for ..
+    } // for ..
+    doubled
+}
+
+
+#[test]
+fn tables_are_permutations() {
+    assert_permutation(&BAYER_2X2);
+    assert_permutation(&BAYER_4X4);
+    assert_permutation(&BAYER_8X8);
+    assert_permutation(&BLUE_NOISE_16X16);
+}
+
+
+#[test]
+fn bayer_tables_follow_the_recursion() {
+    assert_eq!(double::<2, 4>(&BAYER_2X2), BAYER_4X4);
+    assert_eq!(double::<4, 8>(&BAYER_4X4), BAYER_8X8);
+}
+
+
+#[test]
+fn thresholds_tile_the_screen() {
+    assert_eq!(bayer_threshold(&BAYER_2X2, Vec2(0u16, 0u16)), 32u8);
+    assert_eq!(bayer_threshold(&BAYER_2X2, Vec2(1u16, 1u16)), 96u8);
+    assert_eq!(bayer_threshold(&BAYER_8X8, Vec2(0u16, 1u16)), bayer_threshold(&BAYER_8X8, Vec2(8u16, 9u16)));
+    assert_eq!(bayer_threshold(&BAYER_8X8, Vec2(0u16, 7u16)), 254u8);
+    assert_eq!(blue_noise_threshold(Vec2(u16::MAX, u16::MAX)), BLUE_NOISE_16X16[15usize][15usize]);
+}
+
+
+#[test]
+fn quantize_extremes() {
+    for levels in 2u8..=255u8 {
+        for threshold in [0u8, 1u8, 128u8, 254u8, 255u8] {
+            assert_eq!(quantize(0u8, levels, threshold), 0u8, "{levels} {threshold}");
+            assert_eq!(quantize(255u8, levels, threshold), 255u8, "{levels} {threshold}");
+        } // for ..
+    } // for ..
+
+    // A single level or none at all leaves nothing to pick from
+    assert_eq!(quantize(200u8, 0u8, 0u8), 0u8);
+    assert_eq!(quantize(200u8, 1u8, 0u8), 0u8);
+
+    // Black and white only, a channel goes up for the thresholds below it
+    assert_eq!(quantize(128u8, 2u8, 0u8), 255u8);
+    assert_eq!(quantize(128u8, 2u8, 128u8), 255u8);
+    assert_eq!(quantize(128u8, 2u8, 129u8), 0u8);
+    assert_eq!(quantize(128u8, 2u8, 255u8), 0u8);
+
+    // Values already on a level stay there whatever the threshold
+    for value in (0u8..=255u8).step_by(15usize) {
+        assert_eq!(quantize(value, 18u8, 0u8), value);
+        assert_eq!(quantize(value, 18u8, 255u8), value);
+    } // for ..
+}
+
+
+#[test]
+fn nearest_colour_picks_the_first_on_ties() {
+    let (black, grey, white) = (Vec3(0u8, 0u8, 0u8), Vec3(10u8, 0u8, 0u8), Vec3(255u8, 255u8, 255u8));
+    assert_eq!(nearest_colour(&[], black), None);
+    assert_eq!(nearest_colour(&[black, white], Vec3(100u8, 100u8, 100u8)), Some(0usize));
+    assert_eq!(nearest_colour(&[black, white], Vec3(200u8, 100u8, 200u8)), Some(1usize));
+    assert_eq!(nearest_colour(&[black, grey], Vec3(5u8, 0u8, 0u8)), Some(0usize));
+    assert_eq!(nearest_colour(&[grey, black], Vec3(5u8, 0u8, 0u8)), Some(0usize));
+    assert_eq!(nearest_colour(&[white, grey, grey], Vec3(9u8, 3u8, 0u8)), Some(1usize));
+}
+
+
+#[test]
+fn palette_images_pass_through() {
+    let palette = [Vec3(0u8, 0u8, 0u8), Vec3(255u8, 0u8, 77u8), Vec3(40u8, 200u8, 90u8), Vec3(255u8, 255u8, 255u8)];
+    let mut state = 5u32;
+    let image = (0usize..50usize).map(|_| {
+        state = state.wrapping_mul(1664525u32).wrapping_add(1013904223u32);
+        palette[(state >> 24u32) as usize % palette.len()]
+    }).collect::<Vec<_>>();
+
+    // 7 does not divide 50, the last row is short
+    for width in [1usize, 7usize, 10usize, 50usize, 64usize] {
+        let mut pixels = image.clone();
+        floyd_steinberg(&mut pixels, width, &palette);
+        assert_eq!(pixels, image, "{width}");
+        atkinson(&mut pixels, width, &palette);
+        assert_eq!(pixels, image, "{width}");
+    } // for ..
+
+    // Nothing to map to, or no rows
+    let mut pixels = image.clone();
+    floyd_steinberg(&mut pixels, 7usize, &[]);
+    atkinson(&mut pixels, 0usize, &palette);
+    assert_eq!(pixels, image);
+}
+
+
+#[test]
+fn diffusion_keeps_the_average() {
+    let palette = [Vec3(0u8, 0u8, 0u8), Vec3(255u8, 255u8, 255u8)];
+    let mut pixels = vec![Vec3(64u8, 64u8, 64u8); 32usize * 32usize];
+    floyd_steinberg(&mut pixels, 32usize, &palette);
+    let lit = pixels.iter().filter(|&&pixel| pixel == palette[1usize]).count();
+    assert!((lit as i32 - 256i32).abs() <= 16i32, "{lit}");
+}
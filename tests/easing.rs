@@ -0,0 +1,58 @@
+use lofi_maths::easing::Easing;
+
+
+#[test]
+fn lookup_tables_follow_the_float_curves() {
+    for easing in Easing::ALL {
+        for sample in 0u32..=64u32 {
+            // Smallest progress landing on the sample, at most a thousandth past it
+            let t     = (sample * u16::MAX as u32).div_ceil(64u32) as u16;
+            let fixed = easing.ease16_signed(t) as f32 / u16::MAX as f32;
+            let float = easing.ease(t as f32 / u16::MAX as f32);
+            assert!((fixed - float).abs() < 1e-4f32, "{easing:?} at {sample}: {fixed} against {float}");
+        } // for ..
+    } // for ..
+}
+
+
+#[test]
+fn fixed_curves_keep_the_endpoints() {
+    for easing in Easing::ALL {
+        assert_eq!((easing.ease8(0u8), easing.ease8(u8::MAX)), (0u8, u8::MAX), "{easing:?}");
+        assert_eq!((easing.ease16(0u16), easing.ease16(u16::MAX)), (0u16, u16::MAX), "{easing:?}");
+        assert_eq!((easing.ease16_signed(0u16), easing.ease16_signed(u16::MAX)), (0i32, u16::MAX as i32), "{easing:?}");
+        assert_eq!((easing.ease(0f32), easing.ease(1f32)), (0f32, 1f32), "{easing:?}");
+    } // for ..
+}
+
+
+#[test]
+fn lookup_tables_stay_in_range() {
+    let max = u16::MAX as i32;
+    for easing in Easing::ALL {
+        // Back and elastic curves overshoot by less than a half, the others never leave `0..=1`
+        let overshoots  = matches!(easing, Easing::BackIn | Easing::BackOut | Easing::BackInOut | Easing::ElasticIn | Easing::ElasticOut | Easing::ElasticInOut);
+        let (low, high) = if overshoots { (-max / 2i32, max + max / 2i32) } else { (0i32, max) };
+        for sample in 0u32..=64u32 {
+            let entry = easing.ease16_signed((sample * u16::MAX as u32).div_ceil(64u32) as u16);
+            assert!(entry >= low && entry <= high, "{easing:?} at {sample}: {entry}");
+        } // for ..
+    } // for ..
+}
+
+
+#[test]
+fn fixed_curves_rise_monotonically() {
+    for easing in Easing::ALL {
+        let mut previous = 0u16;
+        for t in 0u16..=u16::MAX {
+            let eased = easing.ease16(t);
+            if matches!(easing, Easing::QuadIn | Easing::CubicIn | Easing::SineIn | Easing::ExpoIn | Easing::QuadOut | Easing::CubicOut | Easing::SineOut | Easing::ExpoOut) {
+                assert!(eased >= previous, "{easing:?} decreases at {t}");
+            } // if ..
+            previous = eased;
+        } // for ..
+        assert_eq!(easing.lerp8(10i32, 20i32, u8::MAX), 20i32);
+        assert_eq!(easing.lerp16(-10i32, 10i32, 0u16), -10i32);
+    } // for ..
+}
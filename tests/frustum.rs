@@ -0,0 +1,127 @@
+use lofi_maths::assert_approx_eq;
+use lofi_maths::vectors::{Vec3, Vec4};
+use lofi_maths::matrices::{Mat4, DepthRange};
+use lofi_maths::geometry::{Plane, Ball, Aabb, Obb};
+use lofi_maths::frustum::{Frustum, Containment};
+
+
+/// Box from -2 to 4 across, -1 to 3 up, looking down `-z` from 1 to 10 away
+fn orthogonal() -> Mat4<f32> {
+    Mat4::new_orthogonal_projection_mat((-2f32, 4f32), (-1f32, 3f32), (1f32, 10f32))
+}
+
+
+/// Takes the depth of an OpenGL projection from `-1..1` to `0..1`
+fn zero_to_one(matrix: Mat4<f32>) -> Mat4<f32> {
+    let remap = Mat4(Vec4(1f32, 0f32, 0f32, 0f32), Vec4(0f32, 1f32, 0f32, 0f32), Vec4(0f32, 0f32, 0.5f32, 0f32), Vec4(0f32, 0f32, 0.5f32, 1f32));
+    remap * matrix
+}
+
+
+/// Quarter turn field of view looking down `-z` from 1 to 100 away, OpenGL depth
+fn perspective() -> Mat4<f32> {
+    Mat4(Vec4(1f32, 0f32, 0f32, 0f32), Vec4(0f32, 1f32, 0f32, 0f32), Vec4(0f32, 0f32, -101f32 / 99f32, -1f32), Vec4(0f32, 0f32, -200f32 / 99f32, 0f32))
+}
+
+
+fn assert_planes(frustum: &Frustum<f32>, expected: [Plane<f32>; 6]) {
+    for (plane, expected) in frustum.planes.iter().zip(expected) {
+        assert_approx_eq!(plane.normal, expected.normal, epsilon = 1e-5f32);
+        assert_approx_eq!(plane.distance, expected.distance, epsilon = 1e-5f32);
+    } // for ..
+}
+
+
+#[test]
+fn planes_of_an_orthogonal_projection() {
+    let sides = [
+        Plane::new(Vec3(1f32, 0f32, 0f32), -2f32),
+        Plane::new(Vec3(-1f32, 0f32, 0f32), -4f32),
+        Plane::new(Vec3(0f32, 1f32, 0f32), -1f32),
+        Plane::new(Vec3(0f32, -1f32, 0f32), -3f32),
+    ]; // let ..
+    let (near, far) = (Plane::new(Vec3(0f32, 0f32, -1f32), 1f32), Plane::new(Vec3(0f32, 0f32, 1f32), -10f32));
+    let expected = [sides[0usize], sides[1usize], sides[2usize], sides[3usize], near, far];
+
+    assert_planes(&Frustum::from_matrix(&orthogonal(), DepthRange::NegativeOneToOne), expected);
+    assert_planes(&Frustum::from_matrix(&zero_to_one(orthogonal()), DepthRange::ZeroToOne), expected);
+
+    // An OpenGL matrix read with the other convention puts the near plane halfway, where its depth is zero
+    let halfway = Frustum::from_matrix(&orthogonal(), DepthRange::ZeroToOne);
+    assert_planes(&halfway, [sides[0usize], sides[1usize], sides[2usize], sides[3usize], Plane::new(Vec3(0f32, 0f32, -1f32), 5.5f32), far]);
+}
+
+
+#[test]
+fn points_inside_the_frustum() {
+    for (frustum, near) in [
+        (Frustum::from_matrix(&orthogonal(), DepthRange::NegativeOneToOne), 1f32),
+        (Frustum::from_matrix(&zero_to_one(orthogonal()), DepthRange::ZeroToOne), 1f32),
+        (Frustum::from_matrix(&orthogonal(), DepthRange::ZeroToOne), 5.5f32),
+    ] {
+        for (point, inside) in [
+            (Vec3(0f32, 0f32, -6f32), true),
+            (Vec3(-1.99f32, 2.99f32, -9.99f32), true),
+            (Vec3(3.99f32, -0.99f32, -0.01f32 - near), true),
+            (Vec3(4.01f32, 0f32, -6f32), false),
+            (Vec3(0f32, -1.01f32, -6f32), false),
+            (Vec3(0f32, 0f32, 0.01f32 - near), false),
+            (Vec3(0f32, 0f32, -10.01f32), false),
+        ] {
+            assert_eq!(frustum.contains(point), inside, "{point:?} with the near plane at {near}");
+        } // for ..
+    } // for ..
+
+    let frustum = Frustum::from_matrix(&perspective(), DepthRange::NegativeOneToOne);
+    assert!(frustum.contains(Vec3(0f32, 0f32, -50f32)) && frustum.contains(Vec3(49f32, -49f32, -50f32)));
+    assert!(!frustum.contains(Vec3(51f32, 0f32, -50f32)) && !frustum.contains(Vec3(0f32, 0f32, -0.5f32)) && !frustum.contains(Vec3(0f32, 0f32, -101f32)));
+    assert!(Frustum::from_matrix(&zero_to_one(perspective()), DepthRange::ZeroToOne).contains(Vec3(0f32, 0f32, -1.01f32)));
+
+    // Without a far plane, its row is left without a normal and accepts every point
+    let infinite = Mat4(Vec4(1f32, 0f32, 0f32, 0f32), Vec4(0f32, 1f32, 0f32, 0f32), Vec4(0f32, 0f32, -1f32, -1f32), Vec4(0f32, 0f32, -2f32, 0f32));
+    let frustum  = Frustum::from_matrix(&infinite, DepthRange::NegativeOneToOne);
+    assert_eq!(frustum.planes[5usize], Plane::new(Vec3(0f32, 0f32, 0f32), -2f32));
+    assert!(frustum.contains(Vec3(0f32, 0f32, -1e30f32)) && !frustum.contains(Vec3(0f32, 0f32, -0.5f32)));
+    assert_eq!(frustum.test_sphere(&Ball::new(Vec3(0f32, 0f32, -1e6f32), 1f32)), Containment::Inside);
+}
+
+
+#[test]
+fn spheres() {
+    for frustum in [Frustum::from_matrix(&orthogonal(), DepthRange::NegativeOneToOne), Frustum::from_matrix(&zero_to_one(orthogonal()), DepthRange::ZeroToOne)] {
+        assert_eq!(frustum.test_sphere(&Ball::new(Vec3(1f32, 1f32, -5f32), 1f32)), Containment::Inside);
+        assert_eq!(frustum.test_sphere(&Ball::new(Vec3(1f32, 1f32, -5f32), 2.5f32)), Containment::Intersecting);
+        assert_eq!(frustum.test_sphere(&Ball::new(Vec3(6f32, 1f32, -5f32), 2.1f32)), Containment::Intersecting);
+        assert_eq!(frustum.test_sphere(&Ball::new(Vec3(6f32, 1f32, -5f32), 1.9f32)), Containment::Outside);
+        assert_eq!(frustum.test_sphere(&Ball::new(Vec3(6f32, 1f32, -5f32), 1f32)), Containment::Outside);
+        assert_eq!(frustum.test_sphere(&Ball::new(Vec3(1f32, 1f32, 2f32), 2f32)), Containment::Outside);
+        assert_eq!(frustum.test_sphere(&Ball::new(Vec3(1f32, 1f32, -12f32), 3f32)), Containment::Intersecting);
+
+        // Beyond a corner but within reach of both planes, reported as crossing them
+        assert_eq!(frustum.test_sphere(&Ball::new(Vec3(5f32, 4f32, -5f32), 1.2f32)), Containment::Intersecting);
+    } // for ..
+}
+
+
+#[test]
+fn boxes() {
+    for frustum in [Frustum::from_matrix(&orthogonal(), DepthRange::NegativeOneToOne), Frustum::from_matrix(&zero_to_one(orthogonal()), DepthRange::ZeroToOne)] {
+        assert_eq!(frustum.test_aabb(&Aabb::new(Vec3(-1f32, 0f32, -9f32), Vec3(3f32, 2f32, -2f32))), Containment::Inside);
+        assert_eq!(frustum.test_aabb(&Aabb::new(Vec3(-2f32, -1f32, -10f32), Vec3(4f32, 3f32, -1f32))), Containment::Inside);
+        assert_eq!(frustum.test_aabb(&Aabb::new(Vec3(3f32, 0f32, -9f32), Vec3(5f32, 2f32, -2f32))), Containment::Intersecting);
+        assert_eq!(frustum.test_aabb(&Aabb::new(Vec3(-9f32, -9f32, -20f32), Vec3(9f32, 9f32, 5f32))), Containment::Intersecting);
+        assert_eq!(frustum.test_aabb(&Aabb::new(Vec3(4.5f32, 0f32, -9f32), Vec3(5f32, 2f32, -2f32))), Containment::Outside);
+        assert_eq!(frustum.test_aabb(&Aabb::new(Vec3(0f32, 0f32, -0.5f32), Vec3(1f32, 1f32, 3f32))), Containment::Outside);
+
+        // Turned by 45 degrees around `z`, the corners of the unit box reach sqrt(2) along `x` and `y`
+        let diagonal = std::f32::consts::FRAC_1_SQRT_2;
+        let axes     = [Vec3(diagonal, diagonal, 0f32), Vec3(-diagonal, diagonal, 0f32), Vec3(0f32, 0f32, 1f32)];
+        let obb      = |center: Vec3<f32>| Obb { center, axes, half_extents: Vec3(1f32, 1f32, 1f32) };
+        assert_eq!(frustum.test_obb(&obb(Vec3(1f32, 1f32, -5f32))), Containment::Inside);
+        assert_eq!(frustum.test_obb(&obb(Vec3(2.5f32, 1f32, -5f32))), Containment::Inside);
+        assert_eq!(frustum.test_obb(&obb(Vec3(2.7f32, 1f32, -5f32))), Containment::Intersecting);
+        assert_eq!(frustum.test_obb(&obb(Vec3(5.5f32, 1f32, -5f32))), Containment::Outside);
+        assert_eq!(frustum.test_obb(&obb(Vec3(1f32, 1f32, -11.5f32))), Containment::Outside);
+        assert_eq!(frustum.test_obb(&obb(Vec3(1f32, 1f32, -10.5f32))), Containment::Intersecting);
+    } // for ..
+}
@@ -0,0 +1,172 @@
+use lofi_maths::assert_approx_eq;
+use lofi_maths::vectors::{Vec2, Vec3, Vec4};
+use lofi_maths::matrices::{Mat3, Mat4};
+use lofi_maths::geometry::{Ray, Segment, Line, Plane, Ball, Aabb, Triangle, Capsule, Obb};
+
+
+fn translation(offset: Vec3<f32>) -> Mat4<f32> {
+    Mat4(Vec4(1f32, 0f32, 0f32, 0f32), Vec4(0f32, 1f32, 0f32, 0f32), Vec4(0f32, 0f32, 1f32, 0f32), Vec4(offset.0, offset.1, offset.2, 1f32))
+}
+
+
+/// Quarter turn around `z`, taking `x` to `y`
+fn quarter_turn() -> Mat4<f32> {
+    Mat4(Vec4(0f32, 1f32, 0f32, 0f32), Vec4(-1f32, 0f32, 0f32, 0f32), Vec4(0f32, 0f32, 1f32, 0f32), Vec4(0f32, 0f32, 0f32, 1f32))
+}
+
+
+#[test]
+fn linear_closest_points() {
+    let (start, end) = (Vec2(0i32, 0i32), Vec2(10i32, 0i32));
+    let (segment, ray, line) = (Segment::new(start, end), Ray::new(start, end), Line::new(start, end));
+
+    for (point, on_segment, on_ray, on_line) in [
+        (Vec2(5i32, 3i32),   Vec2(5i32, 0i32),  Vec2(5i32, 0i32),  Vec2(5i32, 0i32)),
+        (Vec2(-4i32, 2i32),  Vec2(0i32, 0i32),  Vec2(0i32, 0i32),  Vec2(-4i32, 0i32)),
+        (Vec2(14i32, -1i32), Vec2(10i32, 0i32), Vec2(14i32, 0i32), Vec2(14i32, 0i32)),
+    ] {
+        assert_eq!((segment.closest_point(point), ray.closest_point(point), line.closest_point(point)), (on_segment, on_ray, on_line));
+    } // for ..
+
+    assert!(segment.contains(Vec2(10i32, 0i32)) && !segment.contains(Vec2(11i32, 0i32)));
+    assert!(ray.contains(Vec2(11i32, 0i32)) && !ray.contains(Vec2(-1i32, 0i32)));
+    assert!(line.contains(Vec2(-1i32, 0i32)) && !line.contains(Vec2(-1i32, 1i32)));
+    assert_eq!(segment.squared_distance(Vec2(13i32, 4i32)), 25i32);
+
+    // Integer projections are rounded once, towards zero
+    assert_eq!(Segment::new(Vec2(0i32, 0i32), Vec2(3i32, 1i32)).closest_point(Vec2(1i32, 3i32)), Vec2(1i32, 0i32));
+    assert_eq!(Segment::new(start, start).closest_point(Vec2(3i32, 3i32)), start);
+
+    assert_eq!(Segment::new(Vec2(0f32, 0f32), Vec2(4f32, 0f32)).signed_distance(Vec2(2f32, -3f32)), -3f32);
+    assert_eq!(Line::new(Vec2(0f32, 0f32), Vec2(2f32, 0f32)).signed_distance(Vec2(7f32, 3f32)), 3f32);
+}
+
+
+#[test]
+fn planes() {
+    let plane = Plane::new(Vec3(0i32, 0i32, 2i32), 4i32);
+    assert_eq!(plane.closest_point(Vec3(1i32, 1i32, 5i32)), Vec3(1i32, 1i32, 2i32));
+    assert!(plane.contains(Vec3(-7i32, 3i32, 2i32)) && !plane.contains(Vec3(0i32, 0i32, 3i32)));
+    assert_eq!(plane.squared_distance(Vec3(0i32, 0i32, 5i32)), 9i32);
+
+    let plane = Plane::from_point_normal(Vec3(0f32, 0f32, 2f32), Vec3(0f32, 0f32, 1f32));
+    assert_eq!(plane.signed_distance(Vec3(3f32, 3f32, -1f32)), -3f32);
+    let moved = plane.transform(&translation(Vec3(5f32, 0f32, 3f32)));
+    assert_eq!((moved.normal, moved.distance), (Vec3(0f32, 0f32, 1f32), 5f32));
+    assert_approx_eq!(plane.transform(&quarter_turn()).distance, 2f32);
+
+    let triangle = Triangle(Vec3(0i32, 0i32, 1i32), Vec3(1i32, 0i32, 1i32), Vec3(0i32, 1i32, 1i32));
+    assert_eq!(Plane::from_triangle(&triangle), Plane::new(Vec3(0i32, 0i32, 1i32), 1i32));
+}
+
+
+#[test]
+fn balls_and_capsules() {
+    let ball = Ball::new(Vec2(1i32, 1i32), 5i32);
+    assert!(ball.contains(Vec2(4i32, 5i32)) && !ball.contains(Vec2(5i32, 5i32)));
+
+    let ball = Ball::new(Vec2(0f32, 0f32), 5f32);
+    assert_eq!(ball.closest_point(Vec2(6f32, 8f32)), Vec2(3f32, 4f32));
+    assert_eq!(ball.closest_point(Vec2(1f32, 1f32)), Vec2(1f32, 1f32));
+    assert_eq!((ball.signed_distance(Vec2(0f32, 2f32)), ball.squared_distance(Vec2(0f32, 2f32))), (-3f32, 0f32));
+
+    let capsule = Capsule::new(Vec2(0f32, 0f32), Vec2(10f32, 0f32), 2f32);
+    assert!(capsule.contains(Vec2(11f32, 1f32)) && !capsule.contains(Vec2(12f32, 1f32)));
+    assert_eq!(capsule.closest_point(Vec2(5f32, 7f32)), Vec2(5f32, 2f32));
+    assert_eq!(capsule.signed_distance(Vec2(-5f32, 0f32)), 3f32);
+
+    let scale = Mat3::new_2d_homogeneous_scaling_mat(Vec2(2f32, 2f32)) * Mat3::new_2d_homogeneous_translation_mat(Vec2(1f32, 0f32));
+    assert_eq!(ball.transform(&scale), Ball::new(Vec2(2f32, 0f32), 10f32));
+    let moved = capsule.transform(&scale);
+    assert_eq!((moved.segment, moved.radius), (Segment::new(Vec2(2f32, 0f32), Vec2(22f32, 0f32)), 4f32));
+}
+
+
+#[test]
+fn boxes() {
+    let aabb = Aabb::new(Vec2(0i32, 0i32), Vec2(4i32, 2i32));
+    assert!(aabb.contains(Vec2(4i32, 2i32)) && aabb.contains(Vec2(0i32, 1i32)) && !aabb.contains(Vec2(5i32, 1i32)));
+    assert_eq!(aabb.closest_point(Vec2(-3i32, 7i32)), Vec2(0i32, 2i32));
+    assert_eq!(aabb.squared_distance(Vec2(7i32, 6i32)), 25i32);
+    assert_eq!(Aabb::from_points([Vec2(3i32, -1i32), Vec2(-2i32, 4i32), Vec2(0i32, 0i32)]), Some(Aabb::new(Vec2(-2i32, -1i32), Vec2(3i32, 4i32))));
+    assert_eq!(Aabb::<Vec2<i32>>::from_points([]), None);
+
+    let aabb = Aabb::new(Vec2(0f32, 0f32), Vec2(4f32, 2f32));
+    assert_eq!(aabb.signed_distance(Vec2(1f32, 1.5f32)), -0.5f32);
+    assert_eq!(aabb.signed_distance(Vec2(7f32, 6f32)), 5f32);
+
+    let turned = aabb.transform(&Mat3::new_2d_homogeneous_rotation_mat(std::f32::consts::FRAC_PI_2));
+    assert_approx_eq!(turned.min, Vec2(0f32, -4f32), epsilon = 1e-5f32);
+    assert_approx_eq!(turned.max, Vec2(2f32, 0f32), epsilon = 1e-5f32);
+
+    let obb = Obb::from_aabb(&Aabb::new(Vec3(0f32, 0f32, 0f32), Vec3(4f32, 2f32, 2f32)));
+    assert_eq!((obb.center, obb.half_extents), (Vec3(2f32, 1f32, 1f32), Vec3(2f32, 1f32, 1f32)));
+    assert!(obb.contains(Vec3(4f32, 2f32, 0f32)) && !obb.contains(Vec3(4f32, 2.5f32, 0f32)));
+    assert_eq!(obb.closest_point(Vec3(6f32, 1f32, -3f32)), Vec3(4f32, 1f32, 0f32));
+
+    let turned = obb.transform(&(translation(Vec3(0f32, 0f32, 5f32)) * quarter_turn()));
+    assert_approx_eq!(turned.center, Vec3(-1f32, 2f32, 6f32));
+    assert!(turned.contains(Vec3(0f32, 4f32, 7f32)) && !turned.contains(Vec3(1f32, 4f32, 7f32)));
+    assert_approx_eq!(turned.closest_point(Vec3(5f32, 2f32, 6f32)), Vec3(0f32, 2f32, 6f32));
+}
+
+
+#[test]
+fn triangles() {
+    let counter_clockwise = Triangle(Vec2(0i32, 0i32), Vec2(4i32, 0i32), Vec2(0i32, 4i32));
+    let clockwise         = Triangle(counter_clockwise.0, counter_clockwise.2, counter_clockwise.1);
+    for triangle in [counter_clockwise, clockwise] {
+        assert!(triangle.contains(Vec2(1i32, 1i32)) && triangle.contains(Vec2(2i32, 2i32)) && triangle.contains(Vec2(0i32, 0i32)));
+        assert!(!triangle.contains(Vec2(3i32, 2i32)) && !triangle.contains(Vec2(-1i32, 0i32)));
+
+        // One point in each Voronoi region
+        assert_eq!(triangle.closest_point(Vec2(-2i32, -3i32)), Vec2(0i32, 0i32));
+        assert_eq!(triangle.closest_point(Vec2(6i32, -1i32)), Vec2(4i32, 0i32));
+        assert_eq!(triangle.closest_point(Vec2(-1i32, 7i32)), Vec2(0i32, 4i32));
+        assert_eq!(triangle.closest_point(Vec2(2i32, -5i32)), Vec2(2i32, 0i32));
+        assert_eq!(triangle.closest_point(Vec2(-5i32, 3i32)), Vec2(0i32, 3i32));
+        assert_eq!(triangle.closest_point(Vec2(4i32, 4i32)), Vec2(2i32, 2i32));
+        assert_eq!(triangle.closest_point(Vec2(1i32, 2i32)), Vec2(1i32, 2i32));
+    } // for ..
+
+    let triangle = Triangle(Vec2(0f32, 0f32), Vec2(4f32, 0f32), Vec2(0f32, 4f32));
+    assert_eq!(triangle.signed_distance(Vec2(1f32, 1.5f32)), -1f32);
+    assert_eq!(triangle.signed_distance(Vec2(1f32, -2f32)), 2f32);
+
+    let triangle = Triangle(Vec3(0i32, 0i32, 1i32), Vec3(4i32, 0i32, 1i32), Vec3(0i32, 4i32, 1i32));
+    assert!(triangle.contains(Vec3(1i32, 1i32, 1i32)) && !triangle.contains(Vec3(1i32, 1i32, 2i32)) && !triangle.contains(Vec3(3i32, 3i32, 1i32)));
+    assert_eq!(triangle.closest_point(Vec3(1i32, 1i32, 9i32)), Vec3(1i32, 1i32, 1i32));
+
+    let triangle = Triangle(Vec3(0f32, 0f32, 1f32), Vec3(4f32, 0f32, 1f32), Vec3(0f32, 4f32, 1f32));
+    assert_eq!(triangle.signed_distance(Vec3(1f32, 1f32, -2f32)), -3f32);
+    let moved = triangle.transform(&translation(Vec3(1f32, 2f32, 3f32)));
+    assert_eq!(moved, Triangle(Vec3(1f32, 2f32, 4f32), Vec3(5f32, 2f32, 4f32), Vec3(1f32, 6f32, 4f32)));
+}
+
+
+#[test]
+fn large_integer_coordinates() {
+    // Products of the coordinates overflowed 32 bits before the result was divided back
+    assert_eq!(Triangle(Vec2(0i32, 0i32), Vec2(100i32, 0i32), Vec2(0i32, 100i32)).closest_point(Vec2(30i32, 30i32)), Vec2(30i32, 30i32));
+    assert_eq!(Segment::new(Vec2(0i32, 0i32), Vec2(2000i32, 0i32)).closest_point(Vec2(1000i32, 5i32)), Vec2(1000i32, 0i32));
+
+    let reach    = 1i32 << 23u32;
+    let triangle = Triangle(Vec3(-reach, -reach, 0i32), Vec3(reach, -reach, 0i32), Vec3(-reach, reach, 0i32));
+    assert_eq!(triangle.closest_point(Vec3(reach, reach, 7i32)), Vec3(0i32, 0i32, 0i32));
+    assert_eq!(triangle.closest_point(Vec3(-5i32, 3i32, -reach)), Vec3(-5i32, 3i32, 0i32));
+    assert_eq!(Line::new(Vec2(-reach, 0i32), Vec2(reach, reach)).closest_point(Vec2(reach, -reach)), Vec2(-reach / 2i32, reach / 2i32));
+}
+
+
+#[test]
+fn transforms_move_points_not_directions() {
+    let shift = Mat3::new_2d_homogeneous_translation_mat(Vec2(5i32, 2i32));
+    assert_eq!(Segment::new(Vec2(0i32, 0i32), Vec2(1i32, 1i32)).transform(&shift), Segment::new(Vec2(5i32, 2i32), Vec2(6i32, 3i32)));
+    assert_eq!(Ray::new(Vec2(0i32, 0i32), Vec2(1i32, 1i32)).transform(&shift), Ray::new(Vec2(5i32, 2i32), Vec2(1i32, 1i32)));
+    assert_eq!(Line::new(Vec2(1i32, 0i32), Vec2(0i32, 3i32)).transform(&shift), Line::new(Vec2(6i32, 2i32), Vec2(0i32, 3i32)));
+    assert_eq!(Aabb::new(Vec2(0i32, 0i32), Vec2(1i32, 1i32)).transform(&shift), Aabb::new(Vec2(5i32, 2i32), Vec2(6i32, 3i32)));
+
+    let ray = Ray::new(Vec3(1f32, 0f32, 0f32), Vec3(1f32, 0f32, 0f32)).transform(&quarter_turn());
+    assert_approx_eq!(ray.origin, Vec3(0f32, 1f32, 0f32));
+    assert_approx_eq!(ray.direction, Vec3(0f32, 1f32, 0f32));
+}
@@ -0,0 +1,166 @@
+use lofi_maths::assert_approx_eq;
+use lofi_maths::vectors::{Vec2, Vec3, Vec4};
+use lofi_maths::matrices::{Mat3, Mat4};
+use lofi_maths::geometry::{Segment, Ball, Aabb, Triangle, Capsule};
+use lofi_maths::gjk::{Support, Transformed, overlap, distance, penetration};
+
+
+fn translation(offset: Vec3<f32>) -> Mat4<f32> {
+    Mat4(Vec4(1f32, 0f32, 0f32, 0f32), Vec4(0f32, 1f32, 0f32, 0f32), Vec4(0f32, 0f32, 1f32, 0f32), Vec4(offset.0, offset.1, offset.2, 1f32))
+}
+
+
+/// Checks that moving `b` along the penetration separates the shapes, and that a shorter move does not
+fn assert_resolves<A: Support<Vec3<f32>>>(a: &A, b: Aabb<Vec3<f32>>) {
+    let hit = penetration(a, &b).unwrap();
+    let moved = |distance: f32| Aabb::new(b.min + hit.normal * distance, b.max + hit.normal * distance);
+    assert!(!overlap(a, &moved(hit.distance + 1e-2f32)));
+    assert!(overlap(a, &moved(hit.distance - 1e-2f32)));
+}
+
+
+#[test]
+fn boxes() {
+    let a = Aabb::new(Vec3(0f32, 0f32, 0f32), Vec3(1f32, 1f32, 1f32));
+    let b = Aabb::new(Vec3(2f32, 0.5f32, 0f32), Vec3(3f32, 1.5f32, 1f32));
+    assert!(!overlap(&a, &b));
+    assert_eq!(penetration(&a, &b), None);
+
+    let separation = distance(&a, &b).unwrap();
+    assert_approx_eq!(separation.distance, 1f32, epsilon = 1e-5f32);
+    assert_approx_eq!(separation.point_a.0, 1f32, epsilon = 1e-5f32);
+    assert_approx_eq!(separation.point_b.0, 2f32, epsilon = 1e-5f32);
+    assert_approx_eq!(separation.point_b - separation.point_a, Vec3(1f32, 0f32, 0f32), epsilon = 1e-5f32);
+
+    // Corner to corner
+    let c = Aabb::new(Vec3(2f32, 3f32, 3f32), Vec3(4f32, 4f32, 4f32));
+    let separation = distance(&a, &c).unwrap();
+    assert_approx_eq!(separation.distance, 3f32, epsilon = 1e-4f32);
+    assert_approx_eq!(separation.point_a, Vec3(1f32, 1f32, 1f32), epsilon = 1e-4f32);
+    assert_approx_eq!(separation.point_b, Vec3(2f32, 3f32, 3f32), epsilon = 1e-4f32);
+
+    let d = Aabb::new(Vec3(0.75f32, 0.25f32, 0.25f32), Vec3(2f32, 0.75f32, 0.75f32));
+    assert!(overlap(&a, &d));
+    assert_eq!(distance(&a, &d), None);
+    let hit = penetration(&a, &d).unwrap();
+    assert_approx_eq!(hit.distance, 0.25f32, epsilon = 1e-4f32);
+    assert_approx_eq!(hit.normal, Vec3(1f32, 0f32, 0f32), epsilon = 1e-4f32);
+    assert_approx_eq!(hit.point.0, 0.875f32, epsilon = 1e-4f32);
+    assert_resolves(&a, d);
+}
+
+
+#[test]
+fn balls() {
+    let a = Ball::new(Vec3(0f32, 0f32, 0f32), 1f32);
+    let b = Ball::new(Vec3(3f32, 4f32, 0f32), 1.5f32);
+    assert!(!overlap(&a, &b));
+
+    let separation = distance(&a, &b).unwrap();
+    assert_approx_eq!(separation.distance, 2.5f32, epsilon = 1e-3f32);
+    assert_approx_eq!(separation.point_a, Vec3(0.6f32, 0.8f32, 0f32), epsilon = 1e-3f32);
+    assert_approx_eq!(separation.point_b, Vec3(2.1f32, 2.8f32, 0f32), epsilon = 1e-3f32);
+
+    let c = Ball::new(Vec3(0f32, 1.5f32, 0f32), 1f32);
+    assert!(overlap(&a, &c));
+    let hit = penetration(&a, &c).unwrap();
+    assert_approx_eq!(hit.distance, 0.5f32, epsilon = 1e-2f32);
+    assert_approx_eq!(hit.normal, Vec3(0f32, 1f32, 0f32), epsilon = 1e-2f32);
+    assert_approx_eq!(hit.point, Vec3(0f32, 0.75f32, 0f32), epsilon = 1e-2f32);
+
+    // Balls against boxes
+    assert_resolves(&a, Aabb::new(Vec3(-0.5f32, -0.5f32, 0.75f32), Vec3(0.5f32, 0.5f32, 2f32)));
+    let separation = distance(&a, &Aabb::new(Vec3(3f32, -1f32, -1f32), Vec3(4f32, 1f32, 1f32))).unwrap();
+    assert_approx_eq!(separation.distance, 2f32, epsilon = 1e-3f32);
+}
+
+
+#[test]
+fn capsules() {
+    let a = Capsule { segment: Segment::new(Vec3(0f32, 0f32, 0f32), Vec3(0f32, 0f32, 4f32)), radius: 0.5f32 };
+    let b = Capsule { segment: Segment::new(Vec3(2f32, -2f32, 1f32), Vec3(2f32, 2f32, 1f32)), radius: 0.5f32 };
+    assert!(!overlap(&a, &b));
+
+    let separation = distance(&a, &b).unwrap();
+    assert_approx_eq!(separation.distance, 1f32, epsilon = 1e-3f32);
+    assert_approx_eq!(separation.point_a, Vec3(0.5f32, 0f32, 1f32), epsilon = 1e-2f32);
+    assert_approx_eq!(separation.point_b, Vec3(1.5f32, 0f32, 1f32), epsilon = 1e-2f32);
+
+    // Crossing at right angles, a quarter apart
+    let c = Capsule { segment: Segment::new(Vec3(0.75f32, -2f32, 3f32), Vec3(0.75f32, 2f32, 3f32)), radius: 0.5f32 };
+    let hit = penetration(&a, &c).unwrap();
+    assert_approx_eq!(hit.distance, 0.25f32, epsilon = 1e-2f32);
+    assert_approx_eq!(hit.normal, Vec3(1f32, 0f32, 0f32), epsilon = 1e-2f32);
+
+    // Parallel capsules have a whole range of closest points, all at the same distance
+    let d = Capsule { segment: Segment::new(Vec3(3f32, 0f32, 1f32), Vec3(3f32, 0f32, 3f32)), radius: 1f32 };
+    let separation = distance(&a, &d).unwrap();
+    assert_approx_eq!(separation.distance, 1.5f32, epsilon = 1e-3f32);
+    assert_approx_eq!(separation.point_b - separation.point_a, Vec3(1.5f32, 0f32, 0f32), epsilon = 1e-2f32);
+}
+
+
+#[test]
+fn flat_shapes() {
+    let triangle = Triangle(Vec2(0f32, 0f32), Vec2(4f32, 0f32), Vec2(0f32, 4f32));
+    assert!(overlap(&triangle, &Vec2(1f32, 1f32)));
+    assert!(overlap(&triangle, &Vec2(2f32, 2f32)));
+    assert!(!overlap(&triangle, &Vec2(3f32, 3f32)));
+
+    let separation = distance(&triangle, &Vec2(3f32, 3f32)).unwrap();
+    assert_approx_eq!(separation.distance, 2f32.sqrt(), epsilon = 1e-5f32);
+    assert_approx_eq!(separation.point_a, Vec2(2f32, 2f32), epsilon = 1e-5f32);
+
+    // Slices are supported as their convex hull
+    let square = [Vec2(0f32, 0f32), Vec2(2f32, 0f32), Vec2(2f32, 2f32), Vec2(0f32, 2f32)];
+    let hit = penetration(&square[..], &Aabb::new(Vec2(-1f32, 1.5f32), Vec2(3f32, 4f32))).unwrap();
+    assert_approx_eq!(hit.distance, 0.5f32, epsilon = 1e-4f32);
+    assert_approx_eq!(hit.normal, Vec2(0f32, 1f32), epsilon = 1e-4f32);
+
+    let segment = Segment::new(Vec3(-1f32, 0f32, 0f32), Vec3(1f32, 0f32, 0f32));
+    assert!(overlap(&segment, &Segment::new(Vec3(0f32, -1f32, 0f32), Vec3(0f32, 1f32, 0f32))));
+    assert_approx_eq!(distance(&segment, &Segment::new(Vec3(0f32, -1f32, 2f32), Vec3(0f32, 1f32, 2f32))).unwrap().distance, 2f32, epsilon = 1e-5f32);
+}
+
+
+#[test]
+fn transformed_shapes() {
+    // Unit square turned by 45 degrees reaches sqrt(2) along the axes
+    let diamond = Transformed {
+        shape:  Aabb::new(Vec2(-1f32, -1f32), Vec2(1f32, 1f32)),
+        matrix: Mat3::new_2d_homogeneous_rotation_mat(std::f32::consts::FRAC_PI_4),
+    }; // let ..
+    let separation = distance(&diamond, &Vec2(3f32, 0f32)).unwrap();
+    assert_approx_eq!(separation.distance, 3f32 - 2f32.sqrt(), epsilon = 1e-5f32);
+    assert_approx_eq!(separation.point_a, Vec2(2f32.sqrt(), 0f32), epsilon = 1e-5f32);
+    assert!(overlap(&diamond, &Vec2(1.2f32, 0.1f32)));
+    assert!(!overlap(&diamond, &Vec2(1f32, 1f32)));
+
+    // Scaled then moved
+    let stretched = Transformed {
+        shape:  Aabb::new(Vec2(-1f32, -1f32), Vec2(1f32, 1f32)),
+        matrix: Mat3::new_2d_homogeneous_translation_mat(Vec2(10f32, 0f32)) * Mat3::new_2d_homogeneous_scaling_mat(Vec2(3f32, 1f32)),
+    }; // let ..
+    assert_approx_eq!(distance(&stretched, &Vec2(0f32, 0f32)).unwrap().distance, 7f32, epsilon = 1e-5f32);
+    assert_approx_eq!(distance(&stretched, &Vec2(10f32, 4f32)).unwrap().distance, 3f32, epsilon = 1e-5f32);
+
+    let moved = Transformed { shape: Ball::new(Vec3(0f32, 0f32, 0f32), 1f32), matrix: translation(Vec3(5f32, 0f32, 0f32)) };
+    let separation = distance(&Ball::new(Vec3(0f32, 0f32, 0f32), 1f32), &moved).unwrap();
+    assert_approx_eq!(separation.distance, 3f32, epsilon = 1e-3f32);
+    assert_approx_eq!(separation.point_b, Vec3(4f32, 0f32, 0f32), epsilon = 1e-2f32);
+
+    let hit = penetration(&Ball::new(Vec3(4.5f32, 0f32, 0f32), 1f32), &moved).unwrap();
+    // The polytope only approaches round shapes
+    assert_approx_eq!(hit.distance, 1.5f32, epsilon = 1e-2f32);
+    assert_approx_eq!(hit.normal, Vec3(1f32, 0f32, 0f32), epsilon = 2e-2f32);
+}
+
+
+#[test]
+fn touching_shapes_overlap() {
+    let a = Aabb::new(Vec3(0f32, 0f32, 0f32), Vec3(1f32, 1f32, 1f32));
+    let b = Aabb::new(Vec3(1f32, 0f32, 0f32), Vec3(2f32, 1f32, 1f32));
+    assert!(overlap(&a, &b));
+    assert_approx_eq!(penetration(&a, &b).unwrap().distance, 0f32, epsilon = 1e-5f32);
+    assert!(overlap(&a, &a));
+}
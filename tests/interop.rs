@@ -0,0 +1,92 @@
+#![cfg(any(feature = "mint", feature = "glam", feature = "nalgebra"))]
+
+use lofi_maths::vectors::{Vec2, Vec3, Vec4};
+use lofi_maths::matrices::{Mat3, Mat4, Affine2};
+
+
+/// Column `i` holds `4i + 1 ..= 4i + 4`, so any transposition is visible
+fn sample_mat4() -> Mat4<f32> {
+    Mat4(
+        Vec4(1f32,  2f32,  3f32,  4f32),
+        Vec4(5f32,  6f32,  7f32,  8f32),
+        Vec4(9f32,  10f32, 11f32, 12f32),
+        Vec4(13f32, 14f32, 15f32, 16f32),
+    ) // Mat4()
+}
+
+
+fn sample_mat3() -> Mat3<f32> {
+    Mat3::new_2d_homogeneous_translation_mat(Vec2(7f32, -3f32)) * Mat3::new_2d_homogeneous_scaling_mat(Vec2(2f32, 5f32))
+}
+
+
+fn sample_affine2() -> Affine2<f32> {
+    Affine2(Vec2(1f32, 2f32), Vec2(3f32, 4f32), Vec2(5f32, 6f32))
+}
+
+
+#[cfg(feature = "mint")]
+#[test]
+fn mint_round_trip() {
+    let vec2: mint::Vector2<i16> = Vec2(1i16, -2i16).into();
+    let vec4: mint::Vector4<u8>  = Vec4(1u8, 2u8, 3u8, 4u8).into();
+    let mat4: mint::ColumnMatrix4<f32> = sample_mat4().into();
+
+    assert_eq!((vec2.x, vec2.y), (1i16, -2i16));
+    assert_eq!((vec4.x, vec4.y, vec4.z, vec4.w), (1u8, 2u8, 3u8, 4u8));
+    assert_eq!((mat4.y.x, mat4.w.z), (5f32, 15f32));
+
+    assert_eq!(Vec2::from(vec2), Vec2(1i16, -2i16));
+    assert_eq!(Vec3::from(mint::Point3 { x: 1i32, y: 2i32, z: 3i32 }), Vec3(1i32, 2i32, 3i32));
+    assert_eq!(Mat4::from(mat4), sample_mat4());
+    assert_eq!(Mat3::from(mint::ColumnMatrix3::from(sample_mat3())), sample_mat3());
+
+    let affine2: mint::ColumnMatrix2x3<f32> = sample_affine2().into();
+    assert_eq!((affine2.x.y, affine2.y.x, affine2.z.x), (2f32, 3f32, 5f32));
+    assert_eq!(Affine2::from(affine2), sample_affine2());
+}
+
+
+#[cfg(feature = "glam")]
+#[test]
+fn glam_round_trip() {
+    assert_eq!(glam::Vec3::from(Vec3(1f32, 2f32, 3f32)), glam::Vec3::new(1f32, 2f32, 3f32));
+    assert_eq!(glam::IVec2::from(Vec2(-4i32, 9i32)), glam::IVec2::new(-4i32, 9i32));
+    assert_eq!(Vec4::from(glam::U8Vec4::new(1u8, 2u8, 3u8, 4u8)), Vec4(1u8, 2u8, 3u8, 4u8));
+    assert_eq!(Vec2::from(glam::I16Vec2::new(-1i16, 1i16)), Vec2(-1i16, 1i16));
+
+    let mat4 = glam::Mat4::from(sample_mat4());
+    assert_eq!(mat4.col(1usize), glam::Vec4::new(5f32, 6f32, 7f32, 8f32));
+    assert_eq!(mat4.to_cols_array(), <[f32; 16usize]>::from(sample_mat4()));
+    assert_eq!(Mat4::from(mat4), sample_mat4());
+
+    let mat3 = glam::Mat3::from(sample_mat3());
+    assert_eq!(mat3.transform_point2(glam::Vec2::new(1f32, 1f32)), glam::Vec2::new(9f32, 2f32));
+    assert_eq!((sample_mat3() * Vec2(1f32, 1f32)).xy(), Vec2(9f32, 2f32));
+    assert_eq!(Mat3::from(mat3), sample_mat3());
+
+    let affine2 = glam::Affine2::from(sample_affine2());
+    assert_eq!(affine2.translation, glam::Vec2::new(5f32, 6f32));
+    assert_eq!(affine2.transform_point2(glam::Vec2::new(1f32, 1f32)), glam::Vec2::new(9f32, 12f32));
+    assert_eq!(sample_affine2() * Vec2(1f32, 1f32), Vec2(9f32, 12f32));
+    assert_eq!(Affine2::from(affine2), sample_affine2());
+}
+
+
+#[cfg(feature = "nalgebra")]
+#[test]
+fn nalgebra_round_trip() {
+    assert_eq!(nalgebra::Vector3::from(Vec3(1i32, 2i32, 3i32)), nalgebra::Vector3::new(1i32, 2i32, 3i32));
+    assert_eq!(Vec2::from(nalgebra::Vector2::new(5u16, 6u16)), Vec2(5u16, 6u16));
+    assert_eq!(Vec3::from(nalgebra::Point3::new(1f32, 2f32, 3f32)), Vec3(1f32, 2f32, 3f32));
+
+    let mat4 = nalgebra::Matrix4::from(sample_mat4());
+    assert_eq!(mat4[(0usize, 1usize)], 5f32);
+    assert_eq!(mat4[(1usize, 0usize)], 2f32);
+    assert_eq!(mat4.as_slice(), &<[f32; 16usize]>::from(sample_mat4()));
+    assert_eq!(Mat4::from(mat4), sample_mat4());
+
+    let mat3 = nalgebra::Matrix3::from(sample_mat3());
+    assert_eq!(mat3 * nalgebra::Vector3::new(1f32, 1f32, 1f32), nalgebra::Vector3::new(9f32, 2f32, 1f32));
+    assert_eq!(Mat3::from(mat3), sample_mat3());
+}
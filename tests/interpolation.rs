@@ -0,0 +1,95 @@
+use lofi_maths::interpolation::{Lerp, FixedLerp, inverse_lerp, remap, smoothstep, smootherstep, smoothstep8, smoothstep16, smootherstep8, smootherstep16};
+use lofi_maths::vectors::Vec3;
+use lofi_maths::Angle;
+
+
+#[test]
+fn angles_take_the_shortest_way() {
+    assert_eq!(Angle(250u8).lerp(Angle(10u8), 0.5f32), Angle(2u8));
+    assert_eq!(Angle(10u8).lerp(Angle(250u8), 0.5f32), Angle(2u8));
+    assert_eq!(Angle(0u8).lerp(Angle(100u8), 0.5f32), Angle(50u8));
+    assert_eq!(Angle(0u8).lerp(Angle(200u8), 0.5f32), Angle(228u8));
+
+    assert_eq!(Angle(250u8).lerp8(Angle(10u8), 128u8), Angle(2u8));
+    assert_eq!(Angle(10u8).lerp8(Angle(250u8), 128u8), Angle(2u8));
+    assert_eq!(Angle(250u8).lerp16(Angle(10u8), 32768u16), Angle(2u8));
+    assert_eq!(Angle(10u8).lerp16(Angle(250u8), 32768u16), Angle(2u8));
+
+    for a in 0u8..=255u8 {
+        for b in [0u8, 1u8, 127u8, 128u8, 129u8, 255u8] {
+            assert_eq!((Angle(a).lerp(Angle(b), 0f32), Angle(a).lerp(Angle(b), 1f32)), (Angle(a), Angle(b)));
+            assert_eq!((Angle(a).lerp8(Angle(b), 0u8), Angle(a).lerp8(Angle(b), u8::MAX)), (Angle(a), Angle(b)));
+            assert_eq!((Angle(a).lerp16(Angle(b), 0u16), Angle(a).lerp16(Angle(b), u16::MAX)), (Angle(a), Angle(b)));
+        } // for ..
+    } // for ..
+}
+
+
+#[test]
+fn integer_lerp_hits_the_endpoints() {
+    for (a, b) in [(u32::MAX - 1u32, 3u32), (u32::MAX, u32::MAX - 1u32), (16777217u32, 16777219u32)] {
+        assert_eq!((a.lerp(b, 0f32), a.lerp(b, 1f32), a.lerp(a, 0.3f32)), (a, b, a));
+    } // for ..
+    for (a, b) in [(i32::MIN, i32::MAX), (i32::MAX - 1i32, -5i32), (-16777217i32, 0i32)] {
+        assert_eq!((a.lerp(b, 0f32), a.lerp(b, 1f32), a.lerp(a, 0.7f32)), (a, b, a));
+    } // for ..
+    assert_eq!(0u32.lerp(u32::MAX - 1u32, 0.5f32), u32::MAX / 2u32);
+    assert_eq!(Vec3(u32::MAX - 1u32, 1u32, 0u32).lerp(Vec3(0u32, 1u32, u32::MAX - 1u32), 1f32), Vec3(0u32, 1u32, u32::MAX - 1u32));
+}
+
+
+#[test]
+fn fixed_lerp_hits_the_endpoints() {
+    for (a, b) in [(i32::MIN, i32::MAX), (i32::MAX, i32::MIN), (-7i32, 7i32), (0i32, 1i32)] {
+        assert_eq!((a.lerp8(b, 0u8), a.lerp8(b, u8::MAX)), (a, b));
+        assert_eq!((a.lerp16(b, 0u16), a.lerp16(b, u16::MAX)), (a, b));
+    } // for ..
+    for (a, b) in [(0u32, u32::MAX), (u32::MAX, 0u32)] {
+        assert_eq!((a.lerp8(b, 0u8), a.lerp8(b, u8::MAX)), (a, b));
+        assert_eq!((a.lerp16(b, 0u16), a.lerp16(b, u16::MAX)), (a, b));
+    } // for ..
+    for a in 0u8..=255u8 {
+        for b in [0u8, 1u8, 128u8, 255u8] {
+            assert_eq!((a.lerp8(b, 0u8), a.lerp8(b, u8::MAX)), (a, b));
+            assert_eq!((a as i8).lerp16(b as i8, u16::MAX), b as i8);
+        } // for ..
+    } // for ..
+
+    let (a, b) = (Vec3(0u8, 255u8, 10u8), Vec3(255u8, 0u8, 20u8));
+    assert_eq!((a.lerp8(b, 0u8), a.lerp8(b, u8::MAX)), (a, b));
+    assert_eq!(a.lerp8(b, 128u8), Vec3(128u8, 127u8, 15u8));
+}
+
+
+#[test]
+fn fixed_smoothstep_follows_the_float_one() {
+    let fixed = |value: f32, max: f32| (value * max).round() as i64;
+    for t in 0u8..=u8::MAX {
+        let x = t as f32;
+        assert!((smoothstep8(t) as i64 - fixed(smoothstep(0f32, 255f32, x), 255f32)).abs() <= 1i64, "{t}");
+        assert!((smootherstep8(t) as i64 - fixed(smootherstep(0f32, 255f32, x), 255f32)).abs() <= 1i64, "{t}");
+    } // for ..
+    for t in 0u16..=u16::MAX {
+        let x = t as f32;
+        assert!((smoothstep16(t) as i64 - fixed(smoothstep(0f32, 65535f32, x), 65535f32)).abs() <= 1i64, "{t}");
+        assert!((smootherstep16(t) as i64 - fixed(smootherstep(0f32, 65535f32, x), 65535f32)).abs() <= 1i64, "{t}");
+    } // for ..
+    assert_eq!((smoothstep8(0u8), smoothstep8(u8::MAX), smoothstep16(0u16), smoothstep16(u16::MAX)), (0u8, u8::MAX, 0u16, u16::MAX));
+    assert_eq!((smootherstep8(0u8), smootherstep8(u8::MAX), smootherstep16(0u16), smootherstep16(u16::MAX)), (0u8, u8::MAX, 0u16, u16::MAX));
+}
+
+
+#[test]
+fn inverse_lerp_and_remap() {
+    assert_eq!(inverse_lerp(2f32, 6f32, 4f32), 0.5f32);
+    assert_eq!(inverse_lerp(6f32, 2f32, 3f32), 0.75f32);
+    assert_eq!(inverse_lerp(2f32, 6f32, 10f32), 2f32);
+    assert_eq!(inverse_lerp(3f32, 3f32, 5f32), 0f32);
+
+    assert_eq!(remap(5f32, (0f32, 10f32), (100f32, 200f32)), 150f32);
+    assert_eq!(remap(5f32, (0f32, 10f32), (200f32, 100f32)), 150f32);
+    assert_eq!(remap(2.5f32, (10f32, 0f32), (0f32, 100f32)), 75f32);
+    assert_eq!(remap(-5f32, (0f32, 10f32), (0f32, 1f32)), -0.5f32);
+
+    assert_eq!((smoothstep(1f32, 3f32, 0f32), smoothstep(1f32, 3f32, 2f32), smoothstep(1f32, 3f32, 4f32)), (0f32, 0.5f32, 1f32));
+}
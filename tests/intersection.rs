@@ -0,0 +1,230 @@
+use lofi_maths::assert_approx_eq;
+use lofi_maths::traits::Vector;
+use lofi_maths::vectors::{Vec2, Vec3};
+use lofi_maths::geometry::{Ray, Segment, Line, Plane, Ball, Aabb, Triangle};
+use lofi_maths::intersection::{
+    ray_aabb, ray_sphere, ray_plane, ray_triangle, segment_segment, circle_circle,
+    ray_aabb_exact, ray_circle_exact, ray_line_exact, ray_triangle_exact, segment_segment_exact, circle_circle_exact,
+};
+
+
+fn to_f32(vector: Vec2<i32>) -> Vec2<f32> { Vec2(vector.0 as f32, vector.1 as f32) }
+
+
+fn directions() -> impl Iterator<Item = Vec2<i32>> {
+    (-2i32..=2i32).flat_map(|x| (-2i32..=2i32).map(move |y| Vec2(x, y))).filter(|&direction| direction != Vec2(0i32, 0i32))
+}
+
+
+#[test]
+fn ray_aabb_hits_grazes_and_misses() {
+    let aabb = Aabb::new(Vec3(0f32, 0f32, 0f32), Vec3(2f32, 2f32, 2f32));
+
+    let hit = ray_aabb(&Ray::new(Vec3(-2f32, 1f32, 1f32), Vec3(2f32, 0f32, 0f32)), &aabb).unwrap();
+    assert_eq!((hit.distance, hit.point, hit.normal), (1f32, Vec3(0f32, 1f32, 1f32), Vec3(-1f32, 0f32, 0f32)));
+
+    let hit = ray_aabb(&Ray::new(Vec3(1f32, 5f32, 1f32), Vec3(0f32, -1f32, 0f32)), &aabb).unwrap();
+    assert_eq!((hit.distance, hit.normal), (3f32, Vec3(0f32, 1f32, 0f32)));
+
+    // Starting inside hits at once with no normal
+    let hit = ray_aabb(&Ray::new(Vec3(1f32, 1f32, 1f32), Vec3(1f32, 0f32, 0f32)), &aabb).unwrap();
+    assert_eq!((hit.distance, hit.normal), (0f32, Vec3(0f32, 0f32, 0f32)));
+
+    // Parallel rays running along a face graze it, the ones beside it miss
+    assert!(ray_aabb(&Ray::new(Vec3(-1f32, 2f32, 1f32), Vec3(1f32, 0f32, 0f32)), &aabb).is_some());
+    assert!(ray_aabb(&Ray::new(Vec3(-1f32, 2.5f32, 1f32), Vec3(1f32, 0f32, 0f32)), &aabb).is_none());
+
+    // Touching an edge only at one point
+    let hit = ray_aabb(&Ray::new(Vec3(-1f32, 1f32, 1f32), Vec3(1f32, -1f32, 0f32)), &aabb).unwrap();
+    assert_eq!(hit.point, Vec3(0f32, 0f32, 1f32));
+
+    assert!(ray_aabb(&Ray::new(Vec3(-1f32, 1f32, 1f32), Vec3(-1f32, 0f32, 0f32)), &aabb).is_none());
+    assert!(ray_aabb(&Ray::new(Vec3(-1f32, 3f32, 1f32), Vec3(1f32, 1f32, 0f32)), &aabb).is_none());
+}
+
+
+#[test]
+fn ray_aabb_exact_agrees_with_float() {
+    let aabb = Aabb::new(Vec2(0i32, 0i32), Vec2(3i32, 2i32));
+    for x in -3i32..=6i32 {
+        for y in -3i32..=5i32 {
+            for direction in directions() {
+                let ray   = Ray::new(Vec2(x, y), direction);
+                let exact = ray_aabb_exact(&ray, &aabb);
+                let float = ray_aabb(&Ray::new(to_f32(ray.origin), to_f32(direction)), &Aabb::new(to_f32(aabb.min), to_f32(aabb.max)));
+                assert_eq!(exact.is_some(), float.is_some(), "{ray:?}");
+
+                if let (Some(exact), Some(float)) = (exact, float) {
+                    assert_approx_eq!(exact.distance(), float.distance);
+                    assert_approx_eq!(exact.point(), float.point);
+                    assert_eq!(Vec2(exact.normal.0 as f32, exact.normal.1 as f32), float.normal, "{ray:?}");
+                } // if ..
+            } // for ..
+        } // for ..
+    } // for ..
+}
+
+
+#[test]
+fn ray_triangle_faces_edges_and_parallel_rays() {
+    let triangle = Triangle(Vec3(0f32, 0f32, 0f32), Vec3(4f32, 0f32, 0f32), Vec3(0f32, 4f32, 0f32));
+
+    let hit = ray_triangle(&Ray::new(Vec3(1f32, 1f32, 3f32), Vec3(0f32, 0f32, -1f32)), &triangle).unwrap();
+    assert_eq!((hit.distance, hit.point, hit.normal), (3f32, Vec3(1f32, 1f32, 0f32), Vec3(0f32, 0f32, 1f32)));
+
+    // Back faces are hit too, the normal still facing the ray
+    let hit = ray_triangle(&Ray::new(Vec3(1f32, 1f32, -2f32), Vec3(0f32, 0f32, 1f32)), &triangle).unwrap();
+    assert_eq!((hit.distance, hit.normal), (2f32, Vec3(0f32, 0f32, -1f32)));
+
+    // Edges and vertices are included
+    assert!(ray_triangle(&Ray::new(Vec3(2f32, 0f32, 1f32), Vec3(0f32, 0f32, -1f32)), &triangle).is_some());
+    assert!(ray_triangle(&Ray::new(Vec3(2f32, 2f32, 1f32), Vec3(0f32, 0f32, -1f32)), &triangle).is_some());
+    assert!(ray_triangle(&Ray::new(Vec3(0f32, 4f32, 1f32), Vec3(0f32, 0f32, -1f32)), &triangle).is_some());
+    assert!(ray_triangle(&Ray::new(Vec3(2.5f32, 2f32, 1f32), Vec3(0f32, 0f32, -1f32)), &triangle).is_none());
+
+    // Parallel rays miss even within the plane, and so do triangles behind the ray
+    assert!(ray_triangle(&Ray::new(Vec3(-1f32, 1f32, 0f32), Vec3(1f32, 0f32, 0f32)), &triangle).is_none());
+    assert!(ray_triangle(&Ray::new(Vec3(-1f32, 1f32, 1f32), Vec3(1f32, 0f32, 0f32)), &triangle).is_none());
+    assert!(ray_triangle(&Ray::new(Vec3(1f32, 1f32, 3f32), Vec3(0f32, 0f32, 1f32)), &triangle).is_none());
+}
+
+
+#[test]
+fn ray_triangle_exact_in_the_plane() {
+    let triangle = Triangle(Vec2(0i32, 0i32), Vec2(4i32, 0i32), Vec2(0i32, 4i32));
+
+    let hit = ray_triangle_exact(&Ray::new(Vec2(-2i32, 1i32), Vec2(1i32, 0i32)), &triangle).unwrap();
+    assert_eq!((hit.distance(), hit.point()), (2f32, Vec2(0f32, 1f32)));
+    assert!(hit.normal.0 < 0i128 && hit.normal.1 == 0i128);
+
+    let hit = ray_triangle_exact(&Ray::new(Vec2(5i32, 5i32), Vec2(-1i32, -1i32)), &triangle).unwrap();
+    assert_eq!(hit.point(), Vec2(2f32, 2f32));
+    assert!(hit.normal.0 > 0i128 && hit.normal.0 == hit.normal.1);
+
+    let hit = ray_triangle_exact(&Ray::new(Vec2(1i32, 1i32), Vec2(1i32, 0i32)), &triangle).unwrap();
+    assert_eq!((hit.time, hit.normal), (0i128, Vec2(0i128, 0i128)));
+
+    // Grazing a vertex, then running beside an edge
+    assert!(ray_triangle_exact(&Ray::new(Vec2(-1i32, 4i32), Vec2(1i32, 0i32)), &triangle).is_some());
+    assert!(ray_triangle_exact(&Ray::new(Vec2(-1i32, 5i32), Vec2(1i32, 0i32)), &triangle).is_none());
+    assert!(ray_triangle_exact(&Ray::new(Vec2(-1i32, -1i32), Vec2(1i32, 0i32)), &triangle).is_none());
+    assert!(ray_triangle_exact(&Ray::new(Vec2(-1i32, 1i32), Vec2(-1i32, 0i32)), &triangle).is_none());
+}
+
+
+#[test]
+fn segments_against_planes_and_lines() {
+    let plane = Plane::new(Vec3(0f32, 0f32, 2f32), 4f32);
+
+    let hit = ray_plane(&Ray::new(Vec3(1f32, 1f32, 6f32), Vec3(0f32, 0f32, -2f32)), &plane).unwrap();
+    assert_eq!((hit.distance, hit.point, hit.normal), (2f32, Vec3(1f32, 1f32, 2f32), Vec3(0f32, 0f32, 1f32)));
+
+    let hit = ray_plane(&Ray::new(Vec3(0f32, 0f32, 0f32), Vec3(1f32, 0f32, 1f32)), &plane).unwrap();
+    assert_eq!((hit.distance, hit.normal), (2f32, Vec3(0f32, 0f32, -1f32)));
+
+    // Segments from `start` to `start + direction` cross the plane when the hit lies within one
+    let segment = Segment::new(Vec3(0f32, 0f32, 0f32), Vec3(0f32, 0f32, 1.5f32));
+    assert!(ray_plane(&Ray::new(segment.start, segment.end - segment.start), &plane).is_some_and(|hit| hit.distance > 1f32));
+    let segment = Segment::new(Vec3(0f32, 0f32, 0f32), Vec3(0f32, 0f32, 2f32));
+    assert!(ray_plane(&Ray::new(segment.start, segment.end - segment.start), &plane).is_some_and(|hit| hit.distance == 1f32));
+
+    // Parallel rays never hit, pointing away neither
+    assert!(ray_plane(&Ray::new(Vec3(0f32, 0f32, 2f32), Vec3(1f32, 0f32, 0f32)), &plane).is_none());
+    assert!(ray_plane(&Ray::new(Vec3(0f32, 0f32, 3f32), Vec3(1f32, 1f32, 0f32)), &plane).is_none());
+    assert!(ray_plane(&Ray::new(Vec3(0f32, 0f32, 3f32), Vec3(0f32, 0f32, 1f32)), &plane).is_none());
+
+    let line = Line::new(Vec2(0i32, 2i32), Vec2(3i32, 0i32));
+    let hit  = ray_line_exact(&Ray::new(Vec2(1i32, -1i32), Vec2(1i32, 2i32)), &line).unwrap();
+    assert_eq!((hit.distance(), hit.point()), (1.5f32, Vec2(2.5f32, 2f32)));
+    assert!(hit.normal.1 < 0i128 && hit.normal.0 == 0i128);
+    assert!(ray_line_exact(&Ray::new(Vec2(1i32, 1i32), Vec2(1i32, 0i32)), &line).is_none());
+    assert_eq!(ray_line_exact(&Ray::new(Vec2(7i32, 2i32), Vec2(1i32, 0i32)), &line).map(|hit| hit.time), Some(0i128));
+}
+
+
+#[test]
+fn segment_segment_exact_agrees_with_float() {
+    let a = Segment::new(Vec2(0f32, 0f32), Vec2(4f32, 4f32));
+    let hit = segment_segment(&a, &Segment::new(Vec2(0f32, 4f32), Vec2(4f32, 0f32))).unwrap();
+    assert_eq!((hit.distance, hit.point), (0.5f32, Vec2(2f32, 2f32)));
+    assert!(segment_segment(&a, &Segment::new(Vec2(1f32, 0f32), Vec2(5f32, 4f32))).is_none());
+    assert_eq!(segment_segment(&a, &Segment::new(Vec2(2f32, 2f32), Vec2(6f32, 6f32))).map(|hit| hit.distance), Some(0.5f32));
+
+    let starts = [Vec2(0i32, 0i32), Vec2(-2i32, 1i32), Vec2(3i32, -1i32), Vec2(1i32, 1i32)];
+    let ends   = [Vec2(4i32, 2i32), Vec2(2i32, 1i32), Vec2(3i32, 3i32), Vec2(-1i32, -1i32), Vec2(0i32, 0i32)];
+    let others = [
+        Segment::new(Vec2(0i32, 2i32), Vec2(4i32, 0i32)),
+        Segment::new(Vec2(2i32, 1i32), Vec2(6i32, 3i32)),
+        Segment::new(Vec2(1i32, -2i32), Vec2(1i32, 4i32)),
+        Segment::new(Vec2(-3i32, 1i32), Vec2(5i32, 1i32)),
+    ];
+    for start in starts {
+        for end in ends {
+            for b in others {
+                let a     = Segment::new(start, end);
+                let exact = segment_segment_exact(&a, &b);
+                let float = segment_segment(&Segment::new(to_f32(a.start), to_f32(a.end)), &Segment::new(to_f32(b.start), to_f32(b.end)));
+                assert_eq!(exact.is_some(), float.is_some(), "{a:?} {b:?}");
+                if let (Some(exact), Some(float)) = (exact, float) {
+                    assert_approx_eq!(exact.distance(), float.distance);
+                    assert_approx_eq!(exact.point(), float.point);
+                } // if ..
+            } // for ..
+        } // for ..
+    } // for ..
+}
+
+
+#[test]
+fn balls_touch_overlap_and_miss() {
+    let (a, b) = (Ball::new(Vec2(0f32, 0f32), 2f32), Ball::new(Vec2(3f32, 0f32), 2f32));
+    let hit    = circle_circle(&a, &b).unwrap();
+    assert_eq!((hit.distance, hit.point, hit.normal), (1f32, Vec2(1.5f32, 0f32), Vec2(1f32, 0f32)));
+
+    let touching = circle_circle(&a, &Ball::new(Vec2(0f32, 4f32), 2f32)).unwrap();
+    assert_eq!((touching.distance, touching.point, touching.normal), (0f32, Vec2(0f32, 2f32), Vec2(0f32, 1f32)));
+    assert!(circle_circle(&a, &Ball::new(Vec2(3f32, 3f32), 2f32)).is_none());
+
+    let concentric = circle_circle(&a, &Ball::new(Vec2(0f32, 0f32), 1f32)).unwrap();
+    assert_eq!((concentric.distance, concentric.normal), (3f32, Vec2(1f32, 0f32)));
+
+    let spheres = circle_circle(&Ball::new(Vec3(0f32, 0f32, 0f32), 1f32), &Ball::new(Vec3(0f32, 0f32, 1.5f32), 1f32)).unwrap();
+    assert_eq!((spheres.distance, spheres.normal), (0.5f32, Vec3(0f32, 0f32, 1f32)));
+
+    for x in -6i32..=6i32 {
+        for y in -6i32..=6i32 {
+            let (a, b) = (Ball::new(Vec2(0i32, 0i32), 3i32), Ball::new(Vec2(x, y), 2i32));
+            let exact  = circle_circle_exact(&a, &b);
+            let float  = circle_circle(&Ball::new(to_f32(a.center), 3f32), &Ball::new(to_f32(b.center), 2f32));
+            assert_eq!(exact.is_some(), float.is_some(), "{x} {y}");
+
+            if let (Some(exact), Some(float)) = (exact, float) {
+                // Rounded down by less than a unit
+                assert!(float.distance >= exact.distance as f32 && float.distance < exact.distance as f32 + 1f32, "{x} {y}");
+                if (x, y) != (0i32, 0i32) { assert_approx_eq!(to_f32(exact.normal).normalized(), float.normal) }
+            } // if ..
+        } // for ..
+    } // for ..
+}
+
+
+#[test]
+fn ray_circle_exact_agrees_with_float() {
+    let circle = Ball::new(Vec2(2i32, 1i32), 3i32);
+    for x in -6i32..=8i32 {
+        for y in -6i32..=8i32 {
+            for direction in directions() {
+                let ray   = Ray::new(Vec2(x, y), direction);
+                let exact = ray_circle_exact(&ray, &circle);
+                let float = ray_sphere(&Ray::new(to_f32(ray.origin), to_f32(direction)), &Ball::new(to_f32(circle.center), 3f32));
+                assert_eq!(exact.is_some(), float.is_some(), "{ray:?}");
+
+                // Irrational hits are rounded towards the origin by less than one step of `1 / |direction|²`
+                if let (Some(exact), Some(float)) = (exact, float) {
+                    let step = 1f32 / (direction.0 * direction.0 + direction.1 * direction.1) as f32;
+                    assert!(exact.distance() <= float.distance + 1e-5f32 && exact.distance() >= float.distance - step, "{ray:?}");
+                } // if ..
+            } // for ..
+        } // for ..
+    } // for ..
+}
@@ -0,0 +1,69 @@
+use std::mem::{size_of, align_of};
+
+use lofi_maths::Angle;
+use lofi_maths::vectors::{Vec2, Vec3, Vec4};
+use lofi_maths::matrices::{Mat3, Mat4, Affine2};
+
+
+#[test]
+fn vectors_are_tightly_packed() {
+    assert_eq!(size_of::<Vec2<f32>>(), 8usize);
+    assert_eq!(size_of::<Vec3<f32>>(), 12usize);
+    assert_eq!(size_of::<Vec4<f32>>(), 16usize);
+    assert_eq!(size_of::<Vec2<i16>>(), 4usize);
+    assert_eq!(size_of::<Vec3<u8>>(), 3usize);
+    assert_eq!(size_of::<Vec4<u8>>(), 4usize);
+
+    assert_eq!(align_of::<Vec2<f32>>(), align_of::<f32>());
+    assert_eq!(align_of::<Vec3<f32>>(), align_of::<f32>());
+    assert_eq!(align_of::<Vec4<f32>>(), align_of::<f32>());
+    assert_eq!(align_of::<Vec2<i16>>(), align_of::<i16>());
+    assert_eq!(align_of::<Vec4<u8>>(), align_of::<u8>());
+}
+
+
+#[test]
+fn matrices_are_tightly_packed() {
+    assert_eq!(size_of::<Mat3<f32>>(), 36usize);
+    assert_eq!(size_of::<Mat4<f32>>(), 64usize);
+    assert_eq!(size_of::<Affine2<f32>>(), 24usize);
+    assert_eq!(align_of::<Mat3<f32>>(), align_of::<f32>());
+    assert_eq!(align_of::<Mat4<f32>>(), align_of::<f32>());
+    assert_eq!(align_of::<Affine2<f32>>(), align_of::<f32>());
+}
+
+
+#[test]
+fn angle_is_a_single_byte() {
+    assert_eq!(size_of::<Angle>(), 1usize);
+    assert_eq!(align_of::<Angle>(), 1usize);
+}
+
+
+#[cfg(feature = "bytemuck")]
+#[test]
+fn vector_slices_cast_to_bytes() {
+    let vertices = [Vec2(1f32, 2f32), Vec2(3f32, 4f32)];
+    let bytes: &[u8] = bytemuck::cast_slice(&vertices);
+
+    assert_eq!(bytes.len(), 16usize);
+    assert_eq!(&bytes[4usize..8usize], &2f32.to_ne_bytes());
+    assert_eq!(bytemuck::cast_slice::<u8, Vec2<f32>>(bytes), &vertices);
+}
+
+
+#[cfg(feature = "bytemuck")]
+#[test]
+fn matrices_cast_to_column_major_floats() {
+    let mat4 = Mat4::transform_2d_to_transform_3d(Mat3::new_2d_homogeneous_translation_mat(Vec2(5f32, 6f32)));
+    let floats: &[f32; 16usize] = bytemuck::cast_ref(&mat4);
+
+    assert_eq!(*floats, <[f32; 16usize]>::from(mat4));
+    assert_eq!(bytemuck::bytes_of(&mat4).len(), 64usize);
+    assert_eq!(bytemuck::bytes_of(&Angle(42u8)), &[42u8]);
+    assert_eq!(<Vec3<i32> as bytemuck::Zeroable>::zeroed(), Vec3(0i32, 0i32, 0i32));
+
+    let affine2 = Affine2(Vec2(1f32, 2f32), Vec2(3f32, 4f32), Vec2(5f32, 6f32));
+    assert_eq!(*bytemuck::cast_ref::<Affine2<f32>, [f32; 6usize]>(&affine2), [1f32, 2f32, 3f32, 4f32, 5f32, 6f32]);
+    assert_eq!(<Affine2<i32> as bytemuck::Zeroable>::zeroed(), Affine2(Vec2(0i32, 0i32), Vec2(0i32, 0i32), Vec2(0i32, 0i32)));
+}
@@ -0,0 +1,240 @@
+use lofi_maths::vectors::Vec2;
+use lofi_maths::predicates::{Orientation, orient2d};
+use lofi_maths::polygon::{
+    FillRule, convex_hull, convex_overlap, minimum_translation, clip_polygon, minkowski_sum,
+    twice_signed_area, winding, set_winding, centroid, winding_number, contains_point, triangulate, simplify,
+};
+
+
+fn square(min: Vec2<i32>, size: i32) -> Vec<Vec2<i32>> {
+    vec![min, Vec2(min.0 + size, min.1), Vec2(min.0 + size, min.1 + size), Vec2(min.0, min.1 + size)]
+}
+
+
+fn inside_convex(polygon: &[Vec2<i32>], point: Vec2<i32>) -> bool {
+    (0usize..polygon.len()).all(|i| orient2d(polygon[i], polygon[(i + 1usize) % polygon.len()], point) != Orientation::Clockwise)
+}
+
+
+#[test]
+fn hulls() {
+    let points = [Vec2(2i32, 2i32), Vec2(0i32, 0i32), Vec2(4i32, 4i32), Vec2(2i32, 0i32), Vec2(4i32, 0i32), Vec2(0i32, 4i32), Vec2(0i32, 4i32), Vec2(1i32, 3i32), Vec2(4i32, 2i32)];
+    assert_eq!(convex_hull(&points), vec![Vec2(0i32, 0i32), Vec2(4i32, 0i32), Vec2(4i32, 4i32), Vec2(0i32, 4i32)]);
+
+    assert_eq!(convex_hull(&[Vec2(3i32, 3i32), Vec2(1i32, 1i32), Vec2(2i32, 2i32)]), vec![Vec2(1i32, 1i32), Vec2(3i32, 3i32)]);
+    assert_eq!(convex_hull(&[Vec2(1i32, 1i32), Vec2(1i32, 1i32)]), vec![Vec2(1i32, 1i32)]);
+    assert!(convex_hull::<i32>(&[]).is_empty());
+
+    let hull = convex_hull(&[Vec2(0.5f32, 0f32), Vec2(0f32, 1f32), Vec2(-0.5f32, 0f32), Vec2(0f32, 0.25f32)]);
+    assert_eq!(hull, vec![Vec2(-0.5f32, 0f32), Vec2(0.5f32, 0f32), Vec2(0f32, 1f32)]);
+}
+
+
+#[test]
+fn separating_axes() {
+    let a = square(Vec2(0i32, 0i32), 4i32);
+    assert!(convex_overlap(&a, &square(Vec2(3i32, 1i32), 4i32)));
+    assert!(convex_overlap(&a, &square(Vec2(4i32, 4i32), 2i32)));
+    assert!(convex_overlap(&a, &square(Vec2(1i32, 1i32), 1i32)));
+    assert!(!convex_overlap(&a, &square(Vec2(5i32, 0i32), 2i32)));
+
+    // Triangles whose bounding boxes overlap but whose diagonal edges separate them
+    let (left, right) = ([Vec2(0i32, 0i32), Vec2(4i32, 0i32), Vec2(0i32, 4i32)], [Vec2(5i32, 1i32), Vec2(5i32, 5i32), Vec2(1i32, 5i32)]);
+    assert!(!convex_overlap(&left, &right));
+    assert_eq!(minimum_translation(&left, &right), None);
+}
+
+
+#[test]
+fn minimum_translation_separates() {
+    let a = square(Vec2(0i32, 0i32), 4i32);
+    assert_eq!(minimum_translation(&a, &[Vec2(3i32, 1i32), Vec2(7i32, 1i32), Vec2(7i32, 3i32), Vec2(3i32, 3i32)]), Some(Vec2(1f32, 0f32)));
+    assert_eq!(minimum_translation(&a, &[Vec2(1i32, -2i32), Vec2(3i32, -2i32), Vec2(3i32, 1i32), Vec2(1i32, 1i32)]), Some(Vec2(0f32, -1f32)));
+    assert_eq!(minimum_translation(&a, &square(Vec2(4i32, 1i32), 2i32)), Some(Vec2(0f32, 0f32)));
+
+    // Moving by the translation leaves the shapes touching, a little more separates them
+    let b           = [Vec2(2f32, 3f32), Vec2(6f32, 5f32), Vec2(3f32, 7f32)];
+    let a           = [Vec2(0f32, 0f32), Vec2(4f32, 0f32), Vec2(4f32, 4f32), Vec2(0f32, 4f32)];
+    let translation = minimum_translation(&a, &b).unwrap();
+    let moved       = b.map(|point| point + translation * 1.001f32);
+    assert!(!convex_overlap(&a, &moved));
+    assert!(translation.1 > 0f32);
+}
+
+
+#[test]
+fn clipping_stays_in_the_window() {
+    let window  = [Vec2(0i32, 0i32), Vec2(10i32, 0i32), Vec2(0i32, 10i32)];
+    let subject = [Vec2(-3i32, -1i32), Vec2(13i32, 2i32), Vec2(4i32, 13i32), Vec2(-2i32, 7i32)];
+    let clipped = clip_polygon(&subject, &window);
+    assert!(clipped.len() >= 3usize);
+    for &point in &clipped { assert!(inside_convex(&window, point), "{point:?} left the window") }
+
+    // Every rounded vertex stays within a unit of a float one, rounded crossings may merge
+    let float = clip_polygon(&subject.map(|point| Vec2(point.0 as f32, point.1 as f32)), &window.map(|point| Vec2(point.0 as f32, point.1 as f32)));
+    for rounded in &clipped {
+        assert!(float.iter().any(|exact| (rounded.0 as f32 - exact.0).abs() <= 1f32 && (rounded.1 as f32 - exact.1).abs() <= 1f32), "{rounded:?}");
+    } // for ..
+
+    for shift in -6i32..6i32 {
+        for size in [3i32, 7i32, 13i32] {
+            let subject = [Vec2(shift, -shift), Vec2(shift + size, 1i32), Vec2(2i32, shift + size), Vec2(-size, 3i32)];
+            for &point in &clip_polygon(&subject, &window) { assert!(inside_convex(&window, point), "{subject:?}: {point:?}") }
+        } // for ..
+    } // for ..
+}
+
+
+#[test]
+fn clipping_exact_cases() {
+    let window = square(Vec2(0i32, 0i32), 4i32);
+    assert_eq!(clip_polygon(&square(Vec2(2i32, 2i32), 4i32), &window), vec![Vec2(2i32, 4i32), Vec2(2i32, 2i32), Vec2(4i32, 2i32), Vec2(4i32, 4i32)]);
+    assert_eq!(clip_polygon(&square(Vec2(1i32, 1i32), 2i32), &window), square(Vec2(1i32, 1i32), 2i32));
+    assert!(clip_polygon(&square(Vec2(5i32, 5i32), 2i32), &window).is_empty());
+
+    let window  = [Vec2(0f32, 0f32), Vec2(2f32, 0f32), Vec2(0f32, 2f32)];
+    let clipped = clip_polygon(&[Vec2(0f32, 0f32), Vec2(2f32, 0f32), Vec2(2f32, 2f32), Vec2(0f32, 2f32)], &window);
+    assert_eq!(clipped, vec![Vec2(0f32, 0f32), Vec2(2f32, 0f32), Vec2(0f32, 2f32)]);
+}
+
+
+#[test]
+fn minkowski_sums() {
+    let square   = square(Vec2(0i32, 0i32), 1i32);
+    let triangle = [Vec2(0i32, 0i32), Vec2(2i32, 0i32), Vec2(0i32, 2i32)];
+    assert_eq!(minkowski_sum(&square, &triangle), vec![Vec2(0i32, 0i32), Vec2(3i32, 0i32), Vec2(3i32, 1i32), Vec2(1i32, 3i32), Vec2(0i32, 3i32)]);
+
+    // Matches the hull of every pairwise sum, whatever vertex each polygon starts from
+    let a = [Vec2(2i32, 1i32), Vec2(5i32, 3i32), Vec2(1i32, 4i32)];
+    let b = [Vec2(-1i32, 0i32), Vec2(0i32, -2i32), Vec2(2i32, 0i32), Vec2(0i32, 1i32)];
+    let sums = a.iter().flat_map(|&p| b.iter().map(move |&q| p + q)).collect::<Vec<_>>();
+    let hull = convex_hull(&sums);
+    for rotation in 0usize..b.len() {
+        let rotated = b[rotation..].iter().chain(&b[..rotation]).copied().collect::<Vec<_>>();
+        let sum     = minkowski_sum(&a, &rotated);
+        assert_eq!(sum.len(), hull.len());
+        assert!(sum.iter().all(|point| hull.contains(point)));
+    } // for ..
+
+    assert!(minkowski_sum(&a, &[]).is_empty());
+    assert_eq!(minkowski_sum(&a, &[Vec2(1i32, 1i32)]).len(), 3usize);
+}
+
+
+/// Checks that the triangles wind counter-clockwise and add up to the area of the polygon
+fn assert_covers(polygon: &[Vec2<i32>], triangles: &[[usize; 3]]) {
+    let mut area = 0i128;
+    for &[a, b, c] in triangles {
+        assert_eq!(orient2d(polygon[a], polygon[b], polygon[c]), Orientation::CounterClockwise);
+        area += twice_signed_area(&[polygon[a], polygon[b], polygon[c]]);
+    } // for ..
+    assert_eq!(area, twice_signed_area(polygon).abs());
+}
+
+
+#[test]
+fn triangulation() {
+    let square = square(Vec2(0i32, 0i32), 4i32);
+    let triangles = triangulate(&square).unwrap();
+    assert_eq!(triangles.len(), 2usize);
+    assert_covers(&square, &triangles);
+
+    let mut clockwise = square.clone();
+    clockwise.reverse();
+    assert_covers(&clockwise, &triangulate(&clockwise).unwrap());
+
+    // Concave, with a flat corner in the middle of the bottom edge
+    let shape = [Vec2(0i32, 0i32), Vec2(3i32, 0i32), Vec2(6i32, 0i32), Vec2(6i32, 2i32), Vec2(2i32, 2i32), Vec2(2i32, 6i32), Vec2(0i32, 6i32)];
+    let triangles = triangulate(&shape).unwrap();
+    assert_covers(&shape, &triangles);
+    assert!(triangles.len() <= shape.len() - 2usize);
+
+    assert_eq!(triangulate(&[Vec2(0i32, 0i32), Vec2(2i32, 0i32), Vec2(0i32, 2i32)]), Some(vec![[0usize, 1usize, 2usize]]));
+    assert_eq!(triangulate(&[Vec2(0i32, 0i32), Vec2(2i32, 2i32)]), Some(Vec::new()));
+    assert_eq!(triangulate(&[Vec2(0i32, 0i32), Vec2(1i32, 1i32), Vec2(2i32, 2i32), Vec2(3i32, 3i32)]), Some(Vec::new()));
+}
+
+
+#[test]
+fn crossing_polygons_have_no_triangulation() {
+    assert_eq!(triangulate(&[Vec2(0i32, 0i32), Vec2(10i32, 10i32), Vec2(10i32, 0i32), Vec2(0i32, 10i32)]), None);
+    assert_eq!(triangulate(&[Vec2(0i32, 0i32), Vec2(10i32, 0i32), Vec2(0i32, 10i32), Vec2(10i32, 10i32)]), None);
+    assert_eq!(triangulate(&[Vec2(0i32, 0i32), Vec2(6i32, 0i32), Vec2(6i32, 6i32), Vec2(3i32, -3i32), Vec2(0i32, 6i32)]), None);
+}
+
+
+#[test]
+fn windings() {
+    let mut polygon = square(Vec2(1i32, 1i32), 3i32);
+    assert_eq!((twice_signed_area(&polygon), winding(&polygon)), (18i128, Orientation::CounterClockwise));
+
+    set_winding(&mut polygon, Orientation::Clockwise);
+    assert_eq!((twice_signed_area(&polygon), winding(&polygon)), (-18i128, Orientation::Clockwise));
+    assert_eq!(polygon[0usize], Vec2(1i32, 4i32));
+    set_winding(&mut polygon, Orientation::Clockwise);
+    assert_eq!(winding(&polygon), Orientation::Clockwise);
+
+    let mut flat = vec![Vec2(0i32, 0i32), Vec2(2i32, 2i32), Vec2(1i32, 1i32)];
+    set_winding(&mut flat, Orientation::CounterClockwise);
+    assert_eq!((winding(&flat), flat[0usize]), (Orientation::Collinear, Vec2(0i32, 0i32)));
+
+    let large = [Vec2(i32::MIN, i32::MIN), Vec2(i32::MAX, i32::MIN), Vec2(i32::MAX, i32::MAX), Vec2(i32::MIN, i32::MAX)];
+    assert_eq!(twice_signed_area(&large), 2i128 * (u32::MAX as i128) * (u32::MAX as i128));
+}
+
+
+#[test]
+fn centroids() {
+    assert_eq!(centroid(&[Vec2(0i32, 0i32), Vec2(4i32, 0i32), Vec2(4i32, 2i32), Vec2(0i32, 2i32)]), Some(Vec2(2f32, 1f32)));
+    assert_eq!(centroid(&[Vec2(0i32, 0i32), Vec2(0i32, 3i32), Vec2(3i32, 0i32)]), Some(Vec2(1f32, 1f32)));
+    assert_eq!(centroid(&[Vec2(10f32, 10f32), Vec2(13f32, 10f32), Vec2(10f32, 13f32)]), Some(Vec2(11f32, 11f32)));
+
+    // L shape made of a 4x2 and a 2x2 square
+    let shape = [Vec2(0i32, 0i32), Vec2(4i32, 0i32), Vec2(4i32, 2i32), Vec2(2i32, 2i32), Vec2(2i32, 4i32), Vec2(0i32, 4i32)];
+    let Some(Vec2(x, y)) = centroid(&shape) else { panic!("the shape has an area") };
+    assert!((x - 5f32 / 3f32).abs() < 1e-6f32 && (y - 5f32 / 3f32).abs() < 1e-6f32);
+
+    assert_eq!(centroid(&[Vec2(0i32, 0i32), Vec2(1i32, 1i32), Vec2(2i32, 2i32)]), None);
+    assert_eq!(centroid::<i32>(&[]), None);
+}
+
+
+#[test]
+fn winding_numbers_and_fill_rules() {
+    let square = square(Vec2(0i32, 0i32), 4i32);
+    assert_eq!(winding_number(&square, Vec2(2i32, 2i32)), 1i32);
+    assert_eq!(winding_number(&square, Vec2(5i32, 2i32)), 0i32);
+    assert_eq!(winding_number(&square.iter().rev().copied().collect::<Vec<_>>(), Vec2(2i32, 2i32)), -1i32);
+
+    // Going around twice
+    let twice = [square.as_slice(), square.as_slice()].concat();
+    assert_eq!(winding_number(&twice, Vec2(2i32, 2i32)), 2i32);
+    assert!(contains_point(&twice, Vec2(2i32, 2i32), FillRule::NonZero));
+    assert!(!contains_point(&twice, Vec2(2i32, 2i32), FillRule::EvenOdd));
+
+    // Vertices and edges count as inside, whatever the rule
+    for point in [Vec2(0i32, 0i32), Vec2(4i32, 2i32), Vec2(2i32, 4i32)] {
+        assert!(contains_point(&square, point, FillRule::NonZero) && contains_point(&square, point, FillRule::EvenOdd));
+    } // for ..
+    assert!(!contains_point(&square, Vec2(-1i32, 2i32), FillRule::NonZero));
+
+    // Rays through vertices are counted once
+    let diamond = [Vec2(2i32, 0i32), Vec2(4i32, 2i32), Vec2(2i32, 4i32), Vec2(0i32, 2i32)];
+    assert_eq!((winding_number(&diamond, Vec2(1i32, 2i32)), winding_number(&diamond, Vec2(-1i32, 2i32)), winding_number(&diamond, Vec2(5i32, 2i32))), (1i32, 0i32, 0i32));
+}
+
+
+#[test]
+fn simplification() {
+    let line = (0i32..10i32).map(|x| Vec2(x, 2i32 * x)).collect::<Vec<_>>();
+    assert_eq!(simplify(&line, 0f32), vec![Vec2(0i32, 0i32), Vec2(9i32, 18i32)]);
+
+    let zigzag = (0i32..9i32).map(|x| Vec2(2i32 * x, x % 2i32)).collect::<Vec<_>>();
+    assert_eq!(simplify(&zigzag, 1f32), vec![Vec2(0i32, 0i32), Vec2(16i32, 0i32)]);
+    assert_eq!(simplify(&zigzag, 0.5f32), zigzag);
+
+    let corner = [Vec2(0f32, 0f32), Vec2(5f32, 0.1f32), Vec2(10f32, 0f32), Vec2(10f32, 5f32), Vec2(10.1f32, 10f32)];
+    assert_eq!(simplify(&corner, 0.5f32), vec![Vec2(0f32, 0f32), Vec2(10f32, 0f32), Vec2(10.1f32, 10f32)]);
+
+    assert_eq!(simplify(&[Vec2(0i32, 0i32), Vec2(5i32, 5i32)], 100f32), vec![Vec2(0i32, 0i32), Vec2(5i32, 5i32)]);
+}
@@ -0,0 +1,181 @@
+use lofi_maths::vectors::{Vec2, Vec3};
+use lofi_maths::geometry::Segment;
+use lofi_maths::predicates::{Orientation, CirclePosition, SegmentIntersection, orient2d, orient3d, incircle, segments_intersect, segment_intersection};
+
+
+/// Scales a float onto the integers, exact for multiples of `2^-24` below `2^40`
+fn scaled(value: f32) -> i128 { (value as f64 * (1u64 << 24u32) as f64) as i128 }
+
+
+fn exact_orient2d(a: Vec2<f32>, b: Vec2<f32>, c: Vec2<f32>) -> Orientation {
+    let (ax, ay, bx, by, cx, cy) = (scaled(a.0), scaled(a.1), scaled(b.0), scaled(b.1), scaled(c.0), scaled(c.1));
+    match ((bx - ax) * (cy - ay) - (by - ay) * (cx - ax)).signum() {
+        1i128  => Orientation::CounterClockwise,
+        -1i128 => Orientation::Clockwise,
+        _      => Orientation::Collinear,
+    } // match ..
+}
+
+
+fn naive_orient2d(a: Vec2<f32>, b: Vec2<f32>, c: Vec2<f32>) -> Orientation {
+    let determinant = (b.0 - a.0) * (c.1 - a.1) - (b.1 - a.1) * (c.0 - a.0);
+    if determinant > 0f32 { Orientation::CounterClockwise } else if determinant < 0f32 { Orientation::Clockwise } else { Orientation::Collinear }
+}
+
+
+#[test]
+fn orient2d_near_degenerate_floats() {
+    // Kettner et al.'s classroom example, points within a few units in the last place of the line `y = x`
+    let (b, c)     = (Vec2(12f32, 12f32), Vec2(24f32, 24f32));
+    let ulp        = f32::EPSILON / 2f32;
+    let mut naive  = 0u32;
+    for x in 0u32..64u32 {
+        for y in 0u32..64u32 {
+            let a = Vec2(0.5f32 + x as f32 * ulp, 0.5f32 + y as f32 * ulp);
+            assert_eq!(orient2d(a, b, c), exact_orient2d(a, b, c), "{a:?}");
+            assert_eq!(orient2d(b, c, a), exact_orient2d(a, b, c), "{a:?}");
+            if naive_orient2d(a, b, c) != exact_orient2d(a, b, c) { naive += 1u32 }
+        } // for ..
+    } // for ..
+    assert!(naive > 0u32, "the naive determinant should get some of these wrong");
+
+    assert_eq!(orient2d(Vec2(0f32, 0f32), Vec2(1f32, 0f32), Vec2(0f32, 1f32)), Orientation::CounterClockwise);
+    assert_eq!(orient2d(Vec2(0f32, 0f32), Vec2(0f32, 1f32), Vec2(1f32, 0f32)), Orientation::Clockwise);
+    assert_eq!(orient2d(Vec2(0.1f32, 0.1f32), Vec2(0.2f32, 0.2f32), Vec2(0.3f32, 0.3f32)), exact_orient2d(Vec2(0.1f32, 0.1f32), Vec2(0.2f32, 0.2f32), Vec2(0.3f32, 0.3f32)));
+}
+
+
+#[test]
+fn incircle_near_degenerate_floats() {
+    let exact = |a: Vec2<f32>, b: Vec2<f32>, c: Vec2<f32>, d: Vec2<f32>| {
+        let offset   = |p: Vec2<f32>| (scaled(p.0) - scaled(d.0), scaled(p.1) - scaled(d.1));
+        let (ax, ay) = offset(a);
+        let (bx, by) = offset(b);
+        let (cx, cy) = offset(c);
+        let determinant = (ax * ax + ay * ay) * (bx * cy - cx * by) + (bx * bx + by * by) * (cx * ay - ax * cy) + (cx * cx + cy * cy) * (ax * by - bx * ay);
+        match determinant.signum() { 1i128 => CirclePosition::Inside, -1i128 => CirclePosition::Outside, _ => CirclePosition::Cocircular }
+    }; // let ..
+
+    // The unit circle through counter-clockwise points, the fourth one a few ulps around `(0, -1)`
+    let (a, b, c) = (Vec2(1f32, 0f32), Vec2(0f32, 1f32), Vec2(-1f32, 0f32));
+    let ulp       = f32::EPSILON / 2f32;
+    for x in -16i32..16i32 {
+        for y in -16i32..16i32 {
+            let d = Vec2(x as f32 * ulp, -1f32 + y as f32 * ulp);
+            assert_eq!(incircle(a, b, c, d), exact(a, b, c, d), "{d:?}");
+            assert_eq!(incircle(a, c, b, d), exact(a, b, c, d), "{d:?}");
+        } // for ..
+    } // for ..
+    assert_eq!(incircle(a, b, c, Vec2(0f32, -1f32)), CirclePosition::Cocircular);
+
+    // Collinear points only have their line as the circle
+    assert_eq!(incircle(Vec2(0f32, 0f32), Vec2(1f32, 1f32), Vec2(2f32, 2f32), Vec2(3f32, 3f32)), CirclePosition::Cocircular);
+    assert_eq!(incircle(Vec2(0f32, 0f32), Vec2(1f32, 1f32), Vec2(2f32, 2f32), Vec2(3f32, 3.5f32)), CirclePosition::Outside);
+}
+
+
+#[test]
+fn integer_extremes() {
+    let (min, max) = (i32::MIN, i32::MAX);
+    assert_eq!(orient2d(Vec2(min, min), Vec2(max, max), Vec2(max - 1i32, max)), Orientation::CounterClockwise);
+    assert_eq!(orient2d(Vec2(min, min), Vec2(max, max), Vec2(max, max - 1i32)), Orientation::Clockwise);
+    assert_eq!(orient2d(Vec2(min, min), Vec2(0i32, 0i32), Vec2(max, max)), Orientation::Collinear);
+    assert_eq!(orient2d(Vec2(0u32, 0u32), Vec2(u32::MAX, u32::MAX), Vec2(u32::MAX, u32::MAX - 1u32)), Orientation::Clockwise);
+
+    let (a, b, c) = (Vec3(min, min, min), Vec3(max, min, min), Vec3(min, max, min));
+    assert_eq!(orient3d(a, b, c, Vec3(max, max, max)), Orientation::CounterClockwise);
+    assert_eq!(orient3d(a, c, b, Vec3(max, max, max)), Orientation::Clockwise);
+    assert_eq!(orient3d(a, b, c, Vec3(max, max, min)), Orientation::Collinear);
+
+    // Corners of the whole square lie on one circle, far beyond what fits in an `i128`
+    let (a, b, c) = (Vec2(min, min), Vec2(max, min), Vec2(max, max));
+    assert_eq!(incircle(a, b, c, Vec2(min, max)), CirclePosition::Cocircular);
+    assert_eq!(incircle(a, b, c, Vec2(min + 1i32, max)), CirclePosition::Inside);
+    assert_eq!(incircle(c, b, a, Vec2(min + 1i32, max)), CirclePosition::Inside);
+    assert_eq!(incircle(a, b, Vec2(max, max - 1i32), Vec2(min, max)), CirclePosition::Outside);
+
+    // Pythagorean points on a circle of radius `5k`, one unit in and out
+    let k = 400_000_000i32;
+    let (a, b, c) = (Vec2(3i32 * k, 4i32 * k), Vec2(-5i32 * k, 0i32), Vec2(0i32, -5i32 * k));
+    for (d, position) in [
+        (Vec2(4i32 * k, -3i32 * k),           CirclePosition::Cocircular),
+        (Vec2(4i32 * k - 1i32, -3i32 * k),    CirclePosition::Inside),
+        (Vec2(4i32 * k, -3i32 * k - 1i32),    CirclePosition::Outside),
+        (Vec2(5i32 * k, 0i32),                CirclePosition::Cocircular),
+        (Vec2(-3i32 * k, -4i32 * k + 1i32),   CirclePosition::Inside),
+    ] {
+        assert_eq!(incircle(a, b, c, d), position, "{d:?}");
+        assert_eq!(incircle(c, b, a, d), position, "{d:?}");
+    } // for ..
+}
+
+
+#[test]
+fn crossing_segments() {
+    let a = Segment::new(Vec2(0i32, 0i32), Vec2(4i32, 2i32));
+    let b = Segment::new(Vec2(0i32, 3i32), Vec2(3i32, 0i32));
+    let Some(SegmentIntersection::Point { numerator, denominator }) = segment_intersection(&a, &b) else { panic!("segments cross") };
+    assert!(denominator > 0i128);
+    assert_eq!(numerator, Vec2(2i128, 1i128) * denominator);
+
+    // Flipping either segment keeps the point
+    let Some(SegmentIntersection::Point { numerator: flipped, denominator: flipped_denominator }) = segment_intersection(&b, &Segment::new(a.end, a.start)) else { panic!("segments cross") };
+    assert_eq!(flipped * denominator, numerator * flipped_denominator);
+
+    assert!(segment_intersection(&a, &Segment::new(Vec2(0i32, 3i32), Vec2(1i32, 2i32))).is_none());
+    assert!(!segments_intersect(&a, &Segment::new(Vec2(0i32, 3i32), Vec2(1i32, 2i32))));
+}
+
+
+#[test]
+fn touching_segments() {
+    let a = Segment::new(Vec2(0i32, 0i32), Vec2(4i32, 0i32));
+
+    // End to end, and an endpoint resting on the other segment
+    for (b, point) in [
+        (Segment::new(Vec2(4i32, 0i32), Vec2(6i32, 3i32)), Vec2(4i128, 0i128)),
+        (Segment::new(Vec2(2i32, 0i32), Vec2(2i32, 5i32)), Vec2(2i128, 0i128)),
+        (Segment::new(Vec2(2i32, 5i32), Vec2(2i32, 0i32)), Vec2(2i128, 0i128)),
+        (Segment::new(Vec2(-1i32, 1i32), Vec2(1i32, -1i32)), Vec2(0i128, 0i128)),
+    ] {
+        let Some(SegmentIntersection::Point { numerator, denominator }) = segment_intersection(&a, &b) else { panic!("{b:?} touches") };
+        assert!(segments_intersect(&a, &b) && segments_intersect(&b, &a));
+        assert_eq!(numerator, point * denominator);
+    } // for ..
+
+    // Collinear and end to end
+    assert_eq!(segment_intersection(&a, &Segment::new(Vec2(4i32, 0i32), Vec2(9i32, 0i32))), Some(SegmentIntersection::Point { numerator: Vec2(4i128, 0i128), denominator: 1i128 }));
+    assert_eq!(segment_intersection(&a, &Segment::new(Vec2(9i32, 0i32), Vec2(4i32, 0i32))), Some(SegmentIntersection::Point { numerator: Vec2(4i128, 0i128), denominator: 1i128 }));
+
+    // Degenerate segments are points
+    assert_eq!(segment_intersection(&a, &Segment::new(Vec2(3i32, 0i32), Vec2(3i32, 0i32))), Some(SegmentIntersection::Point { numerator: Vec2(3i128, 0i128), denominator: 1i128 }));
+    assert!(segment_intersection(&a, &Segment::new(Vec2(3i32, 1i32), Vec2(3i32, 1i32))).is_none());
+}
+
+
+#[test]
+fn collinear_segments() {
+    let a = Segment::new(Vec2(0i32, 0i32), Vec2(6i32, 3i32));
+
+    // Disjoint on the same line
+    assert!(segment_intersection(&a, &Segment::new(Vec2(8i32, 4i32), Vec2(10i32, 5i32))).is_none());
+    assert!(segment_intersection(&a, &Segment::new(Vec2(-2i32, -1i32), Vec2(-4i32, -2i32))).is_none());
+
+    // Overlaps are ordered lexicographically whatever the directions
+    let overlap = Some(SegmentIntersection::Overlap(Segment::new(Vec2(2i32, 1i32), Vec2(6i32, 3i32))));
+    assert_eq!(segment_intersection(&a, &Segment::new(Vec2(2i32, 1i32), Vec2(8i32, 4i32))), overlap);
+    assert_eq!(segment_intersection(&a, &Segment::new(Vec2(8i32, 4i32), Vec2(2i32, 1i32))), overlap);
+    assert_eq!(segment_intersection(&Segment::new(a.end, a.start), &Segment::new(Vec2(8i32, 4i32), Vec2(2i32, 1i32))), overlap);
+
+    // One inside the other
+    let inner = Segment::new(Vec2(4i32, 2i32), Vec2(2i32, 1i32));
+    assert_eq!(segment_intersection(&a, &inner), Some(SegmentIntersection::Overlap(Segment::new(Vec2(2i32, 1i32), Vec2(4i32, 2i32)))));
+    assert_eq!(segment_intersection(&inner, &a), Some(SegmentIntersection::Overlap(Segment::new(Vec2(2i32, 1i32), Vec2(4i32, 2i32)))));
+
+    // Vertical lines order by `y`
+    let vertical = Segment::new(Vec2(1i32, 5i32), Vec2(1i32, -5i32));
+    assert_eq!(segment_intersection(&vertical, &Segment::new(Vec2(1i32, 0i32), Vec2(1i32, 9i32))), Some(SegmentIntersection::Overlap(Segment::new(Vec2(1i32, 0i32), Vec2(1i32, 5i32)))));
+
+    // Parallel but on another line
+    assert!(segment_intersection(&a, &Segment::new(Vec2(0i32, 1i32), Vec2(6i32, 4i32))).is_none());
+}
@@ -0,0 +1,283 @@
+use std::collections::{HashMap, HashSet};
+
+use lofi_maths::vectors::{Vec2, Vec3, Vec4};
+use lofi_maths::raster::{Span, Interpolation, TriangleSetup, SUBPIXEL_BITS, line, thick_line, circle, ellipse, filled_circle, filled_ellipse, wu_line};
+
+
+/// Ends of lines leaving the origin in every octant, along the axes and diagonals too
+fn directions() -> Vec<Vec2<i32>> {
+    let mut ends = Vec::new();
+    for (x, y) in [(7i32, 0i32), (7i32, 3i32), (7i32, 7i32), (3i32, 7i32), (1i32, 13i32), (13i32, 1i32), (12i32, 5i32)] {
+        for (sx, sy) in [(1i32, 1i32), (-1i32, 1i32), (1i32, -1i32), (-1i32, -1i32)] {
+            ends.extend([Vec2(x * sx, y * sy), Vec2(y * sx, x * sy)]);
+        } // for ..
+    } // for ..
+    ends
+}
+
+
+/// Row extents of a set of pixels
+fn rows(pixels: impl IntoIterator<Item = Vec2<i32>>) -> HashMap<i32, (i32, i32)> {
+    let mut rows = HashMap::new();
+    for Vec2(x, y) in pixels {
+        let row = rows.entry(y).or_insert((x, x));
+        *row = (row.0.min(x), row.1.max(x));
+    } // for ..
+    rows
+}
+
+
+#[test]
+fn lines_join_their_ends() {
+    let start = Vec2(-2i32, 5i32);
+    for direction in directions() {
+        let end    = start + direction;
+        let pixels = line(start, end).collect::<Vec<_>>();
+        assert_eq!((pixels[0usize], pixels[pixels.len() - 1usize]), (start, end), "{direction:?}");
+        assert_eq!(pixels.len() as i32, direction.0.abs().max(direction.1.abs()) + 1i32, "{direction:?}");
+
+        // Every step moves one pixel along the major axis and at most one across it
+        for pair in pixels.windows(2usize) {
+            let step = pair[1usize] - pair[0usize];
+            assert!(step.0.abs() <= 1i32 && step.1.abs() <= 1i32 && step != Vec2(0i32, 0i32), "{direction:?}");
+            assert!(step.0 * direction.0 >= 0i32 && step.1 * direction.1 >= 0i32, "{direction:?}");
+        } // for ..
+
+        // Pixels stay within half a pixel of the ideal line along the minor axis
+        let (dx, dy) = (direction.0 as f32, direction.1 as f32);
+        for pixel in &pixels {
+            let (x, y) = ((pixel.0 - start.0) as f32, (pixel.1 - start.1) as f32);
+            assert!((x * dy - y * dx).abs() / dx.abs().max(dy.abs()) <= 0.5f32, "{direction:?} {pixel:?}");
+        } // for ..
+    } // for ..
+    assert_eq!(line(start, start).collect::<Vec<_>>(), vec![start]);
+}
+
+
+#[test]
+fn thick_lines_are_wide_runs() {
+    let (start, end) = (Vec2(0i32, 0i32), Vec2(20i32, 5i32));
+    for width in [1u32, 2u32, 3u32, 6u32] {
+        let pixels = thick_line(start, end, width).collect::<Vec<_>>();
+        let unique = pixels.iter().copied().collect::<HashSet<_>>();
+        assert_eq!(unique.len(), pixels.len());
+        assert!(line(start, end).all(|pixel| unique.contains(&pixel)));
+        assert_eq!(pixels.len(), 21usize * width as usize);
+    } // for ..
+    assert_eq!(thick_line(start, end, 1u32).collect::<Vec<_>>(), line(start, end).collect::<Vec<_>>());
+}
+
+
+#[test]
+fn circles_are_symmetric() {
+    let center = Vec2(3i32, -4i32);
+    for radius in 0i32..40i32 {
+        let pixels = circle(center, radius).collect::<Vec<_>>();
+        let unique = pixels.iter().map(|&pixel| pixel - center).collect::<HashSet<_>>();
+        assert_eq!(unique.len(), pixels.len(), "duplicate pixels at radius {radius}");
+
+        for &Vec2(x, y) in &unique {
+            assert!([Vec2(-x, y), Vec2(x, -y), Vec2(-x, -y), Vec2(y, x)].iter().all(|pixel| unique.contains(pixel)), "radius {radius} at {x} {y}");
+            let distance = ((x * x + y * y) as f32).sqrt();
+            assert!((distance - radius as f32).abs() < 1f32, "radius {radius} at {x} {y}");
+        } // for ..
+        assert!(unique.contains(&Vec2(radius, 0i32)) && unique.contains(&Vec2(0i32, radius)));
+    } // for ..
+
+    for (radius_x, radius_y) in [(10i32, 3i32), (3i32, 10i32), (25i32, 1i32), (1i32, 25i32), (0i32, 5i32), (7i32, 0i32)] {
+        let pixels = ellipse(Vec2(0i32, 0i32), radius_x, radius_y).collect::<Vec<_>>();
+        let unique = pixels.iter().copied().collect::<HashSet<_>>();
+        assert_eq!(unique.len(), pixels.len(), "duplicate pixels for {radius_x} by {radius_y}");
+        assert!(unique.iter().all(|&Vec2(x, y)| unique.contains(&Vec2(-x, y)) && unique.contains(&Vec2(x, -y))));
+        assert!(unique.contains(&Vec2(radius_x, 0i32)) && unique.contains(&Vec2(0i32, radius_y)));
+    } // for ..
+}
+
+
+#[test]
+fn spans_match_the_outline() {
+    for (radius_x, radius_y) in [(0i32, 0i32), (1i32, 1i32), (5i32, 5i32), (17i32, 17i32), (12i32, 4i32), (3i32, 11i32), (30i32, 1i32), (0i32, 6i32)] {
+        let center  = Vec2(-7i32, 9i32);
+        let outline = rows(ellipse(center, radius_x, radius_y));
+        let spans   = filled_ellipse(center, radius_x, radius_y).collect::<Vec<_>>();
+
+        assert_eq!(spans.len(), outline.len(), "{radius_x} by {radius_y}");
+        for Span { y, start, end } in spans {
+            assert_eq!(outline.get(&y), Some(&(start, end)), "{radius_x} by {radius_y} at row {y}");
+        } // for ..
+    } // for ..
+    assert_eq!(filled_circle(Vec2(0i32, 0i32), 9i32).collect::<Vec<_>>(), filled_ellipse(Vec2(0i32, 0i32), 9i32, 9i32).collect::<Vec<_>>());
+}
+
+
+#[test]
+fn wu_coverage_adds_up() {
+    let start = Vec2(4i32, -3i32);
+    for direction in directions() {
+        let end    = start + direction;
+        let pixels = wu_line(start, end).collect::<Vec<_>>();
+        assert_eq!((pixels[0usize], pixels[pixels.len() - 1usize]), ((start, u8::MAX), (end, u8::MAX)), "{direction:?}");
+
+        // Coverage across the line at each step of the major axis sums to one pixel
+        let major = |pixel: Vec2<i32>| if direction.0.abs() >= direction.1.abs() { pixel.0 } else { pixel.1 };
+        let mut sums: HashMap<i32, u32> = HashMap::new();
+        for &(pixel, coverage) in &pixels { *sums.entry(major(pixel)).or_default() += coverage as u32 }
+        assert_eq!(sums.len() as i32, direction.0.abs().max(direction.1.abs()) + 1i32, "{direction:?}");
+        assert!(sums.values().all(|&sum| (254u32..=255u32).contains(&sum)), "{direction:?} {sums:?}");
+    } // for ..
+    assert_eq!(wu_line(start, start).collect::<Vec<_>>(), vec![(start, u8::MAX)]);
+}
+
+
+#[test]
+fn pixels_past_the_coordinate_range_are_skipped() {
+    let narrow = |pixel: Vec2<i32>| Some(Vec2(i16::try_from(pixel.0).ok()?, i16::try_from(pixel.1).ok()?));
+    let widen  = |pixel: Vec2<i16>| Vec2(pixel.0 as i32, pixel.1 as i32);
+
+    for center in [Vec2(i16::MAX - 3i16, 0i16), Vec2(i16::MIN + 2i16, i16::MAX - 1i16), Vec2(i16::MAX, i16::MIN)] {
+        let wide = circle(widen(center), 10i32).filter_map(narrow).collect::<Vec<_>>();
+        assert_eq!(circle(center, 10i16).collect::<Vec<_>>(), wide);
+
+        // Spans are cut at the edges of the range, rows past them dropped
+        let wide = filled_circle(widen(center), 10i32).filter_map(|span| Some(Span {
+            y:     i16::try_from(span.y).ok()?,
+            start: span.start.clamp(i16::MIN as i32, i16::MAX as i32) as i16,
+            end:   span.end.clamp(i16::MIN as i32, i16::MAX as i32) as i16,
+        })).collect::<Vec<_>>();
+        assert_eq!(filled_circle(center, 10i16).collect::<Vec<_>>(), wide);
+    } // for ..
+
+    let (start, end) = (Vec2(i16::MAX - 5i16, i16::MIN), Vec2(i16::MAX, i16::MIN + 5i16));
+    assert_eq!(line(start, end).last(), Some(end));
+    assert_eq!(line(start, end).count(), 6usize);
+
+    let wide = thick_line(widen(start), widen(end), 5u32).filter_map(narrow).collect::<Vec<_>>();
+    assert_eq!(thick_line(start, end, 5u32).collect::<Vec<_>>(), wide);
+
+    let (start, end) = (Vec2(i16::MAX - 9i16, 0i16), Vec2(i16::MAX, 3i16));
+    let wide = wu_line(widen(start), widen(end)).filter_map(|(pixel, coverage)| Some((narrow(pixel)?, coverage))).collect::<Vec<_>>();
+    assert_eq!(wu_line(start, end).collect::<Vec<_>>(), wide);
+
+    let corner = Vec2(i8::MIN, i8::MIN);
+    assert_eq!(circle(corner, 1i8).collect::<HashSet<_>>(), HashSet::from([Vec2(-127i8, -128i8), Vec2(-128i8, -127i8)]));
+}
+
+
+#[test]
+fn shared_edges_cover_pixels_once() {
+    let mut state = 5u32;
+    let mut random = || { state = state.wrapping_mul(1664525u32).wrapping_add(1013904223u32); ((state >> 8u32) % 320u32) as i32 };
+
+    // Fans of triangles around a point, edges landing on pixel centers included
+    for round in 0usize..50usize {
+        let snap   = |value: i32| if round % 2usize == 0usize { value } else { (value & !15i32) | 8i32 };
+        let center = Vec2(snap(random()), snap(random()));
+        let ring   = [Vec2(0i32, 0i32), Vec2(160i32, 0i32), Vec2(320i32, 0i32), Vec2(320i32, 320i32), Vec2(160i32, 320i32), Vec2(0i32, 320i32)];
+        let ring   = ring.map(|corner| Vec2(snap(corner.0), snap(corner.1)));
+
+        let mut covered = HashSet::new();
+        for index in 0usize..ring.len() {
+            let Some(setup) = TriangleSetup::new(center, ring[index], ring[(index + 1usize) % ring.len()]) else { continue };
+            for fragment in setup.pixels() { assert!(covered.insert(fragment.pixel), "{:?} covered twice", fragment.pixel) }
+        } // for ..
+
+        // Together they cover the hull of the ring, whose edges count when top or left
+        let (min, max) = (ring[0usize], ring[3usize]);
+        let inside = |value: i32, low: i32, high: i32| { let center = value * 16i32 + 8i32; low <= center && center < high };
+        let expected = (0i32..20i32).flat_map(|y| (0i32..20i32).map(move |x| Vec2(x, y)))
+            .filter(|pixel| inside(pixel.0, min.0, max.0) && inside(pixel.1, min.1, max.1))
+            .collect::<HashSet<_>>();
+        assert_eq!(covered, expected);
+    } // for ..
+
+    // Same edge from either triangle, whatever the winding
+    let (a, b) = (Vec2(3i32, 7i32), Vec2(150i32, 91i32));
+    let left   = TriangleSetup::new(a, b, Vec2(-40i32, 130i32)).unwrap().pixels().map(|fragment| fragment.pixel).collect::<HashSet<_>>();
+    let right  = TriangleSetup::new(b, a, Vec2(190i32, -20i32)).unwrap().pixels().map(|fragment| fragment.pixel).collect::<HashSet<_>>();
+    assert!(left.is_disjoint(&right));
+    assert!(line(Vec2(1i32, 1i32), Vec2(8i32, 5i32)).skip(1usize).take(6usize).all(|pixel| left.contains(&pixel) || right.contains(&pixel)));
+}
+
+
+#[test]
+fn weights_locate_the_pixel() {
+    let vertices = [Vec2(5i32, 3i32), Vec2(260i32, 90i32), Vec2(40i32, 300i32)];
+    for order in [[0usize, 1usize, 2usize], [0usize, 2usize, 1usize], [2usize, 1usize, 0usize]] {
+        let corners = order.map(|index| vertices[index]);
+        let setup   = TriangleSetup::new(corners[0usize], corners[1usize], corners[2usize]).unwrap();
+        let points  = corners.map(|corner| Vec2(corner.0 as f32, corner.1 as f32) / (1i32 << SUBPIXEL_BITS) as f32);
+
+        let mut count = 0usize;
+        for fragment in setup.pixels() {
+            count += 1usize;
+            assert!((fragment.weights.iter().sum::<f32>() - 1f32).abs() < 1e-5f32);
+            assert!(fragment.weights.iter().all(|&weight| (0f32..=1f32).contains(&weight)));
+
+            let position = fragment.interpolate(points);
+            let center   = Vec2(fragment.pixel.0 as f32 + 0.5f32, fragment.pixel.1 as f32 + 0.5f32);
+            assert!((position - center).0.abs() < 1e-3f32 && (position - center).1.abs() < 1e-3f32, "{order:?} {fragment:?}");
+        } // for ..
+
+        // Close to the area, 255 * 297 / 2 squared subpixels
+        assert!((count as f32 - 148f32).abs() < 15f32, "{count}");
+    } // for ..
+}
+
+
+#[test]
+fn perspective_weights_follow_depth() {
+    let clip = [Vec4(-1f32, -1f32, 0.5f32, 1f32), Vec4(4f32, -4f32, 3.5f32, 4f32), Vec4(-2f32, 2f32, 1.5f32, 2f32)];
+    let size = Vec2(64i32, 48i32);
+    let affine      = TriangleSetup::from_clip(clip, size, Interpolation::Affine).unwrap();
+    let perspective = TriangleSetup::from_clip(clip, size, Interpolation::Perspective).unwrap();
+
+    let mut count = 0usize;
+    for (flat, deep) in affine.pixels().zip(perspective.pixels()) {
+        count += 1usize;
+        assert_eq!((flat.pixel, flat.depth), (deep.pixel, deep.depth));
+        assert!((deep.weights.iter().sum::<f32>() - 1f32).abs() < 1e-5f32);
+
+        // Attributes linear in space, the clip `w` among them, go back to linear in screen space
+        // once divided by `w`
+        let w = deep.interpolate(clip.map(|vertex| Vec3(vertex.3, 0f32, 0f32))).0;
+        let inverse_w = flat.interpolate(clip.map(|vertex| Vec3(1f32 / vertex.3, 0f32, 0f32))).0;
+        assert!((w * inverse_w - 1f32).abs() < 1e-4f32, "{:?}", flat.pixel);
+
+        // Depth is the screen space interpolation of `z / w`
+        let depth = flat.interpolate(clip.map(|vertex| Vec3(vertex.2 / vertex.3, 0f32, 0f32))).0;
+        assert!((flat.depth - depth).abs() < 1e-5f32);
+    } // for ..
+    assert!(count > 100usize && affine.pixels().count() == count);
+
+    // Without depth differences both agree
+    let flat = clip.map(|vertex| Vec4(vertex.0 / vertex.3 * 2f32, vertex.1 / vertex.3 * 2f32, 0f32, 2f32));
+    let affine      = TriangleSetup::from_clip(flat, size, Interpolation::Affine).unwrap();
+    let perspective = TriangleSetup::from_clip(flat, size, Interpolation::Perspective).unwrap();
+    for (flat, deep) in affine.pixels().zip(perspective.pixels()) {
+        assert!(flat.weights.iter().zip(deep.weights).all(|(a, b)| (a - b).abs() < 1e-5f32));
+    } // for ..
+}
+
+
+#[test]
+fn vertices_past_the_guard_band_are_rejected() {
+    let size = Vec2(320i32, 240i32);
+    let near = [Vec4(0f32, 0f32, 0f32, 1f32), Vec4(1f32, 0f32, 0f32, 1f32), Vec4(0f32, 1f32, 0f32, 1e-30f32)];
+    assert_eq!(TriangleSetup::from_clip(near, size, Interpolation::Perspective), None);
+    assert_eq!(TriangleSetup::from_clip(near, size, Interpolation::Affine), None);
+
+    let infinite = [Vec4(0f32, 0f32, 0f32, 1f32), Vec4(f32::INFINITY, 0f32, 0f32, 1f32), Vec4(0f32, 1f32, 0f32, 1f32)];
+    assert_eq!(TriangleSetup::from_clip(infinite, size, Interpolation::Affine), None);
+    let undefined = [Vec4(0f32, 0f32, 0f32, 1f32), Vec4(f32::NAN, 0f32, 0f32, 1f32), Vec4(0f32, 1f32, 0f32, 1f32)];
+    assert_eq!(TriangleSetup::from_clip(undefined, size, Interpolation::Affine), None);
+
+    // Far outside of the viewport but within the band, covering all of it
+    let large = [Vec4(-1000f32, -1000f32, 0f32, 1f32), Vec4(3000f32, -1000f32, 0f32, 1f32), Vec4(-1000f32, 3000f32, 0f32, 1f32)];
+    let setup = TriangleSetup::from_clip(large, size, Interpolation::Affine).unwrap();
+    assert_eq!(setup.pixels().count(), 320usize * 240usize);
+
+    let band = 1i32 << 30u32;
+    assert_eq!(TriangleSetup::new(Vec2(-band, 0i32), Vec2(0i32, 0i32), Vec2(0i32, 16i32)), None);
+    assert_eq!(TriangleSetup::new(Vec2(i32::MIN, i32::MIN), Vec2(i32::MAX, i32::MIN), Vec2(i32::MIN, i32::MAX)), None);
+    assert!(TriangleSetup::new(Vec2(1i32 - band, 1i32 - band), Vec2(band - 1i32, 1i32 - band), Vec2(1i32 - band, band - 1i32)).is_some());
+}
@@ -0,0 +1,148 @@
+use lofi_maths::assert_approx_eq;
+use lofi_maths::vectors::Vec2;
+use lofi_maths::matrices::{Mat3, Affine2};
+use lofi_maths::resample::{Filter, Edge, resample, scale2x};
+
+
+const FILTERS: [Filter; 3usize] = [Filter::Nearest, Filter::Bilinear, Filter::RotSprite];
+
+
+/// Linear congruential generator, returns an image of `levels` grey levels
+fn random_image(state: &mut u32, length: usize, levels: u32) -> Vec<f32> {
+    (0usize..length).map(|_| {
+        *state = state.wrapping_mul(1664525u32).wrapping_add(1013904223u32);
+        ((*state >> 8u32) % levels) as f32
+    }).collect()
+}
+
+
+/// Alternating pixels, which Scale2x leaves blocky since no two neighbours on a side agree
+fn checkerboard(width: usize, height: usize) -> Vec<f32> {
+    (0usize..width * height).map(|index| ((index % width + index / width) % 2usize) as f32).collect()
+}
+
+
+fn draw(source: &[f32], source_width: usize, destination_width: usize, destination_height: usize, inverse: &Affine2<f32>, filter: Filter, edge: Edge) -> Vec<f32> {
+    let mut destination = vec![-1f32; destination_width * destination_height];
+    resample(source, source_width, &mut destination, destination_width, *inverse, filter, edge);
+    destination
+}
+
+
+#[test]
+fn identity() {
+    let mut state = 4u32;
+    let image = random_image(&mut state, 13usize * 7usize, 5u32);
+    for filter in [Filter::Nearest, Filter::Bilinear] {
+        assert_eq!(draw(&image, 13usize, 13usize, 7usize, &Affine2::IDENTITY, filter, Edge::Clip), image, "{filter:?}");
+    } // for ..
+
+    // Scale2x keeps the pixel centers of images without diagonal lines
+    let board = checkerboard(13usize, 7usize);
+    for filter in FILTERS {
+        assert_eq!(draw(&board, 13usize, 13usize, 7usize, &Affine2::IDENTITY, filter, Edge::Clip), board, "{filter:?}");
+    } // for ..
+}
+
+
+#[test]
+fn quarter_turn() {
+    // Destination `(x, y)` shows source `(y, height - 1 - x)`, the image turned clockwise
+    let (width, height) = (5usize, 3usize);
+    let inverse = Affine2(Vec2(0f32, -1f32), Vec2(1f32, 0f32), Vec2(0f32, height as f32));
+
+    let mut state = 6u32;
+    let image  = random_image(&mut state, width * height, 7u32);
+    let turned = |image: &[f32]| (0usize..width * height).map(|index| image[(height - 1usize - index % height) * width + index / height]).collect::<Vec<_>>();
+    for filter in [Filter::Nearest, Filter::Bilinear] {
+        assert_eq!(draw(&image, width, height, width, &inverse, filter, Edge::Clip), turned(&image), "{filter:?}");
+    } // for ..
+
+    let board = checkerboard(width, height);
+    assert_eq!(draw(&board, width, height, width, &inverse, Filter::RotSprite, Edge::Clip), turned(&board));
+}
+
+
+#[test]
+fn edges() {
+    let mut state = 2u32;
+    let image = random_image(&mut state, 4usize * 4usize, 9u32);
+
+    // Moved by half its width, the left half of the destination is left alone or wraps around
+    let inverse = Affine2(Vec2(1f32, 0f32), Vec2(0f32, 1f32), Vec2(-2f32, 0f32));
+    for filter in [Filter::Nearest, Filter::Bilinear] {
+        let clipped = draw(&image, 4usize, 4usize, 4usize, &inverse, filter, Edge::Clip);
+        let tiled   = draw(&image, 4usize, 4usize, 4usize, &inverse, filter, Edge::Repeat);
+        for (index, (&clipped, &tiled)) in clipped.iter().zip(&tiled).enumerate() {
+            let (x, y) = (index % 4usize, index / 4usize);
+            assert_eq!(tiled, image[y * 4usize + (x + 2usize) % 4usize], "{filter:?}");
+            assert_eq!(clipped, if x < 2usize { -1f32 } else { tiled }, "{filter:?}");
+        } // for ..
+    } // for ..
+}
+
+
+#[test]
+fn matrices_draw_as_affine() {
+    let mut state = 3u32;
+    let image  = random_image(&mut state, 6usize * 4usize, 11u32);
+    let affine = Affine2(Vec2(0f32, 1f32), Vec2(1f32, 0f32), Vec2(1f32, -1f32));
+    for filter in FILTERS {
+        let mut destination = vec![-1f32; 6usize * 4usize];
+        resample(&image, 6usize, &mut destination, 6usize, Mat3::from(affine), filter, Edge::Repeat);
+        assert_eq!(destination, draw(&image, 6usize, 6usize, 4usize, &affine, filter, Edge::Repeat), "{filter:?}");
+    } // for ..
+}
+
+
+#[test]
+fn wide_sources() {
+    // Past 32768 pixels, 16.16 fixed point positions overflowed 32 bits
+    let mut state = 1u32;
+    let image = random_image(&mut state, 40000usize, 200u32);
+    assert_eq!(draw(&image, 40000usize, 40000usize, 1usize, &Affine2::IDENTITY, Filter::Nearest, Edge::Clip), image);
+
+    // RotSprite samples a source eight times as wide
+    let board  = checkerboard(5000usize, 1usize);
+    let offset = Affine2(Vec2(1f32, 0f32), Vec2(0f32, 1f32), Vec2(4990f32, 0f32));
+    assert_eq!(draw(&board, 5000usize, 10usize, 1usize, &offset, Filter::RotSprite, Edge::Clip), board[4990usize..5000usize].to_vec());
+}
+
+
+#[test]
+fn scale2x_rounds_diagonals() {
+    assert_eq!(scale2x(&[1u8, 0u8, 0u8, 1u8], 2usize), vec![
+        1u8, 1u8, 0u8, 0u8,
+        1u8, 0u8, 1u8, 0u8,
+        0u8, 1u8, 0u8, 1u8,
+        0u8, 0u8, 1u8, 1u8,
+    ]);
+
+    // Flat areas and lone pixels stay square
+    assert_eq!(scale2x(&[3u8; 6usize], 3usize), vec![3u8; 24usize]);
+    assert_eq!(scale2x(&[0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8], 3usize)[2usize * 6usize + 2usize..2usize * 6usize + 4usize], [1u8, 1u8]);
+    assert_eq!(scale2x(&[7u8], 1usize), vec![7u8; 4usize]);
+    assert!(scale2x::<u8>(&[1u8, 2u8], 0usize).is_empty());
+}
+
+
+#[test]
+fn affine_inverse() {
+    let translation = Affine2(Vec2(1i32, 0i32), Vec2(0i32, 1i32), Vec2(3i32, -4i32));
+    assert_eq!(translation.inverse(), Some(Affine2(Vec2(1i32, 0i32), Vec2(0i32, 1i32), Vec2(-3i32, 4i32))));
+    assert_eq!(Affine2(Vec2(2f32, 4f32), Vec2(1f32, 2f32), Vec2(5f32, 5f32)).inverse(), None);
+    assert_eq!(Affine2::<f32>::IDENTITY.inverse(), Some(Affine2::IDENTITY));
+
+    // Turned by a third of a turn, stretched and moved
+    let (cos, sin) = (-0.5f32, 0.75f32.sqrt());
+    let affine  = Affine2(Vec2(2f32 * cos, 2f32 * sin), Vec2(-3f32 * sin, 3f32 * cos), Vec2(7f32, -1f32));
+    let inverse = affine.inverse().unwrap();
+    for product in [affine * inverse, inverse * affine] {
+        assert_approx_eq!(product.0, Vec2(1f32, 0f32), epsilon = 1e-5f32);
+        assert_approx_eq!(product.1, Vec2(0f32, 1f32), epsilon = 1e-5f32);
+        assert_approx_eq!(product.2, Vec2(0f32, 0f32), epsilon = 1e-5f32);
+    } // for ..
+
+    let point = Vec2(-2f32, 9f32);
+    assert_approx_eq!(inverse * (affine * point), point, epsilon = 1e-4f32);
+}
@@ -0,0 +1,83 @@
+#![cfg(feature = "serde")]
+
+use lofi_maths::Angle;
+use lofi_maths::vectors::{Vec2, Vec3, Vec4};
+use lofi_maths::matrices::{Mat3, Mat4, Affine2};
+
+
+#[test]
+fn vectors_serialize_as_sequences() {
+    assert_eq!(serde_json::to_string(&Vec2(1i16, -2i16)).unwrap(), "[1,-2]");
+    assert_eq!(serde_json::to_string(&Vec3(1f32, 2.5f32, -3f32)).unwrap(), "[1.0,2.5,-3.0]");
+    assert_eq!(bincode::serialize(&Vec2(1i16, -2i16)).unwrap(), vec![1u8, 0u8, 254u8, 255u8]);
+}
+
+
+#[test]
+fn matrices_serialize_as_nested_arrays() {
+    assert_eq!(
+        serde_json::to_string(&Mat3::<i32>::IDENTITY).unwrap(),
+        "[[1,0,0],[0,1,0],[0,0,1]]",
+    );
+    assert_eq!(serde_json::to_string(&Affine2(Vec2(1i32, 2i32), Vec2(3i32, 4i32), Vec2(5i32, 6i32))).unwrap(), "[[1,2],[3,4],[5,6]]");
+}
+
+
+#[test]
+fn angle_serializes_as_degrees_or_byte() {
+    assert_eq!(serde_json::to_string(&Angle(64u8)).unwrap(), "90.0");
+    assert_eq!(bincode::serialize(&Angle(64u8)).unwrap(), vec![64u8]);
+    assert_eq!(serde_json::from_str::<Angle>("180").unwrap(), Angle(128u8));
+}
+
+
+#[test]
+fn json_round_trip() {
+    let vec2 = Vec2(12i16, -7i16);
+    let vec3 = Vec3(0.25f32, -1.5f32, 8f32);
+    let vec4 = Vec4(1u8, 2u8, 3u8, 4u8);
+    let mat4 = Mat4::<f32>::new_orthogonal_projection_mat((-4f32, 4f32), (-3f32, 3f32), (0.1f32, 100f32));
+
+    let affine2 = Affine2(Vec2(0.5f32, -1f32), Vec2(2f32, 0.25f32), Vec2(-7f32, 3f32));
+
+    assert_eq!(serde_json::from_str::<Vec2<i16>>(&serde_json::to_string(&vec2).unwrap()).unwrap(), vec2);
+    assert_eq!(serde_json::from_str::<Vec3<f32>>(&serde_json::to_string(&vec3).unwrap()).unwrap(), vec3);
+    assert_eq!(serde_json::from_str::<Vec4<u8>>(&serde_json::to_string(&vec4).unwrap()).unwrap(), vec4);
+    assert_eq!(serde_json::from_str::<Mat4<f32>>(&serde_json::to_string(&mat4).unwrap()).unwrap(), mat4);
+    assert_eq!(serde_json::from_str::<Affine2<f32>>(&serde_json::to_string(&affine2).unwrap()).unwrap(), affine2);
+
+    for byte in 0u8..=255u8 {
+        let angle = Angle(byte);
+        assert_eq!(serde_json::from_str::<Angle>(&serde_json::to_string(&angle).unwrap()).unwrap(), angle);
+    } // for ..
+}
+
+
+#[test]
+fn binary_round_trip() {
+    let vec2 = Vec2(12i16, -7i16);
+    let mat3 = Mat3::new_2d_homogeneous_translation_mat(Vec2(3f32, -9f32));
+    let mat4 = Mat4::<i32>::IDENTITY;
+
+    let affine2 = Affine2(Vec2(1i16, 2i16), Vec2(3i16, 4i16), Vec2(5i16, -6i16));
+
+    assert_eq!(bincode::serialize(&mat3).unwrap().len(), 9usize * 4usize);
+    assert_eq!(bincode::deserialize::<Vec2<i16>>(&bincode::serialize(&vec2).unwrap()).unwrap(), vec2);
+    assert_eq!(bincode::deserialize::<Mat3<f32>>(&bincode::serialize(&mat3).unwrap()).unwrap(), mat3);
+    assert_eq!(bincode::deserialize::<Mat4<i32>>(&bincode::serialize(&mat4).unwrap()).unwrap(), mat4);
+    assert_eq!(bincode::serialize(&affine2).unwrap().len(), 6usize * 2usize);
+    assert_eq!(bincode::deserialize::<Affine2<i16>>(&bincode::serialize(&affine2).unwrap()).unwrap(), affine2);
+
+    for byte in 0u8..=255u8 {
+        let angle = Angle(byte);
+        assert_eq!(bincode::deserialize::<Angle>(&bincode::serialize(&angle).unwrap()).unwrap(), angle);
+    } // for ..
+}
+
+
+#[test]
+fn short_sequences_are_rejected() {
+    assert!(serde_json::from_str::<Vec3<f32>>("[1.0,2.0]").is_err());
+    assert!(serde_json::from_str::<Mat3<i32>>("[[1,0,0],[0,1,0]]").is_err());
+    assert!(serde_json::from_str::<Affine2<i32>>("[[1,0],[0,1]]").is_err());
+}
@@ -0,0 +1,162 @@
+use std::collections::HashSet;
+use std::time::{Duration, Instant};
+
+use lofi_maths::vectors::Vec2;
+use lofi_maths::geometry::Aabb2;
+use lofi_maths::spatial::{Handle, SpatialHash, Quadtree};
+
+
+/// Common surface of both indices, so that the same checks run on each
+trait Index {
+    fn insert(&mut self, bounds: Aabb2<i32>, item: usize) -> Handle;
+    fn remove(&mut self, handle: Handle) -> Option<usize>;
+    fn move_to(&mut self, handle: Handle, bounds: Aabb2<i32>) -> bool;
+    fn query_rect(&self, rect: &Aabb2<i32>) -> Vec<usize>;
+    fn query_radius(&self, center: Vec2<i32>, radius: i32) -> Vec<usize>;
+    fn nearest(&self, point: Vec2<i32>, k: usize) -> Vec<usize>;
+}
+
+
+macro_rules! impl_index {
+    ($($index:ident),*) => {$(
+        impl Index for $index<usize> {
+            fn insert(&mut self, bounds: Aabb2<i32>, item: usize) -> Handle { $index::insert(self, bounds, item) }
+            fn remove(&mut self, handle: Handle) -> Option<usize> { $index::remove(self, handle) }
+            fn move_to(&mut self, handle: Handle, bounds: Aabb2<i32>) -> bool { $index::move_to(self, handle, bounds) }
+
+            fn query_rect(&self, rect: &Aabb2<i32>) -> Vec<usize> {
+                let mut found = Vec::new();
+                $index::query_rect(self, rect, |_, &item| found.push(item));
+                found
+            } // fn ..
+
+            fn query_radius(&self, center: Vec2<i32>, radius: i32) -> Vec<usize> {
+                let mut found = Vec::new();
+                $index::query_radius(self, center, radius, |_, &item| found.push(item));
+                found
+            } // fn ..
+
+            fn nearest(&self, point: Vec2<i32>, k: usize) -> Vec<usize> {
+                $index::nearest(self, point, k).into_iter().map(|handle| *self.get(handle).unwrap()).collect()
+            } // fn ..
+        } // impl ..
+    )*};
+} // macro_rules! ..
+
+impl_index!(SpatialHash, Quadtree);
+
+
+/// Linear congruential generator, returns values in `min..max`
+fn random(state: &mut u32, min: i32, max: i32) -> i32 {
+    *state = state.wrapping_mul(1664525u32).wrapping_add(1013904223u32);
+    min + ((*state >> 8u32) % (max - min) as u32) as i32
+}
+
+
+fn random_box(state: &mut u32) -> Aabb2<i32> {
+    let min  = Vec2(random(state, -500i32, 500i32), random(state, -500i32, 500i32));
+    let size = if random(state, 0i32, 4i32) == 0i32 { 0i32 } else { random(state, 0i32, 60i32) };
+    Aabb2::new(min, min + Vec2(size, random(state, 0i32, 60i32)))
+}
+
+
+fn squared_distance(bounds: &Aabb2<i32>, point: Vec2<i32>) -> i64 {
+    let gap = |min: i32, max: i32, value: i32| (min as i64 - value as i64).max(value as i64 - max as i64).max(0i64);
+    gap(bounds.min.0, bounds.max.0, point.0).pow(2u32) + gap(bounds.min.1, bounds.max.1, point.1).pow(2u32)
+}
+
+
+/// Checks every query against a linear scan of the live boxes, indexed by item
+fn assert_matches_scan(index: &impl Index, boxes: &[Option<Aabb2<i32>>], state: &mut u32) {
+    let live    = || boxes.iter().enumerate().filter_map(|(item, bounds)| Some((item, (*bounds)?)));
+    let unique  = |found: Vec<usize>| { let set: HashSet<usize> = found.iter().copied().collect(); assert_eq!(set.len(), found.len(), "reported twice"); set };
+    let touches = |a: &Aabb2<i32>, b: &Aabb2<i32>| a.min.0 <= b.max.0 && b.min.0 <= a.max.0 && a.min.1 <= b.max.1 && b.min.1 <= a.max.1;
+
+    // Small areas walk their cells, the whole plane goes through the occupied ones
+    let mut rects: Vec<Aabb2<i32>> = (0usize..50usize).map(|_| random_box(state)).collect();
+    rects.push(Aabb2::new(Vec2(i32::MIN, i32::MIN), Vec2(i32::MAX, i32::MAX)));
+    for rect in rects {
+        assert_eq!(unique(index.query_rect(&rect)), live().filter(|(_, bounds)| touches(bounds, &rect)).map(|(item, _)| item).collect());
+    } // for ..
+
+    for radius in [0i32, 5i32, 40i32, 300i32, i32::MAX] {
+        let center = Vec2(random(state, -600i32, 600i32), random(state, -600i32, 600i32));
+        let scan   = live().filter(|(_, bounds)| squared_distance(bounds, center) <= (radius as i64).pow(2u32)).map(|(item, _)| item).collect();
+        assert_eq!(unique(index.query_radius(center, radius)), scan);
+    } // for ..
+
+    for k in [0usize, 1usize, 7usize, 1000usize] {
+        let point    = Vec2(random(state, -2000i32, 2000i32), random(state, -2000i32, 2000i32));
+        let mut scan = live().map(|(_, bounds)| squared_distance(&bounds, point)).collect::<Vec<_>>();
+        scan.sort_unstable();
+        scan.truncate(k);
+
+        // Ties may come in any order, their distances may not
+        let nearest = index.nearest(point, k);
+        unique(nearest.clone());
+        assert_eq!(nearest.iter().map(|&item| squared_distance(&boxes[item].unwrap(), point)).collect::<Vec<_>>(), scan);
+    } // for ..
+}
+
+
+fn check_index(mut index: impl Index) {
+    let mut state = 3u32;
+    let mut boxes: Vec<Option<Aabb2<i32>>> = Vec::new();
+    let mut handles = Vec::new();
+    for item in 0usize..300usize {
+        let bounds = random_box(&mut state);
+        boxes.push(Some(bounds));
+        handles.push(index.insert(bounds, item));
+    } // for ..
+    assert_matches_scan(&index, &boxes, &mut state);
+
+    for round in 0usize..3usize {
+        for item in 0usize..boxes.len() {
+            if boxes[item].is_none() { continue }
+            match random(&mut state, 0i32, 10i32) {
+                0i32 if round > 0usize => {
+                    assert_eq!(index.remove(handles[item]), Some(item));
+                    assert!(!index.move_to(handles[item], boxes[item].unwrap()));
+                    boxes[item] = None;
+                } // 0i32
+                1i32..=5i32 => {
+                    let bounds = random_box(&mut state);
+                    assert!(index.move_to(handles[item], bounds));
+                    boxes[item] = Some(bounds);
+                } // 1i32..=5i32
+                _ => (),
+            } // match ..
+        } // for ..
+        assert_matches_scan(&index, &boxes, &mut state);
+    } // for ..
+}
+
+
+#[test]
+fn spatial_hash_matches_a_linear_scan() {
+    for cell_size in [1i32, 16i32, 64i32, 5000i32] {
+        check_index(SpatialHash::new(cell_size));
+    } // for ..
+}
+
+
+#[test]
+fn quadtree_matches_a_linear_scan() {
+    check_index(Quadtree::new(Aabb2::new(Vec2(-512i32, -512i32), Vec2(511i32, 511i32)), 6u32));
+    check_index(Quadtree::new(Aabb2::new(Vec2(0i32, 0i32), Vec2(255i32, 255i32)), 8u32));
+}
+
+
+#[test]
+fn nearest_across_empty_space() {
+    let point = |x: i32, y: i32| Aabb2::new(Vec2(x, y), Vec2(x, y));
+    let mut hash = SpatialHash::new(1i32);
+    for (item, (x, y)) in [(0i32, 0i32), (1_000_000i32, 1_000_000i32), (-3_000_000i32, 5i32), (2i32, -1i32)].into_iter().enumerate() {
+        hash.insert(point(x, y), item);
+    } // for ..
+
+    let start = Instant::now();
+    assert_eq!(Index::nearest(&hash, Vec2(0i32, 0i32), 4usize), vec![0usize, 3usize, 1usize, 2usize]);
+    assert_eq!(Index::nearest(&hash, Vec2(i32::MAX, i32::MAX), 1usize), vec![1usize]);
+    assert!(start.elapsed() < Duration::from_secs(1u64));
+}